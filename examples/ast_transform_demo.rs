@@ -40,7 +40,7 @@ More text with ENV_VAR and OTHER_VAR variables.
 
     // Count autolinks
     let autolink_count =
-        doc.count_inlines(|inline| matches!(inline, markdown_ppp::ast::Inline::Autolink(_)));
+        doc.count_inlines(|inline| matches!(inline, markdown_ppp::ast::Inline::Autolink { .. }));
     println!("Found {autolink_count} autolinks");
 
     println!("\n=== Transformation Examples ===");
@@ -16,6 +16,7 @@ fn main() {
         blocks: vec![
             Block::Heading(Heading {
                 kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
                 content: vec![
                     Inline::Text("Welcome to".to_string()),
                     Inline::Strong(vec![Inline::Text("Generic AST".to_string())]),
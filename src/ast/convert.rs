@@ -89,24 +89,61 @@ impl<T: Default> WithData<T> for Block {
                 user_data: data,
             },
             Block::List(list) => generic::Block::List(list.with_data(data)),
+            Block::DefinitionList(list) => generic::Block::DefinitionList(list.with_data(data)),
             Block::CodeBlock(code_block) => generic::Block::CodeBlock(code_block.with_data(data)),
             Block::HtmlBlock(content) => generic::Block::HtmlBlock {
                 content,
                 user_data: data,
             },
             Block::Definition(def) => generic::Block::Definition(def.with_data(data)),
+            Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+            } => generic::Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+                user_data: data,
+            },
             Block::Table(table) => generic::Block::Table(table.with_data(data)),
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+            } => generic::Block::Figure {
+                content: content
+                    .into_iter()
+                    .map(|b| b.with_data(T::default()))
+                    .collect(),
+                caption: caption
+                    .into_iter()
+                    .map(|i| i.with_data(T::default()))
+                    .collect(),
+                label,
+                caption_syntax,
+                user_data: data,
+            },
             Block::FootnoteDefinition(footnote) => {
                 generic::Block::FootnoteDefinition(footnote.with_data(data))
             }
             Block::GitHubAlert(alert) => generic::Block::GitHubAlert(alert.with_data(data)),
-            Block::LatexBlock(content) => generic::Block::LatexBlock {
+            Block::LatexBlock { delimiter, content } => generic::Block::LatexBlock {
+                delimiter,
                 content,
                 user_data: data,
             },
             Block::Empty => generic::Block::Empty { user_data: data },
             Block::Container(container) => generic::Block::Container(container.with_data(data)),
-            Block::MacroBlock(_content) => todo!(),
+            Block::MacroBlock(content) => generic::Block::MacroBlock {
+                content,
+                user_data: data,
+            },
+            Block::FrontMatter { format, raw } => generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data: data,
+            },
+            Block::Extension(extension) => generic::Block::Extension(extension.with_data(data)),
         }
     }
 }
@@ -128,6 +165,23 @@ impl<T: Default> WithData<T> for Container {
     }
 }
 
+impl<T: Default> WithData<T> for BlockExtension {
+    type WithDataType = generic::BlockExtension<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::BlockExtension {
+            name: self.name,
+            attrs: self.attrs,
+            children: self
+                .children
+                .into_iter()
+                .map(|b| b.with_data(T::default()))
+                .collect(),
+            user_data: data,
+        }
+    }
+}
+
 impl<T: Default> WithData<T> for Inline {
     type WithDataType = generic::Inline<T>;
 
@@ -142,7 +196,8 @@ impl<T: Default> WithData<T> for Inline {
                 content,
                 user_data: data,
             },
-            Inline::Latex(content) => generic::Inline::Latex {
+            Inline::Latex { delimiter, content } => generic::Inline::Latex {
+                delimiter,
                 content,
                 user_data: data,
             },
@@ -176,15 +231,82 @@ impl<T: Default> WithData<T> for Inline {
                     .collect(),
                 user_data: data,
             },
-            Inline::Autolink(url) => generic::Inline::Autolink {
+            Inline::Superscript(content) => generic::Inline::Superscript {
+                content: content
+                    .into_iter()
+                    .map(|i| i.with_data(T::default()))
+                    .collect(),
+                user_data: data,
+            },
+            Inline::Subscript(content) => generic::Inline::Subscript {
+                content: content
+                    .into_iter()
+                    .map(|i| i.with_data(T::default()))
+                    .collect(),
+                user_data: data,
+            },
+            Inline::Highlight(content) => generic::Inline::Highlight {
+                content: content
+                    .into_iter()
+                    .map(|i| i.with_data(T::default()))
+                    .collect(),
+                user_data: data,
+            },
+            Inline::Insert(content) => generic::Inline::Insert {
+                content: content
+                    .into_iter()
+                    .map(|i| i.with_data(T::default()))
+                    .collect(),
+                user_data: data,
+            },
+            Inline::Autolink { url, bare } => generic::Inline::Autolink {
                 url,
+                bare,
                 user_data: data,
             },
             Inline::FootnoteReference(label) => generic::Inline::FootnoteReference {
                 label,
                 user_data: data,
             },
+            Inline::WikiLink(wiki_link) => generic::Inline::WikiLink(wiki_link.with_data(data)),
+            Inline::Span(span) => generic::Inline::Span(span.with_data(data)),
+            Inline::GitHubReference(reference) => generic::Inline::GitHubReference {
+                reference,
+                user_data: data,
+            },
+            Inline::Citation(citation) => generic::Inline::Citation {
+                citation,
+                user_data: data,
+            },
+            Inline::Emoji { shortcode, glyph } => generic::Inline::Emoji {
+                shortcode,
+                glyph,
+                user_data: data,
+            },
+            Inline::Abbreviation { text, title } => generic::Inline::Abbreviation {
+                text,
+                title,
+                user_data: data,
+            },
             Inline::Empty => generic::Inline::Empty { user_data: data },
+            Inline::Extension(extension) => generic::Inline::Extension(extension.with_data(data)),
+        }
+    }
+}
+
+impl<T: Default> WithData<T> for InlineExtension {
+    type WithDataType = generic::InlineExtension<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::InlineExtension {
+            name: self.name,
+            attrs: self.attrs,
+            children: self
+                .children
+                .into_iter()
+                .map(|i| i.with_data(T::default()))
+                .collect(),
+            user_data: data,
         }
     }
 }
@@ -195,6 +317,7 @@ impl<T: Default> WithData<T> for Heading {
     fn with_data(self, data: T) -> Self::WithDataType {
         generic::Heading {
             kind: self.kind,
+            attributes: self.attributes,
             content: self
                 .content
                 .into_iter()
@@ -237,6 +360,46 @@ impl<T: Default> WithData<T> for ListItem {
     }
 }
 
+impl<T: Default> WithData<T> for DefinitionList {
+    type WithDataType = generic::DefinitionList<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::DefinitionList {
+            items: self
+                .items
+                .into_iter()
+                .map(|i| i.with_data(T::default()))
+                .collect(),
+            user_data: data,
+        }
+    }
+}
+
+impl<T: Default> WithData<T> for DefinitionListItem {
+    type WithDataType = generic::DefinitionListItem<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::DefinitionListItem {
+            term: self
+                .term
+                .into_iter()
+                .map(|i| i.with_data(T::default()))
+                .collect(),
+            definitions: self
+                .definitions
+                .into_iter()
+                .map(|blocks| {
+                    blocks
+                        .into_iter()
+                        .map(|b| b.with_data(T::default()))
+                        .collect()
+                })
+                .collect(),
+            user_data: data,
+        }
+    }
+}
+
 impl<T: Default> WithData<T> for CodeBlock {
     type WithDataType = generic::CodeBlock<T>;
 
@@ -244,6 +407,7 @@ impl<T: Default> WithData<T> for CodeBlock {
         generic::CodeBlock {
             kind: self.kind,
             literal: self.literal,
+            attributes: self.attributes,
             user_data: data,
         }
     }
@@ -290,6 +454,7 @@ impl<T: Default> WithData<T> for Table {
                 })
                 .collect(),
             alignments: self.alignments,
+            attributes: self.attributes,
             user_data: data,
         }
     }
@@ -339,6 +504,7 @@ impl<T: Default> WithData<T> for Link {
                 .into_iter()
                 .map(|i| i.with_data(T::default()))
                 .collect(),
+            attributes: self.attributes,
             user_data: data,
         }
     }
@@ -352,10 +518,37 @@ impl<T: Default> WithData<T> for Image {
             destination: self.destination,
             title: self.title,
             alt: self.alt,
-            attr: self.attr.map(|a| generic::ImageAttributes {
-                width: a.width,
-                height: a.height,
-            }),
+            attributes: self.attributes,
+            user_data: data,
+        }
+    }
+}
+
+impl<T: Default> WithData<T> for WikiLink {
+    type WithDataType = generic::WikiLink<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::WikiLink {
+            target: self.target,
+            fragment: self.fragment,
+            alias: self.alias,
+            embed: self.embed,
+            user_data: data,
+        }
+    }
+}
+
+impl<T: Default> WithData<T> for Span {
+    type WithDataType = generic::Span<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::Span {
+            attributes: self.attributes,
+            children: self
+                .children
+                .into_iter()
+                .map(|i| i.with_data(T::default()))
+                .collect(),
             user_data: data,
         }
     }
@@ -376,6 +569,7 @@ impl<T: Default> WithData<T> for LinkReference {
                 .into_iter()
                 .map(|i| i.with_data(T::default()))
                 .collect(),
+            image: self.image,
             user_data: data,
         }
     }
@@ -409,17 +603,43 @@ impl<T: Default> StripData<T> for generic::Block<T> {
                 Block::BlockQuote(blocks.into_iter().map(|b| b.strip_data()).collect())
             }
             generic::Block::List(list) => Block::List(list.strip_data()),
+            generic::Block::DefinitionList(list) => Block::DefinitionList(list.strip_data()),
             generic::Block::CodeBlock(code_block) => Block::CodeBlock(code_block.strip_data()),
             generic::Block::HtmlBlock { content, .. } => Block::HtmlBlock(content),
             generic::Block::Definition(def) => Block::Definition(def.strip_data()),
+            generic::Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+                ..
+            } => Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+            },
             generic::Block::Table(table) => Block::Table(table.strip_data()),
+            generic::Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+                ..
+            } => Block::Figure {
+                content: content.into_iter().map(|b| b.strip_data()).collect(),
+                caption: caption.into_iter().map(|i| i.strip_data()).collect(),
+                label,
+                caption_syntax,
+            },
             generic::Block::FootnoteDefinition(footnote) => {
                 Block::FootnoteDefinition(footnote.strip_data())
             }
             generic::Block::GitHubAlert(alert) => Block::GitHubAlert(alert.strip_data()),
-            generic::Block::LatexBlock { content, .. } => Block::LatexBlock(content),
+            generic::Block::LatexBlock {
+                delimiter, content, ..
+            } => Block::LatexBlock { delimiter, content },
             generic::Block::Empty { .. } => Block::Empty,
             generic::Block::Container(container) => Block::Container(container.strip_data()),
+            generic::Block::MacroBlock { content, .. } => Block::MacroBlock(content),
+            generic::Block::FrontMatter { format, raw, .. } => Block::FrontMatter { format, raw },
+            generic::Block::Extension(extension) => Block::Extension(extension.strip_data()),
         }
     }
 }
@@ -432,7 +652,9 @@ impl<T> StripData<T> for generic::Inline<T> {
             generic::Inline::Text { content, .. } => Inline::Text(content),
             generic::Inline::LineBreak { .. } => Inline::LineBreak,
             generic::Inline::Code { content, .. } => Inline::Code(content),
-            generic::Inline::Latex { content, .. } => Inline::Latex(content),
+            generic::Inline::Latex {
+                delimiter, content, ..
+            } => Inline::Latex { delimiter, content },
             generic::Inline::Html { content, .. } => Inline::Html(content),
             generic::Inline::Link(link) => Inline::Link(link.strip_data()),
             generic::Inline::LinkReference(link_ref) => {
@@ -448,9 +670,34 @@ impl<T> StripData<T> for generic::Inline<T> {
             generic::Inline::Strikethrough { content, .. } => {
                 Inline::Strikethrough(content.into_iter().map(|i| i.strip_data()).collect())
             }
-            generic::Inline::Autolink { url, .. } => Inline::Autolink(url),
+            generic::Inline::Superscript { content, .. } => {
+                Inline::Superscript(content.into_iter().map(|i| i.strip_data()).collect())
+            }
+            generic::Inline::Subscript { content, .. } => {
+                Inline::Subscript(content.into_iter().map(|i| i.strip_data()).collect())
+            }
+            generic::Inline::Highlight { content, .. } => {
+                Inline::Highlight(content.into_iter().map(|i| i.strip_data()).collect())
+            }
+            generic::Inline::Insert { content, .. } => {
+                Inline::Insert(content.into_iter().map(|i| i.strip_data()).collect())
+            }
+            generic::Inline::Autolink { url, bare, .. } => Inline::Autolink { url, bare },
             generic::Inline::FootnoteReference { label, .. } => Inline::FootnoteReference(label),
+            generic::Inline::WikiLink(wiki_link) => Inline::WikiLink(wiki_link.strip_data()),
+            generic::Inline::Span(span) => Inline::Span(span.strip_data()),
+            generic::Inline::GitHubReference { reference, .. } => {
+                Inline::GitHubReference(reference)
+            }
+            generic::Inline::Citation { citation, .. } => Inline::Citation(citation),
+            generic::Inline::Emoji {
+                shortcode, glyph, ..
+            } => Inline::Emoji { shortcode, glyph },
+            generic::Inline::Abbreviation { text, title, .. } => {
+                Inline::Abbreviation { text, title }
+            }
             generic::Inline::Empty { .. } => Inline::Empty,
+            generic::Inline::Extension(extension) => Inline::Extension(extension.strip_data()),
         }
     }
 }
@@ -461,6 +708,7 @@ impl<T> StripData<T> for generic::Heading<T> {
     fn strip_data(self) -> Self::StrippedType {
         Heading {
             kind: self.kind,
+            attributes: self.attributes,
             content: self.content.into_iter().map(|i| i.strip_data()).collect(),
         }
     }
@@ -488,6 +736,31 @@ impl<T: Default> StripData<T> for generic::ListItem<T> {
     }
 }
 
+impl<T: Default> StripData<T> for generic::DefinitionList<T> {
+    type StrippedType = DefinitionList;
+
+    fn strip_data(self) -> Self::StrippedType {
+        DefinitionList {
+            items: self.items.into_iter().map(|i| i.strip_data()).collect(),
+        }
+    }
+}
+
+impl<T: Default> StripData<T> for generic::DefinitionListItem<T> {
+    type StrippedType = DefinitionListItem;
+
+    fn strip_data(self) -> Self::StrippedType {
+        DefinitionListItem {
+            term: self.term.into_iter().map(|i| i.strip_data()).collect(),
+            definitions: self
+                .definitions
+                .into_iter()
+                .map(|blocks| blocks.into_iter().map(|b| b.strip_data()).collect())
+                .collect(),
+        }
+    }
+}
+
 impl<T> StripData<T> for generic::CodeBlock<T> {
     type StrippedType = CodeBlock;
 
@@ -495,6 +768,7 @@ impl<T> StripData<T> for generic::CodeBlock<T> {
         CodeBlock {
             kind: self.kind,
             literal: self.literal,
+            attributes: self.attributes,
         }
     }
 }
@@ -531,6 +805,7 @@ impl<T: Default> StripData<T> for generic::Table<T> {
                 })
                 .collect(),
             alignments: self.alignments,
+            attributes: self.attributes,
         }
     }
 }
@@ -565,6 +840,7 @@ impl<T> StripData<T> for generic::Link<T> {
             destination: self.destination,
             title: self.title,
             children: self.children.into_iter().map(|i| i.strip_data()).collect(),
+            attributes: self.attributes,
         }
     }
 }
@@ -577,10 +853,31 @@ impl<T> StripData<T> for generic::Image<T> {
             destination: self.destination,
             title: self.title,
             alt: self.alt,
-            attr: self.attr.map(|a| ImageAttributes {
-                width: a.width,
-                height: a.height,
-            }),
+            attributes: self.attributes,
+        }
+    }
+}
+
+impl<T> StripData<T> for generic::WikiLink<T> {
+    type StrippedType = WikiLink;
+
+    fn strip_data(self) -> Self::StrippedType {
+        WikiLink {
+            target: self.target,
+            fragment: self.fragment,
+            alias: self.alias,
+            embed: self.embed,
+        }
+    }
+}
+
+impl<T> StripData<T> for generic::Span<T> {
+    type StrippedType = Span;
+
+    fn strip_data(self) -> Self::StrippedType {
+        Span {
+            attributes: self.attributes,
+            children: self.children.into_iter().map(|i| i.strip_data()).collect(),
         }
     }
 }
@@ -592,6 +889,7 @@ impl<T> StripData<T> for generic::LinkReference<T> {
         LinkReference {
             label: self.label.into_iter().map(|i| i.strip_data()).collect(),
             text: self.text.into_iter().map(|i| i.strip_data()).collect(),
+            image: self.image,
         }
     }
 }
@@ -608,6 +906,30 @@ impl<T: Default> StripData<T> for generic::Container<T> {
     }
 }
 
+impl<T: Default> StripData<T> for generic::BlockExtension<T> {
+    type StrippedType = BlockExtension;
+
+    fn strip_data(self) -> Self::StrippedType {
+        BlockExtension {
+            name: self.name,
+            attrs: self.attrs,
+            children: self.children.into_iter().map(|b| b.strip_data()).collect(),
+        }
+    }
+}
+
+impl<T> StripData<T> for generic::InlineExtension<T> {
+    type StrippedType = InlineExtension;
+
+    fn strip_data(self) -> Self::StrippedType {
+        InlineExtension {
+            name: self.name,
+            attrs: self.attrs,
+            children: self.children.into_iter().map(|i| i.strip_data()).collect(),
+        }
+    }
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // MapData implementations (transform user data type)
 // NOTE: Disabled due to compiler recursion limits
@@ -801,6 +1123,7 @@ impl<T, U> MapData<T, U> for generic::LinkReference<T> {
         generic::LinkReference {
             label: self.label.into_iter().map(|i| i.map_data(&mut f)).collect(),
             text: self.text.into_iter().map(|i| i.map_data(&mut f)).collect(),
+            image: self.image,
             user_data: f(self.user_data),
         }
     }
@@ -36,8 +36,10 @@
 
 // Re-export types from parent module that don't need generics
 pub use super::{
-    Alignment, CodeBlockKind, GitHubAlert, GitHubAlertType, HeadingKind, ListBulletKind,
-    ListOrderedKindOptions, SetextHeading, TaskState,
+    Alignment, Attributes, CaptionSyntax, Citation, CitationItem, CodeBlockKind,
+    DisplayMathDelimiter, FrontMatterFormat, GitHubAlert, GitHubAlertType, GitHubReference,
+    GitHubReferenceKind, HeadingKind, InlineMathDelimiter, ListBulletKind, ListOrderedKindOptions,
+    SetextHeading, TaskState,
 };
 
 // ——————————————————————————————————————————————————————————————————————————
@@ -96,6 +98,9 @@ where
     /// List (bullet or ordered)
     List(List<T>),
 
+    /// Definition list (`Term` followed by `: Definition` lines)
+    DefinitionList(DefinitionList<T>),
+
     /// Fenced or indented code block
     CodeBlock(CodeBlock<T>),
 
@@ -109,9 +114,27 @@ where
     /// Link reference definition. Preserved for round‑tripping.
     Definition(LinkDefinition<T>),
 
+    /// Abbreviation definition (`*[HTML]: HyperText Markup Language`)
+    AbbreviationDefinition {
+        abbreviation: String,
+        expansion: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Tables
     Table(Table<T>),
 
+    /// Numbered figure: a standalone image, or a table with a caption line
+    Figure {
+        content: Vec<Block<T>>,
+        caption: Vec<Inline<T>>,
+        label: Option<String>,
+        caption_syntax: CaptionSyntax,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Footnote definition
     FootnoteDefinition(FootnoteDefinition<T>),
 
@@ -120,6 +143,8 @@ where
 
     /// LaTeX block
     LatexBlock {
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        delimiter: DisplayMathDelimiter,
         content: String,
         #[cfg_attr(feature = "ast-serde", serde(default))]
         user_data: T,
@@ -133,6 +158,24 @@ where
 
     /// A container block.
     Container(Container<T>),
+
+    /// A macro block.
+    MacroBlock {
+        content: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Front matter at the start of the document (`---` YAML or `+++` TOML)
+    FrontMatter {
+        format: FrontMatterFormat,
+        raw: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// A block-level node defined by a plugin
+    Extension(BlockExtension<T>),
 }
 
 /// A container block with optional user data.
@@ -156,6 +199,27 @@ where
     pub user_data: T,
 }
 
+/// A block-level node defined by a plugin with optional user data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockExtension<T = ()>
+where
+    T: Default,
+{
+    /// Name identifying the kind of node, chosen by the plugin.
+    pub name: String,
+
+    /// Attributes of the node.
+    pub attrs: Vec<(String, String)>,
+
+    /// The blocks inside the node.
+    pub children: Vec<Block<T>>,
+
+    /// User-defined data associated with this node.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
 /// Heading with level 1–6 and inline content.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -163,6 +227,11 @@ pub struct Heading<T = ()> {
     /// Kind of heading (ATX or Setext) together with the level.
     pub kind: HeadingKind,
 
+    /// Attributes given with a trailing `{#id .class key=value}` list; the
+    /// id is the explicit identifier of the heading.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
+
     /// Inlines that form the heading text (before trimming).
     pub content: Vec<Inline<T>>,
 
@@ -223,6 +292,39 @@ where
     pub user_data: T,
 }
 
+/// A definition list with optional user data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionList<T = ()>
+where
+    T: Default,
+{
+    /// Terms with their definitions, in source order.
+    pub items: Vec<DefinitionListItem<T>>,
+
+    /// User-defined data associated with this definition list
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
+/// A term of a definition list together with its definitions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionListItem<T = ()>
+where
+    T: Default,
+{
+    /// The term being defined.
+    pub term: Vec<Inline<T>>,
+
+    /// One or more definitions of the term, each made of blocks.
+    pub definitions: Vec<Vec<Block<T>>>,
+
+    /// User-defined data associated with this item
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // Code blocks
 // ——————————————————————————————————————————————————————————————————————————
@@ -237,6 +339,10 @@ pub struct CodeBlock<T = ()> {
     /// Literal text inside the code block **without** final newline trimming.
     pub literal: String,
 
+    /// Attributes given after the info string of a fenced code block.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
+
     /// User-defined data associated with this code block
     #[cfg_attr(feature = "ast-serde", serde(default))]
     pub user_data: T,
@@ -282,6 +388,10 @@ where
     /// Column alignment; `alignments.len() == column_count`.
     pub alignments: Vec<Alignment>,
 
+    /// Attributes given on the line following the table.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
+
     /// User-defined data associated with this table
     #[cfg_attr(feature = "ast-serde", serde(default))]
     pub user_data: T,
@@ -377,6 +487,8 @@ pub enum Inline<T = ()> {
 
     /// LaTeX formula
     Latex {
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        delimiter: InlineMathDelimiter,
         content: String,
         #[cfg_attr(feature = "ast-serde", serde(default))]
         user_data: T,
@@ -419,10 +531,41 @@ pub enum Inline<T = ()> {
         user_data: T,
     },
 
-    /// Autolink (`<https://>` or `<mailto:…>`)
+    /// Superscript (`^`)
+    Superscript {
+        content: Vec<Inline<T>>,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Subscript (`~`)
+    Subscript {
+        content: Vec<Inline<T>>,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Highlighted text (`==`)
+    Highlight {
+        content: Vec<Inline<T>>,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Inserted text (`++`), usually rendered underlined
+    Insert {
+        content: Vec<Inline<T>>,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Autolink (`<https://>` or `<mailto:…>`), or a bare URL, `www.` link or
+    /// email address
     Autolink {
         url: String,
         #[cfg_attr(feature = "ast-serde", serde(default))]
+        bare: bool,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
         user_data: T,
     },
 
@@ -433,11 +576,83 @@ pub enum Inline<T = ()> {
         user_data: T,
     },
 
+    /// Wiki-style link (`[[Target]]`, `[[Target|alias]]` or `![[Target]]`)
+    WikiLink(WikiLink<T>),
+
+    /// Bracketed span with an attribute list (`[text]{.class}`)
+    Span(Span<T>),
+
+    /// Reference to a GitHub user, issue or commit (`@alice`, `#123`, `owner/repo#45`)
+    GitHubReference {
+        reference: GitHubReference,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Pandoc citation (`[see @doe99, p. 33; -@smith04]`, `@doe99 [p. 33]`)
+    Citation {
+        citation: Citation,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Emoji shortcode (`:rocket:`)
+    Emoji {
+        shortcode: String,
+        glyph: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
+    /// Abbreviation with a definition elsewhere in the document
+    Abbreviation {
+        text: String,
+        title: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Empty element. This is used to represent skipped elements in the AST.
     Empty {
         #[cfg_attr(feature = "ast-serde", serde(default))]
         user_data: T,
     },
+
+    /// An inline node defined by a plugin
+    Extension(InlineExtension<T>),
+}
+
+/// An inline node defined by a plugin with optional user data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InlineExtension<T = ()> {
+    /// Name identifying the kind of node, chosen by the plugin.
+    pub name: String,
+
+    /// Attributes of the node.
+    pub attrs: Vec<(String, String)>,
+
+    /// The inlines inside the node.
+    pub children: Vec<Inline<T>>,
+
+    /// User-defined data associated with this node.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
+/// Bracketed span with optional user data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span<T = ()> {
+    /// Attributes of the span.
+    pub attributes: Attributes,
+
+    /// The inlines inside the span.
+    pub children: Vec<Inline<T>>,
+
+    /// User-defined data associated with this span.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
 }
 
 /// Re‑usable structure for links and images (destination + children).
@@ -453,21 +668,15 @@ pub struct Link<T = ()> {
     /// Inline content (text, code, etc.) inside the link or image.
     pub children: Vec<Inline<T>>,
 
+    /// Attributes given after the link (e.g., `[text](/url){.external}`).
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
+
     /// User-defined data associated with this link
     #[cfg_attr(feature = "ast-serde", serde(default))]
     pub user_data: T,
 }
 
-/// Attributes for an image.
-#[derive(Debug, Clone, PartialEq, Default)]
-#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ImageAttributes {
-    /// Width of the image.
-    pub width: Option<String>,
-    /// Height of the image.
-    pub height: Option<String>,
-}
-
 /// Re‑usable structure for images.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -481,9 +690,9 @@ pub struct Image<T = ()> {
     /// Alternative text.
     pub alt: String,
 
-    /// Image attributes.
+    /// Attributes given after the image (e.g., `![alt](/img.png){width=50%}`).
     #[cfg_attr(feature = "ast-serde", serde(default))]
-    pub attr: Option<ImageAttributes>,
+    pub attributes: Attributes,
 
     /// User-defined data associated with this image
     #[cfg_attr(feature = "ast-serde", serde(default))]
@@ -500,11 +709,37 @@ pub struct LinkReference<T = ()> {
     /// Link text
     pub text: Vec<Inline<T>>,
 
+    /// `true` for image references (`![alt][label]`).
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub image: bool,
+
     /// User-defined data associated with this link reference
     #[cfg_attr(feature = "ast-serde", serde(default))]
     pub user_data: T,
 }
 
+/// Wiki-style link with optional user data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WikiLink<T = ()> {
+    /// Name of the page the link points to. Empty for links within the same page
+    /// (`[[#Section]]`).
+    pub target: String,
+
+    /// Heading fragment after `#`, if any.
+    pub fragment: Option<String>,
+
+    /// Text displayed instead of the target, after `|`.
+    pub alias: Option<String>,
+
+    /// `true` for embeds (`![[Page]]`).
+    pub embed: bool,
+
+    /// User-defined data associated with this wiki link
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // Default implementations for common cases
 // ——————————————————————————————————————————————————————————————————————————
@@ -522,6 +757,7 @@ impl<T: Default> Default for Heading<T> {
     fn default() -> Self {
         Self {
             kind: HeadingKind::Atx(1),
+            attributes: Attributes::default(),
             content: Vec::new(),
             user_data: T::default(),
         }
@@ -543,6 +779,7 @@ impl<T: Default> Default for Table<T> {
         Self {
             rows: Vec::new(),
             alignments: Vec::new(),
+            attributes: Attributes::default(),
             user_data: T::default(),
         }
     }
@@ -0,0 +1,122 @@
+use crate::ast::Inline;
+
+/// Normalize a link label for matching references against definitions
+///
+/// Following the CommonMark specification, labels match after Unicode case
+/// folding, with leading and trailing whitespace stripped and runs of inner
+/// whitespace collapsed to a single space. Inline markup is kept in the key,
+/// so `[*foo*]` does not match `[foo]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::{normalize_label, Inline};
+///
+/// let reference = normalize_label(&[Inline::Text("Foo\n  Bar".to_string())]);
+/// assert_eq!(reference, normalize_label(&[Inline::Text("foo bar".to_string())]));
+/// assert_eq!(reference, "foo bar");
+///
+/// // `ẞ` folds to `ss`
+/// assert_eq!(normalize_label(&[Inline::Text("ẞ".to_string())]), "ss");
+/// ```
+pub fn normalize_label(label: &[Inline]) -> String {
+    let mut key = String::new();
+    push_label_key(label, &mut key);
+    // Lowercasing the uppercased text applies the case folding expansions
+    // such as `ß` to `ss`
+    key.to_lowercase()
+        .to_uppercase()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn push_label_key(inlines: &[Inline], key: &mut String) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) | Inline::Html(text) => key.push_str(text),
+            Inline::LineBreak => key.push('\n'),
+            Inline::Code(code) => {
+                key.push('`');
+                key.push_str(code);
+                key.push('`');
+            }
+            Inline::Latex { delimiter, content } => {
+                key.push_str(delimiter.open());
+                key.push_str(content);
+                key.push_str(delimiter.close());
+            }
+            Inline::Emphasis(children) => push_delimited(children, "*", key),
+            Inline::Strong(children) => push_delimited(children, "**", key),
+            Inline::Strikethrough(children) => push_delimited(children, "~~", key),
+            Inline::Superscript(children) => push_delimited(children, "^", key),
+            Inline::Subscript(children) => push_delimited(children, "~", key),
+            Inline::Highlight(children) => push_delimited(children, "==", key),
+            Inline::Insert(children) => push_delimited(children, "++", key),
+            Inline::Link(link) => {
+                key.push('[');
+                push_label_key(&link.children, key);
+                key.push_str("](");
+                key.push_str(&link.destination);
+                key.push(')');
+            }
+            Inline::LinkReference(link) => {
+                key.push('[');
+                push_label_key(&link.text, key);
+                key.push(']');
+            }
+            Inline::Image(image) => {
+                key.push_str("![");
+                key.push_str(&image.alt);
+                key.push_str("](");
+                key.push_str(&image.destination);
+                key.push(')');
+            }
+            Inline::Autolink { url, bare: true } => key.push_str(url),
+            Inline::Autolink { url, bare: false } => {
+                key.push('<');
+                key.push_str(url);
+                key.push('>');
+            }
+            Inline::FootnoteReference(label) => {
+                key.push_str("[^");
+                key.push_str(label);
+                key.push(']');
+            }
+            Inline::WikiLink(wiki_link) => {
+                if wiki_link.embed {
+                    key.push('!');
+                }
+                key.push_str("[[");
+                key.push_str(&wiki_link.target);
+                if let Some(fragment) = &wiki_link.fragment {
+                    key.push('#');
+                    key.push_str(fragment);
+                }
+                if let Some(alias) = &wiki_link.alias {
+                    key.push('|');
+                    key.push_str(alias);
+                }
+                key.push_str("]]");
+            }
+            Inline::Span(span) => push_label_key(&span.children, key),
+            Inline::GitHubReference(reference) => key.push_str(&reference.text()),
+            Inline::Citation(citation) => key.push_str(&citation.text()),
+            Inline::Abbreviation { text, .. } => key.push_str(text),
+            Inline::Emoji { shortcode, .. } => {
+                key.push(':');
+                key.push_str(shortcode);
+                key.push(':');
+            }
+            Inline::Extension(extension) => push_label_key(&extension.children, key),
+            Inline::Empty => {}
+        }
+    }
+}
+
+fn push_delimited(children: &[Inline], delimiter: &str, key: &mut String) {
+    key.push_str(delimiter);
+    push_label_key(children, key);
+    key.push_str(delimiter);
+}
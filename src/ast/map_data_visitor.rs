@@ -40,6 +40,9 @@ pub trait MapDataVisitor<T: Default, U: Default> {
                 user_data: self.map_data(user_data),
             },
             generic::Block::List(list) => generic::Block::List(self.visit_list(list)),
+            generic::Block::DefinitionList(list) => {
+                generic::Block::DefinitionList(self.visit_definition_list(list))
+            }
             generic::Block::CodeBlock(code_block) => {
                 generic::Block::CodeBlock(self.visit_code_block(code_block))
             }
@@ -50,7 +53,29 @@ pub trait MapDataVisitor<T: Default, U: Default> {
             generic::Block::Definition(def) => {
                 generic::Block::Definition(self.visit_link_definition(def))
             }
+            generic::Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+                user_data,
+            } => generic::Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+                user_data: self.map_data(user_data),
+            },
             generic::Block::Table(table) => generic::Block::Table(self.visit_table(table)),
+            generic::Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+                user_data,
+            } => generic::Block::Figure {
+                content: content.into_iter().map(|b| self.visit_block(b)).collect(),
+                caption: caption.into_iter().map(|i| self.visit_inline(i)).collect(),
+                label,
+                caption_syntax,
+                user_data: self.map_data(user_data),
+            },
             generic::Block::FootnoteDefinition(footnote) => {
                 generic::Block::FootnoteDefinition(self.visit_footnote_definition(footnote))
             }
@@ -60,13 +85,34 @@ pub trait MapDataVisitor<T: Default, U: Default> {
             generic::Block::Empty { user_data } => generic::Block::Empty {
                 user_data: self.map_data(user_data),
             },
-            generic::Block::LatexBlock { content, user_data } => generic::Block::LatexBlock {
+            generic::Block::LatexBlock {
+                delimiter,
+                content,
+                user_data,
+            } => generic::Block::LatexBlock {
+                delimiter,
                 content,
                 user_data: self.map_data(user_data),
             },
             generic::Block::Container(container) => {
                 generic::Block::Container(self.visit_container(container))
             }
+            generic::Block::MacroBlock { content, user_data } => generic::Block::MacroBlock {
+                content,
+                user_data: self.map_data(user_data),
+            },
+            generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data,
+            } => generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data: self.map_data(user_data),
+            },
+            generic::Block::Extension(extension) => {
+                generic::Block::Extension(self.visit_block_extension(extension))
+            }
         }
     }
 
@@ -87,6 +133,23 @@ pub trait MapDataVisitor<T: Default, U: Default> {
         }
     }
 
+    /// Transform a block extension
+    fn visit_block_extension(
+        &mut self,
+        extension: generic::BlockExtension<T>,
+    ) -> generic::BlockExtension<U> {
+        generic::BlockExtension {
+            name: extension.name,
+            attrs: extension.attrs,
+            children: extension
+                .children
+                .into_iter()
+                .map(|b| self.visit_block(b))
+                .collect(),
+            user_data: self.map_data(extension.user_data),
+        }
+    }
+
     /// Transform an inline element
     fn visit_inline(&mut self, inline: generic::Inline<T>) -> generic::Inline<U> {
         match inline {
@@ -124,8 +187,29 @@ pub trait MapDataVisitor<T: Default, U: Default> {
                     user_data: self.map_data(user_data),
                 }
             }
-            generic::Inline::Autolink { url, user_data } => generic::Inline::Autolink {
+            generic::Inline::Superscript { content, user_data } => generic::Inline::Superscript {
+                content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Subscript { content, user_data } => generic::Inline::Subscript {
+                content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Highlight { content, user_data } => generic::Inline::Highlight {
+                content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Insert { content, user_data } => generic::Inline::Insert {
+                content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Autolink {
+                url,
+                bare,
+                user_data,
+            } => generic::Inline::Autolink {
                 url,
+                bare,
                 user_data: self.map_data(user_data),
             },
             generic::Inline::FootnoteReference { label, user_data } => {
@@ -134,13 +218,74 @@ pub trait MapDataVisitor<T: Default, U: Default> {
                     user_data: self.map_data(user_data),
                 }
             }
+            generic::Inline::WikiLink(wiki_link) => {
+                generic::Inline::WikiLink(self.visit_wiki_link(wiki_link))
+            }
+            generic::Inline::Span(span) => generic::Inline::Span(self.visit_span(span)),
+            generic::Inline::GitHubReference {
+                reference,
+                user_data,
+            } => generic::Inline::GitHubReference {
+                reference,
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Citation {
+                citation,
+                user_data,
+            } => generic::Inline::Citation {
+                citation,
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Emoji {
+                shortcode,
+                glyph,
+                user_data,
+            } => generic::Inline::Emoji {
+                shortcode,
+                glyph,
+                user_data: self.map_data(user_data),
+            },
+            generic::Inline::Abbreviation {
+                text,
+                title,
+                user_data,
+            } => generic::Inline::Abbreviation {
+                text,
+                title,
+                user_data: self.map_data(user_data),
+            },
             generic::Inline::Empty { user_data } => generic::Inline::Empty {
                 user_data: self.map_data(user_data),
             },
-            generic::Inline::Latex { content, user_data } => generic::Inline::Latex {
+            generic::Inline::Latex {
+                delimiter,
+                content,
+                user_data,
+            } => generic::Inline::Latex {
+                delimiter,
                 content,
                 user_data: self.map_data(user_data),
             },
+            generic::Inline::Extension(extension) => {
+                generic::Inline::Extension(self.visit_inline_extension(extension))
+            }
+        }
+    }
+
+    /// Transform an inline extension
+    fn visit_inline_extension(
+        &mut self,
+        extension: generic::InlineExtension<T>,
+    ) -> generic::InlineExtension<U> {
+        generic::InlineExtension {
+            name: extension.name,
+            attrs: extension.attrs,
+            children: extension
+                .children
+                .into_iter()
+                .map(|i| self.visit_inline(i))
+                .collect(),
+            user_data: self.map_data(extension.user_data),
         }
     }
 
@@ -148,6 +293,7 @@ pub trait MapDataVisitor<T: Default, U: Default> {
     fn visit_heading(&mut self, heading: generic::Heading<T>) -> generic::Heading<U> {
         generic::Heading {
             kind: heading.kind,
+            attributes: heading.attributes,
             content: heading
                 .content
                 .into_iter()
@@ -183,11 +329,47 @@ pub trait MapDataVisitor<T: Default, U: Default> {
         }
     }
 
+    /// Transform a definition list
+    fn visit_definition_list(
+        &mut self,
+        list: generic::DefinitionList<T>,
+    ) -> generic::DefinitionList<U> {
+        generic::DefinitionList {
+            items: list
+                .items
+                .into_iter()
+                .map(|i| self.visit_definition_list_item(i))
+                .collect(),
+            user_data: self.map_data(list.user_data),
+        }
+    }
+
+    /// Transform a definition list item
+    fn visit_definition_list_item(
+        &mut self,
+        item: generic::DefinitionListItem<T>,
+    ) -> generic::DefinitionListItem<U> {
+        generic::DefinitionListItem {
+            term: item
+                .term
+                .into_iter()
+                .map(|i| self.visit_inline(i))
+                .collect(),
+            definitions: item
+                .definitions
+                .into_iter()
+                .map(|blocks| blocks.into_iter().map(|b| self.visit_block(b)).collect())
+                .collect(),
+            user_data: self.map_data(item.user_data),
+        }
+    }
+
     /// Transform a code block
     fn visit_code_block(&mut self, code_block: generic::CodeBlock<T>) -> generic::CodeBlock<U> {
         generic::CodeBlock {
             kind: code_block.kind,
             literal: code_block.literal,
+            attributes: code_block.attributes,
             user_data: self.map_data(code_block.user_data),
         }
     }
@@ -231,6 +413,7 @@ pub trait MapDataVisitor<T: Default, U: Default> {
                 })
                 .collect(),
             alignments: table.alignments,
+            attributes: table.attributes,
             user_data: self.map_data(table.user_data),
         }
     }
@@ -277,6 +460,7 @@ pub trait MapDataVisitor<T: Default, U: Default> {
                 .into_iter()
                 .map(|i| self.visit_inline(i))
                 .collect(),
+            attributes: link.attributes,
             user_data: self.map_data(link.user_data),
         }
     }
@@ -287,11 +471,35 @@ pub trait MapDataVisitor<T: Default, U: Default> {
             destination: image.destination,
             title: image.title,
             alt: image.alt,
-            attr: image.attr,
+            attributes: image.attributes,
             user_data: self.map_data(image.user_data),
         }
     }
 
+    /// Transform a wiki link
+    fn visit_wiki_link(&mut self, wiki_link: generic::WikiLink<T>) -> generic::WikiLink<U> {
+        generic::WikiLink {
+            target: wiki_link.target,
+            fragment: wiki_link.fragment,
+            alias: wiki_link.alias,
+            embed: wiki_link.embed,
+            user_data: self.map_data(wiki_link.user_data),
+        }
+    }
+
+    /// Transform a span
+    fn visit_span(&mut self, span: generic::Span<T>) -> generic::Span<U> {
+        generic::Span {
+            attributes: span.attributes,
+            children: span
+                .children
+                .into_iter()
+                .map(|i| self.visit_inline(i))
+                .collect(),
+            user_data: self.map_data(span.user_data),
+        }
+    }
+
     /// Transform a link reference
     fn visit_link_reference(
         &mut self,
@@ -308,6 +516,7 @@ pub trait MapDataVisitor<T: Default, U: Default> {
                 .into_iter()
                 .map(|i| self.visit_inline(i))
                 .collect(),
+            image: link_ref.image,
             user_data: self.map_data(link_ref.user_data),
         }
     }
@@ -396,6 +605,7 @@ mod tests {
             blocks: vec![
                 generic::Block::Heading(generic::Heading {
                     kind: crate::ast::HeadingKind::Atx(1),
+                    attributes: crate::ast::Attributes::default(),
                     content: vec![
                         generic::Inline::Text {
                             content: "Title".to_string(),
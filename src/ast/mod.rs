@@ -31,6 +31,15 @@ pub mod map_data_visitor;
 mod github_alerts;
 pub use github_alerts::{GitHubAlert, GitHubAlertType};
 
+mod label;
+pub use label::normalize_label;
+
+mod plain_text;
+pub use plain_text::plain_text;
+
+mod slug;
+pub use slug::{slugify, Slugger};
+
 // ——————————————————————————————————————————————————————————————————————————
 // Document root
 // ——————————————————————————————————————————————————————————————————————————
@@ -66,6 +75,9 @@ pub enum Block {
     /// List (bullet or ordered)
     List(List),
 
+    /// Definition list (`Term` followed by `: Definition` lines)
+    DefinitionList(DefinitionList),
+
     /// Fenced or indented code block
     CodeBlock(CodeBlock),
 
@@ -75,17 +87,48 @@ pub enum Block {
     /// Link reference definition.  Preserved for round‑tripping.
     Definition(LinkDefinition),
 
+    /// Abbreviation definition (`*[HTML]: HyperText Markup Language`)
+    AbbreviationDefinition {
+        /// Abbreviation between the brackets
+        abbreviation: String,
+
+        /// Full form, used as the title of matching abbreviations
+        expansion: String,
+    },
+
     /// Tables
     Table(Table),
 
+    /// Numbered figure: a standalone image, or a table with a caption line
+    Figure {
+        /// The image paragraph or the table
+        content: Vec<Block>,
+
+        /// Caption of the figure
+        caption: Vec<Inline>,
+
+        /// Identifier to refer to the figure, from the `{#id}` of the image or table
+        label: Option<String>,
+
+        /// How the caption was written
+        caption_syntax: CaptionSyntax,
+    },
+
     /// Footnote definition
     FootnoteDefinition(FootnoteDefinition),
 
     /// GitHub alert block (NOTE, TIP, IMPORTANT, WARNING, CAUTION)
     GitHubAlert(GitHubAlert),
 
-    /// LaTeX block
-    LatexBlock(String),
+    /// LaTeX block (display math)
+    LatexBlock {
+        /// Delimiters the formula was written with
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        delimiter: DisplayMathDelimiter,
+
+        /// Source of the formula
+        content: String,
+    },
 
     /// Empty block. This is used to represent skipped blocks in the AST.
     Empty,
@@ -95,6 +138,123 @@ pub enum Block {
 
     /// A macro block.
     MacroBlock(String),
+
+    /// Front matter at the start of the document (`---` YAML or `+++` TOML)
+    FrontMatter {
+        /// Format of the metadata, given by the fence
+        format: FrontMatterFormat,
+
+        /// Text between the fences, without the final line ending
+        raw: String,
+    },
+
+    /// A block-level node defined by a plugin
+    Extension(BlockExtension),
+}
+
+/// Format of a front matter block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrontMatterFormat {
+    /// YAML, fenced by `---` lines
+    Yaml,
+
+    /// TOML, fenced by `+++` lines
+    Toml,
+}
+
+impl FrontMatterFormat {
+    /// The fence line that opens and closes the block
+    pub fn fence(self) -> &'static str {
+        match self {
+            FrontMatterFormat::Yaml => "---",
+            FrontMatterFormat::Toml => "+++",
+        }
+    }
+}
+
+/// Source syntax of a figure caption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaptionSyntax {
+    /// The title, or else the alt text, of a standalone image
+    Image,
+
+    /// A `Table: caption` line before or after a table
+    Table,
+
+    /// A `: caption` line before or after a table
+    Colon,
+}
+
+/// Delimiters of display math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DisplayMathDelimiter {
+    /// `$$...$$`
+    #[default]
+    DoubleDollar,
+
+    /// `\[...\]`
+    Brackets,
+
+    /// A fenced code block with the `math` info string (```` ```math ````)
+    Fence,
+}
+
+/// Delimiters of inline math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InlineMathDelimiter {
+    /// `$...$`
+    #[default]
+    Dollar,
+
+    /// `\(...\)`
+    Parentheses,
+
+    /// ``$`...`$``, math written as a code span between dollars
+    DollarBacktick,
+}
+
+impl DisplayMathDelimiter {
+    /// The delimiter that opens the formula
+    pub fn open(self) -> &'static str {
+        match self {
+            DisplayMathDelimiter::DoubleDollar => "$$",
+            DisplayMathDelimiter::Brackets => r"\[",
+            DisplayMathDelimiter::Fence => "```math",
+        }
+    }
+
+    /// The delimiter that closes the formula
+    pub fn close(self) -> &'static str {
+        match self {
+            DisplayMathDelimiter::DoubleDollar => "$$",
+            DisplayMathDelimiter::Brackets => r"\]",
+            DisplayMathDelimiter::Fence => "```",
+        }
+    }
+}
+
+impl InlineMathDelimiter {
+    /// The delimiter that opens the formula
+    pub fn open(self) -> &'static str {
+        match self {
+            InlineMathDelimiter::Dollar => "$",
+            InlineMathDelimiter::Parentheses => r"\(",
+            InlineMathDelimiter::DollarBacktick => "$`",
+        }
+    }
+
+    /// The delimiter that closes the formula
+    pub fn close(self) -> &'static str {
+        match self {
+            InlineMathDelimiter::Dollar => "$",
+            InlineMathDelimiter::Parentheses => r"\)",
+            InlineMathDelimiter::DollarBacktick => "`$",
+        }
+    }
 }
 
 /// A container block.
@@ -111,6 +271,23 @@ pub struct Container {
     pub blocks: Vec<Block>,
 }
 
+/// A block-level node defined by a plugin, e.g. produced by a custom parser.
+///
+/// Printers render the children unless a renderer for extension nodes is
+/// configured.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockExtension {
+    /// Name identifying the kind of node, chosen by the plugin.
+    pub name: String,
+
+    /// Attributes of the node.
+    pub attrs: Vec<(String, String)>,
+
+    /// The blocks inside the node.
+    pub children: Vec<Block>,
+}
+
 /// Heading with level 1–6 and inline content.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -118,6 +295,11 @@ pub struct Heading {
     /// Kind of heading (ATX or Setext) together with the level.
     pub kind: HeadingKind,
 
+    /// Attributes given with a trailing `{#id .class key=value}` list; the
+    /// id is the explicit identifier of the heading.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
+
     /// Inlines that form the heading text (before trimming).
     pub content: Vec<Inline>,
 }
@@ -204,6 +386,25 @@ pub struct ListItem {
     pub blocks: Vec<Block>,
 }
 
+/// A definition list (e.g., `Term\n: Definition`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionList {
+    /// Terms with their definitions, in source order.
+    pub items: Vec<DefinitionListItem>,
+}
+
+/// A term of a definition list together with its definitions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionListItem {
+    /// The term being defined.
+    pub term: Vec<Inline>,
+
+    /// One or more definitions of the term, each made of blocks.
+    pub definitions: Vec<Vec<Block>>,
+}
+
 /// State of a task‑list checkbox.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -228,6 +429,10 @@ pub struct CodeBlock {
 
     /// Literal text inside the code block **without** final newline trimming.
     pub literal: String,
+
+    /// Attributes given after the info string of a fenced code block.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
 }
 
 /// The concrete kind of a code block.
@@ -276,6 +481,10 @@ pub struct Table {
 
     /// Column alignment; `alignments.len() == column_count`.
     pub alignments: Vec<Alignment>,
+
+    /// Attributes given on the line following the table.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
 }
 
 /// A table row is a vector of cells (columns).
@@ -341,8 +550,15 @@ pub enum Inline {
     /// Inline code span
     Code(String),
 
-    /// LaTeX formula
-    Latex(String),
+    /// LaTeX formula (inline math)
+    Latex {
+        /// Delimiters the formula was written with
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        delimiter: InlineMathDelimiter,
+
+        /// Source of the formula
+        content: String,
+    },
 
     /// Raw HTML fragment
     Html(String),
@@ -362,25 +578,112 @@ pub enum Inline {
     Strong(Vec<Inline>),
     /// Strikethrough (`~~`)
     Strikethrough(Vec<Inline>),
-
-    /// Autolink (`<https://>` or `<mailto:…>`)
-    Autolink(String),
+    /// Superscript (`^`)
+    Superscript(Vec<Inline>),
+    /// Subscript (`~`)
+    Subscript(Vec<Inline>),
+    /// Highlighted text (`==`)
+    Highlight(Vec<Inline>),
+    /// Inserted text (`++`), usually rendered underlined
+    Insert(Vec<Inline>),
+
+    /// Autolink (`<https://>` or `<mailto:…>`), or a bare URL, `www.` link or
+    /// email address
+    Autolink {
+        /// Destination as written
+        url: String,
+
+        /// Whether the link was written without angle brackets
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        bare: bool,
+    },
 
     /// Footnote reference (`[^label]`)
     FootnoteReference(String),
 
+    /// Wiki-style link (`[[Target]]`, `[[Target|alias]]` or `![[Target]]`)
+    WikiLink(WikiLink),
+
+    /// Bracketed span with an attribute list (`[text]{.class}`)
+    Span(Span),
+
+    /// Reference to a GitHub user, issue or commit (`@alice`, `#123`, `owner/repo#45`)
+    GitHubReference(GitHubReference),
+
+    /// Pandoc citation (`[see @doe99, p. 33; -@smith04]`, `@doe99 [p. 33]`)
+    Citation(Citation),
+
+    /// Emoji shortcode (`:rocket:`)
+    Emoji {
+        /// Name between the colons
+        shortcode: String,
+
+        /// The emoji the shortcode stands for
+        glyph: String,
+    },
+
+    /// Abbreviation with a definition elsewhere in the document, found by the
+    /// abbreviation resolution pass
+    Abbreviation {
+        /// Abbreviation as written
+        text: String,
+
+        /// Expansion from the definition
+        title: String,
+    },
+
     /// Empty element. This is used to represent skipped elements in the AST.
     Empty,
+
+    /// An inline node defined by a plugin
+    Extension(InlineExtension),
+}
+
+/// An inline node defined by a plugin, e.g. produced by a custom parser.
+///
+/// Printers render the children unless a renderer for extension nodes is
+/// configured.
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InlineExtension {
+    /// Name identifying the kind of node, chosen by the plugin.
+    pub name: String,
+
+    /// Attributes of the node.
+    pub attrs: Vec<(String, String)>,
+
+    /// The inlines inside the node.
+    pub children: Vec<Inline>,
+}
+
+/// Bracketed span (`[text]{#id .class key=value}`): inlines carrying an
+/// attribute list.
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// Attributes of the span.
+    pub attributes: Attributes,
+
+    /// The inlines inside the span.
+    pub children: Vec<Inline>,
 }
 
-/// Attributes for an image.
+/// Attribute list `{#id .class key=value}` attached to headings, fenced code
+/// blocks, tables, links, images and spans.
 #[derive(Debug, Clone, PartialEq, Hash, Eq, Default)]
 #[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ImageAttributes {
-    /// Width of the image.
-    pub width: Option<String>,
-    /// Height of the image.
-    pub height: Option<String>,
+pub struct Attributes {
+    /// Identifier (`#id`).
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub id: Option<String>,
+
+    /// Classes (`.class`), in the order they were written.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub classes: Vec<String>,
+
+    /// Other attributes (`key=value` or `key="value"`), in the order they were written.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub pairs: Vec<(String, String)>,
 }
 
 /// Re‑usable structure for links and images (destination + children).
@@ -395,6 +698,10 @@ pub struct Link {
 
     /// Inline content (text, code, etc.) inside the link or image.
     pub children: Vec<Inline>,
+
+    /// Attributes given after the link (e.g., `[text](/url){.external}`).
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub attributes: Attributes,
 }
 
 /// Re‑usable structure for links and images (destination + children).
@@ -410,9 +717,9 @@ pub struct Image {
     /// Alternative text.
     pub alt: String,
 
-    /// Image attributes.
+    /// Attributes given after the image (e.g., `![alt](/img.png){width=50%}`).
     #[cfg_attr(feature = "ast-serde", serde(default))]
-    pub attr: Option<ImageAttributes>,
+    pub attributes: Attributes,
 }
 
 /// Reference-style link (e.g., `[text][label]` or `[label][]`).
@@ -424,6 +731,244 @@ pub struct LinkReference {
 
     /// Link text
     pub text: Vec<Inline>,
+
+    /// `true` for image references (`![alt][label]`).
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub image: bool,
+}
+
+/// Wiki-style link (e.g., `[[Page#Section|label]]`), or an embed (`![[Page]]`).
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WikiLink {
+    /// Name of the page the link points to. Empty for links within the same page
+    /// (`[[#Section]]`).
+    pub target: String,
+
+    /// Heading fragment after `#`, if any.
+    pub fragment: Option<String>,
+
+    /// Text displayed instead of the target, after `|`.
+    pub alias: Option<String>,
+
+    /// `true` for embeds (`![[Page]]`).
+    pub embed: bool,
+}
+
+impl WikiLink {
+    /// The text displayed for the link: the alias if there is one, the target
+    /// and fragment as written otherwise
+    pub fn text(&self) -> String {
+        match (&self.alias, &self.fragment) {
+            (Some(alias), _) => alias.clone(),
+            (None, Some(fragment)) if self.target.is_empty() => fragment.clone(),
+            (None, Some(fragment)) => format!("{}#{}", self.target, fragment),
+            (None, None) => self.target.clone(),
+        }
+    }
+
+    /// The destination used by printers when targets are not resolved: the
+    /// target followed by `#fragment`
+    pub fn destination(&self) -> String {
+        match &self.fragment {
+            Some(fragment) => format!("{}#{}", self.target, fragment),
+            None => self.target.clone(),
+        }
+    }
+}
+
+/// Reference to a GitHub user, issue or commit, as found in release notes.
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GitHubReference {
+    /// What the reference points to.
+    pub kind: GitHubReferenceKind,
+
+    /// Destination given by the resolver of the parser configuration, if any.
+    pub destination: Option<String>,
+}
+
+/// Kind of a [`GitHubReference`].
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GitHubReferenceKind {
+    /// User or team mention (`@alice`, `@org/team`), without the `@`.
+    Mention(String),
+
+    /// Issue or pull request (`#123`, `owner/repo#45`).
+    Issue {
+        /// Repository (`owner/repo`) when given.
+        repository: Option<String>,
+        /// Number of the issue.
+        number: u64,
+    },
+
+    /// Commit (`a5c3785`, `owner/repo@a5c3785`).
+    Commit {
+        /// Repository (`owner/repo`) when given.
+        repository: Option<String>,
+        /// Abbreviated or full SHA.
+        sha: String,
+    },
+}
+
+impl GitHubReference {
+    /// The reference as written, e.g. `owner/repo#45`
+    pub fn text(&self) -> String {
+        match &self.kind {
+            GitHubReferenceKind::Mention(name) => format!("@{name}"),
+            GitHubReferenceKind::Issue { repository, number } => match repository {
+                Some(repository) => format!("{repository}#{number}"),
+                None => format!("#{number}"),
+            },
+            GitHubReferenceKind::Commit { repository, sha } => match repository {
+                Some(repository) => format!("{repository}@{sha}"),
+                None => sha.clone(),
+            },
+        }
+    }
+
+    /// The page of the reference on github.com, taking `repository`
+    /// (`owner/repo`) for issues and commits that do not name one
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::{GitHubReference, GitHubReferenceKind};
+    ///
+    /// let reference = GitHubReference {
+    ///     kind: GitHubReferenceKind::Issue { repository: None, number: 123 },
+    ///     destination: None,
+    /// };
+    /// assert_eq!(
+    ///     reference.github_url("owner/repo"),
+    ///     "https://github.com/owner/repo/issues/123"
+    /// );
+    /// ```
+    pub fn github_url(&self, repository: &str) -> String {
+        match &self.kind {
+            GitHubReferenceKind::Mention(name) => match name.split_once('/') {
+                Some((org, team)) => format!("https://github.com/orgs/{org}/teams/{team}"),
+                None => format!("https://github.com/{name}"),
+            },
+            GitHubReferenceKind::Issue {
+                repository: repo,
+                number,
+            } => format!(
+                "https://github.com/{}/issues/{number}",
+                repo.as_deref().unwrap_or(repository)
+            ),
+            GitHubReferenceKind::Commit {
+                repository: repo,
+                sha,
+            } => format!(
+                "https://github.com/{}/commit/{sha}",
+                repo.as_deref().unwrap_or(repository)
+            ),
+        }
+    }
+}
+
+/// Pandoc citation of one or more bibliography entries.
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Citation {
+    /// Cited entries, in order.
+    pub items: Vec<CitationItem>,
+
+    /// Whether the citation is part of the sentence (`@doe99` or `@doe99 [p. 33]`)
+    /// rather than between brackets. Such a citation has a single item.
+    pub in_text: bool,
+}
+
+/// Entry of a [`Citation`].
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CitationItem {
+    /// Citation key, without the `@`.
+    pub key: String,
+
+    /// Text before the key as written (`see` in `[see @doe99]`), if any.
+    pub prefix: Option<String>,
+
+    /// Text after the key as written, without the separating comma (`p. 33` in
+    /// `[@doe99, p. 33]`), if any.
+    pub locator: Option<String>,
+
+    /// Whether the author is left out of the rendered citation (`[-@doe99]`).
+    pub suppress_author: bool,
+}
+
+impl Citation {
+    /// The keys of the cited entries, in order
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().map(|item| item.key.as_str())
+    }
+
+    /// The citation as written, e.g. `[see @doe99, p. 33; -@smith04]`
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::{Citation, CitationItem};
+    ///
+    /// let citation = Citation {
+    ///     items: vec![CitationItem {
+    ///         key: "doe99".to_string(),
+    ///         prefix: None,
+    ///         locator: Some("p. 33".to_string()),
+    ///         suppress_author: false,
+    ///     }],
+    ///     in_text: true,
+    /// };
+    /// assert_eq!(citation.text(), "@doe99 [p. 33]");
+    /// ```
+    pub fn text(&self) -> String {
+        if self.in_text {
+            return self
+                .items
+                .iter()
+                .map(|item| match &item.locator {
+                    Some(locator) => format!("@{} [{locator}]", item.key),
+                    None => format!("@{}", item.key),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+        let items: Vec<_> = self
+            .items
+            .iter()
+            .map(|item| {
+                let mut text = String::new();
+                if let Some(prefix) = &item.prefix {
+                    text.push_str(prefix);
+                    text.push(' ');
+                }
+                if item.suppress_author {
+                    text.push('-');
+                }
+                text.push('@');
+                text.push_str(&item.key);
+                if let Some(locator) = &item.locator {
+                    text.push_str(", ");
+                    text.push_str(locator);
+                }
+                text
+            })
+            .collect();
+        format!("[{}]", items.join("; "))
+    }
+}
+
+impl Attributes {
+    /// Whether the list holds no id, class or key-value pair.
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none() && self.classes.is_empty() && self.pairs.is_empty()
+    }
+
+    /// The value of the first pair with the given key (e.g., `width`).
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
 }
 
 // ——————————————————————————————————————————————————————————————————————————
@@ -0,0 +1,58 @@
+use crate::ast::Inline;
+
+/// Collect the plain text content of inline elements
+///
+/// Formatting is dropped, code spans, LaTeX and autolinks keep their source
+/// text, images contribute their alt text and emoji their glyph. Raw HTML and
+/// footnote references contribute nothing. This is the text used for image
+/// alt text and heading slugs.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::{plain_text, Inline};
+///
+/// let inlines = vec![
+///     Inline::Text("Hello, ".to_string()),
+///     Inline::Strong(vec![Inline::Text("world".to_string())]),
+///     Inline::Html("<br>".to_string()),
+/// ];
+/// assert_eq!(plain_text(&inlines), "Hello, world");
+/// ```
+pub fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+    push_text(inlines, &mut text);
+    text
+}
+
+fn push_text(inlines: &[Inline], text: &mut String) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(s)
+            | Inline::Code(s)
+            | Inline::Latex { content: s, .. }
+            | Inline::Autolink { url: s, .. }
+            | Inline::Emoji { glyph: s, .. } => text.push_str(s),
+            Inline::LineBreak => text.push('\n'),
+            Inline::Image(image) => text.push_str(&image.alt),
+            Inline::Link(link) => push_text(&link.children, text),
+            Inline::LinkReference(link) => push_text(&link.text, text),
+            Inline::Emphasis(children)
+            | Inline::Strong(children)
+            | Inline::Strikethrough(children)
+            | Inline::Superscript(children)
+            | Inline::Subscript(children)
+            | Inline::Highlight(children)
+            | Inline::Insert(children) => push_text(children, text),
+            Inline::WikiLink(wiki_link) => text.push_str(&wiki_link.text()),
+            Inline::Span(span) => push_text(&span.children, text),
+            Inline::GitHubReference(reference) => text.push_str(&reference.text()),
+            Inline::Citation(citation) => text.push_str(&citation.text()),
+            Inline::Abbreviation {
+                text: abbreviation, ..
+            } => text.push_str(abbreviation),
+            Inline::Extension(extension) => push_text(&extension.children, text),
+            Inline::Html(_) | Inline::FootnoteReference(_) | Inline::Empty => {}
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::ast::{plain_text, Heading};
+use std::collections::HashMap;
+
+/// Generator of unique heading identifiers, compatible with GitHub
+///
+/// Slugs are computed with [`slugify`]. A slug that was already returned gets
+/// a `-1`, `-2`, … suffix, as GitHub does for repeated headings. Identifiers
+/// must be requested in document order to match the anchors GitHub creates.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::Slugger;
+///
+/// let mut slugger = Slugger::new();
+/// assert_eq!(slugger.slug("Hello, World!"), "hello-world");
+/// assert_eq!(slugger.slug("Hello World"), "hello-world-1");
+/// assert_eq!(slugger.slug("hello-world-1"), "hello-world-1-1");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Slugger {
+    /// Number of repetitions of every slug returned so far
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    /// Create a slugger that has not returned any slug yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the unique slug of `text`
+    pub fn slug(&mut self, text: &str) -> String {
+        let original = slugify(text);
+        let mut slug = original.clone();
+        while self.occurrences.contains_key(&slug) {
+            let count = self.occurrences.entry(original.clone()).or_default();
+            *count += 1;
+            slug = format!("{original}-{count}");
+        }
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+
+    /// Get the identifier of `heading`
+    ///
+    /// This is the explicit `{#id}` of the heading if it has one, and the
+    /// unique slug of its text otherwise. Explicit identifiers are reserved,
+    /// so that later slugs do not collide with them.
+    pub fn heading_id(&mut self, heading: &Heading) -> String {
+        match &heading.attributes.id {
+            Some(id) => {
+                self.occurrences.entry(id.clone()).or_default();
+                id.clone()
+            }
+            None => self.slug(&plain_text(&heading.content)),
+        }
+    }
+}
+
+/// Compute the GitHub slug of `text`, without deduplication
+///
+/// The text is lowercased, everything except letters, numbers, marks, `_`,
+/// `-` and spaces is removed, and spaces become `-`.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::slugify;
+///
+/// assert_eq!(slugify("Getting Started: `cargo add`"), "getting-started-cargo-add");
+/// assert_eq!(slugify("Größe & Gewicht"), "größe--gewicht");
+/// ```
+pub fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || is_mark(c) || matches!(c, '_' | '-' | ' '))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Whether `c` is a combining mark that GitHub keeps in slugs
+fn is_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Attributes, Inline};
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+        assert_eq!(
+            slugify("  Leading and trailing  "),
+            "--leading-and-trailing--"
+        );
+        assert_eq!(
+            slugify("snake_case and kebab-case"),
+            "snake_case-and-kebab-case"
+        );
+        assert_eq!(slugify("C++ & Rust (2021)!"), "c--rust-2021");
+        assert_eq!(slugify("Привет, мир"), "привет-мир");
+        assert_eq!(slugify("café\u{301} 🚀 launch"), "café\u{301}--launch");
+    }
+
+    #[test]
+    fn test_heading_ids_are_unique() {
+        let heading = |text: &str, id: Option<&str>| Heading {
+            kind: crate::ast::HeadingKind::Atx(1),
+            attributes: Attributes {
+                id: id.map(str::to_string),
+                ..Default::default()
+            },
+            content: vec![
+                Inline::Text(text.to_string()),
+                Inline::Code("x".to_string()),
+            ],
+        };
+        let mut slugger = Slugger::new();
+        assert_eq!(
+            slugger.heading_id(&heading("Intro ", Some("intro-x"))),
+            "intro-x"
+        );
+        assert_eq!(slugger.heading_id(&heading("Intro ", None)), "intro-x-1");
+        assert_eq!(slugger.heading_id(&heading("Intro ", None)), "intro-x-2");
+        assert_eq!(slugger.heading_id(&heading("", None)), "x");
+    }
+}
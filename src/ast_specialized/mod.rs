@@ -1,7 +1,7 @@
-//! Specialized AST types for element ID tracking
+//! Specialized AST types for element ID and source span tracking
 //!
 //! This module provides pre-defined specialized versions of the generic AST
-//! for element identification scenarios.
+//! for element identification and source location scenarios.
 //!
 //! # Quick Start
 //!
@@ -16,13 +16,14 @@
 //!
 //! ```rust
 //! use markdown_ppp::ast_specialized::{ElementId, with_ids, utilities::id_utils};
-//! use markdown_ppp::ast::{Document, Block, Heading, HeadingKind, Inline};
+//! use markdown_ppp::ast::{Attributes, Document, Block, Heading, HeadingKind, Inline};
 //!
 //! // Create a regular document
 //! let doc = Document {
 //!     blocks: vec![
 //!         Block::Heading(Heading {
 //!             kind: HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
 //!             content: vec![Inline::Text("Hello World".to_string())],
 //!         })
 //!     ],
@@ -38,18 +39,21 @@
 //! # Organization
 //!
 //! - `element_id` - Element ID support and related functionality
+//! - `span` - Source positions of elements
 //! - `type_aliases` - Convenient type aliases for specialized AST types
 //! - `utilities` - Helper functions and utilities
 
 pub mod element_id;
+pub mod span;
 pub mod type_aliases;
 pub mod utilities;
 
 // Re-export main types for convenience
 pub use element_id::ElementId;
+pub use span::{Position, Span};
 
 // Re-export type alias modules
-pub use type_aliases::with_ids;
+pub use type_aliases::{with_ids, with_spans};
 
 // Re-export utility modules
 pub use utilities::id_utils;
@@ -0,0 +1,90 @@
+//! Source positions for AST nodes
+//!
+//! This module provides the [`Span`] type describing where an element was
+//! found in the parsed text. Spans are filled in by
+//! [`parse_markdown_with_spans`](crate::parser::parse_markdown_with_spans).
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast_specialized::span::{Position, Span};
+//!
+//! let span = Span::new(
+//!     Position::new(0, 1, 1),
+//!     Position::new(7, 1, 8),
+//! );
+//! assert_eq!(span.len(), 7);
+//! assert_eq!(span.range(), 0..7);
+//! ```
+
+/// A position in the source text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    /// Byte offset from the start of the input
+    pub offset: usize,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number in characters, starting at 1
+    pub column: usize,
+}
+
+impl Position {
+    /// Create a new position
+    pub fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+/// Source range of an AST element
+///
+/// The `end` position is exclusive: it points just past the last character
+/// of the element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// Position of the first character
+    pub start: Position,
+    /// Position just past the last character
+    pub end: Position,
+}
+
+impl Span {
+    /// Create a new span
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Byte range of the span, suitable for slicing the input
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start.offset..self.end.offset
+    }
+
+    /// Length of the span in bytes
+    pub fn len(&self) -> usize {
+        self.end.offset.saturating_sub(self.start.offset)
+    }
+
+    /// Check if the span is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_range() {
+        let span = Span::new(Position::new(4, 2, 1), Position::new(9, 2, 6));
+        assert_eq!(span.range(), 4..9);
+        assert_eq!(span.len(), 5);
+        assert!(!span.is_empty());
+        assert!(Span::default().is_empty());
+    }
+}
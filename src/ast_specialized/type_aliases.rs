@@ -1,19 +1,20 @@
 //! Type aliases for specialized AST types
 //!
 //! This module provides convenient type aliases for generic AST types
-//! specialized with element IDs.
+//! specialized with element IDs or source spans.
 //!
 //! # Example
 //!
 //! ```rust
 //! use markdown_ppp::ast_specialized::{with_ids, ElementId};
-//! use markdown_ppp::ast::generic;
+//! use markdown_ppp::ast::{generic, Attributes};
 //!
 //! // Use type aliases for cleaner code
 //! let doc: with_ids::Document = generic::Document {
 //!     blocks: vec![
 //!         generic::Block::Heading(generic::Heading {
 //!             kind: markdown_ppp::ast::HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
 //!             content: vec![
 //!                 generic::Inline::Text {
 //!                     content: "Hello".to_string(),
@@ -27,7 +28,7 @@
 //! };
 //! ```
 
-use super::ElementId;
+use super::{ElementId, Span};
 use crate::ast::generic;
 
 /// AST types with element IDs
@@ -82,3 +83,56 @@ pub mod with_ids {
     /// Link reference with element ID
     pub type LinkReference = generic::LinkReference<ElementId>;
 }
+
+/// AST types with source spans
+pub mod with_spans {
+    use super::*;
+
+    /// Document with source spans
+    pub type Document = generic::Document<Span>;
+
+    /// Block with source span
+    pub type Block = generic::Block<Span>;
+
+    /// Inline element with source span
+    pub type Inline = generic::Inline<Span>;
+
+    /// Heading with source span
+    pub type Heading = generic::Heading<Span>;
+
+    /// List with source span
+    pub type List = generic::List<Span>;
+
+    /// List item with source span
+    pub type ListItem = generic::ListItem<Span>;
+
+    /// Code block with source span
+    pub type CodeBlock = generic::CodeBlock<Span>;
+
+    /// Link definition with source span
+    pub type LinkDefinition = generic::LinkDefinition<Span>;
+
+    /// Table with source span
+    pub type Table = generic::Table<Span>;
+
+    /// Table row with source spans
+    pub type TableRow = generic::TableRow<Span>;
+
+    /// Table cell with source spans
+    pub type TableCell = generic::TableCell<Span>;
+
+    /// Footnote definition with source span
+    pub type FootnoteDefinition = generic::FootnoteDefinition<Span>;
+
+    /// GitHub alert with source span
+    pub type GitHubAlert = generic::GitHubAlertNode<Span>;
+
+    /// Link with source span
+    pub type Link = generic::Link<Span>;
+
+    /// Image with source span
+    pub type Image = generic::Image<Span>;
+
+    /// Link reference with source span
+    pub type LinkReference = generic::LinkReference<Span>;
+}
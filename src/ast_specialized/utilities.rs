@@ -7,13 +7,14 @@
 //!
 //! ```rust
 //! use markdown_ppp::ast_specialized::utilities::id_utils;
-//! use markdown_ppp::ast::{Document, Block, Heading, HeadingKind, Inline};
+//! use markdown_ppp::ast::{Attributes, Document, Block, Heading, HeadingKind, Inline};
 //!
 //! // Create a regular document
 //! let doc = Document {
 //!     blocks: vec![
 //!         Block::Heading(Heading {
 //!             kind: HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
 //!             content: vec![Inline::Text("Title".to_string())],
 //!         })
 //!     ],
@@ -91,13 +92,14 @@ pub mod id_utils {
     #[cfg(test)]
     mod tests {
         use super::*;
-        use crate::ast::{Block, Heading, HeadingKind, Inline};
+        use crate::ast::{Attributes, Block, Heading, HeadingKind, Inline};
 
         #[test]
         fn test_add_ids_to_document() {
             let doc = crate::ast::Document {
                 blocks: vec![Block::Heading(Heading {
                     kind: HeadingKind::Atx(1),
+                    attributes: Attributes::default(),
                     content: vec![Inline::Text("Test".to_string())],
                 })],
             };
@@ -129,6 +131,7 @@ pub mod id_utils {
             let doc = crate::ast::Document {
                 blocks: vec![Block::Heading(Heading {
                     kind: HeadingKind::Atx(1),
+                    attributes: Attributes::default(),
                     content: vec![Inline::Text("Test".to_string())],
                 })],
             };
@@ -0,0 +1,244 @@
+//! Resolution of abbreviations against abbreviation definitions
+//!
+//! The parser keeps abbreviation definitions (`*[HTML]: HyperText Markup
+//! Language`) as [`Block::AbbreviationDefinition`] and leaves the text they
+//! apply to alone. [`AbbreviationResolver`] wraps every whole-word occurrence
+//! of a defined abbreviation in an [`Inline::Abbreviation`] carrying the
+//! expansion as its title. Matching is case-sensitive, and longer
+//! abbreviations win over shorter ones. Code, HTML and link destinations are
+//! left untouched.
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::ast_transform::{AbbreviationResolver, TransformWith};
+//! use markdown_ppp::parser::config::{ElementBehavior, MarkdownParserConfig};
+//! use markdown_ppp::parser::{parse_markdown, MarkdownParserState};
+//!
+//! let config = MarkdownParserConfig::default()
+//!     .with_block_abbreviation_definition_behavior(ElementBehavior::Parse);
+//! let doc = parse_markdown(
+//!     MarkdownParserState::with_config(config),
+//!     "Write HTML.\n\n*[HTML]: HyperText Markup Language\n",
+//! )
+//! .unwrap();
+//!
+//! let mut resolver = AbbreviationResolver::new(&doc);
+//! let doc = doc.transform_with(&mut resolver);
+//!
+//! assert_eq!(
+//!     doc.blocks[0],
+//!     Block::Paragraph(vec![
+//!         Inline::Text("Write ".to_string()),
+//!         Inline::Abbreviation {
+//!             text: "HTML".to_string(),
+//!             title: "HyperText Markup Language".to_string(),
+//!         },
+//!         Inline::Text(".".to_string()),
+//!     ])
+//! );
+//! ```
+
+use super::transformer::Transformer;
+use crate::ast::*;
+
+/// A transformer that marks up abbreviations that have a definition
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Default)]
+pub struct AbbreviationResolver {
+    /// Abbreviations and their expansions, longest abbreviation first
+    definitions: Vec<(String, String)>,
+}
+
+impl AbbreviationResolver {
+    /// Create a resolver for the abbreviation definitions in `doc`
+    ///
+    /// When an abbreviation is defined more than once, the first definition wins.
+    pub fn new(doc: &Document) -> Self {
+        let mut resolver = Self::default();
+        resolver.add_definitions(&doc.blocks);
+        resolver
+    }
+
+    /// Create a resolver for the given abbreviations and expansions
+    pub fn with_definitions<'a>(definitions: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut resolver = Self::default();
+        for (abbreviation, expansion) in definitions {
+            resolver.add_definition(abbreviation, expansion);
+        }
+        resolver
+    }
+
+    fn add_definition(&mut self, abbreviation: &str, expansion: &str) {
+        if self.definitions.iter().any(|(a, _)| a == abbreviation) {
+            return;
+        }
+        // Keep the longest abbreviations first, so that they are tried first
+        let index = self
+            .definitions
+            .partition_point(|(a, _)| a.len() >= abbreviation.len());
+        self.definitions
+            .insert(index, (abbreviation.to_owned(), expansion.to_owned()));
+    }
+
+    fn add_definitions(&mut self, blocks: &[Block]) {
+        for block in blocks {
+            match block {
+                Block::AbbreviationDefinition {
+                    abbreviation,
+                    expansion,
+                } => self.add_definition(abbreviation, expansion),
+                Block::BlockQuote(blocks) => self.add_definitions(blocks),
+                Block::List(list) => {
+                    for item in &list.items {
+                        self.add_definitions(&item.blocks);
+                    }
+                }
+                Block::DefinitionList(list) => {
+                    for item in &list.items {
+                        item.definitions
+                            .iter()
+                            .for_each(|blocks| self.add_definitions(blocks));
+                    }
+                }
+                Block::FootnoteDefinition(footnote) => self.add_definitions(&footnote.blocks),
+                Block::GitHubAlert(alert) => self.add_definitions(&alert.blocks),
+                Block::Container(container) => self.add_definitions(&container.blocks),
+                Block::Extension(extension) => self.add_definitions(&extension.children),
+                _ => {}
+            }
+        }
+    }
+
+    /// Split a text around the abbreviations it holds as whole words
+    fn resolve_text(&self, text: String, result: &mut Vec<Inline>) {
+        let mut start = 0;
+        let mut i = 0;
+        let mut prev: Option<char> = None;
+        while let Some(c) = text[i..].chars().next() {
+            let found = if prev.is_some_and(is_word_char) {
+                None
+            } else {
+                self.definitions.iter().find(|(abbreviation, _)| {
+                    text[i..].starts_with(abbreviation.as_str())
+                        && !text[i + abbreviation.len()..]
+                            .chars()
+                            .next()
+                            .is_some_and(is_word_char)
+                })
+            };
+            match found {
+                Some((abbreviation, expansion)) => {
+                    if start < i {
+                        result.push(Inline::Text(text[start..i].to_owned()));
+                    }
+                    result.push(Inline::Abbreviation {
+                        text: abbreviation.clone(),
+                        title: expansion.clone(),
+                    });
+                    i += abbreviation.len();
+                    start = i;
+                    prev = abbreviation.chars().next_back();
+                }
+                None => {
+                    i += c.len_utf8();
+                    prev = Some(c);
+                }
+            }
+        }
+        if start == 0 {
+            result.push(Inline::Text(text));
+        } else if start < text.len() {
+            result.push(Inline::Text(text[start..].to_owned()));
+        }
+    }
+
+    /// Resolve the abbreviations in a sequence of inlines
+    fn resolve_inlines(&mut self, inlines: Vec<Inline>) -> Vec<Inline> {
+        let mut result = Vec::with_capacity(inlines.len());
+        for inline in inlines {
+            match inline {
+                Inline::Text(text) => self.resolve_text(text, &mut result),
+                other => result.push(self.transform_inline(other)),
+            }
+        }
+        result
+    }
+}
+
+impl Transformer for AbbreviationResolver {
+    fn transform_block(&mut self, block: Block) -> Block {
+        match block {
+            Block::Paragraph(inlines) => Block::Paragraph(self.resolve_inlines(inlines)),
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+            } => Block::Figure {
+                content: content
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect(),
+                caption: self.resolve_inlines(caption),
+                label,
+                caption_syntax,
+            },
+            // Labels of link definitions must keep matching their references
+            Block::Definition(definition) => Block::Definition(definition),
+            other => self.walk_transform_block(other),
+        }
+    }
+
+    fn transform_inline(&mut self, inline: Inline) -> Inline {
+        match inline {
+            Inline::Emphasis(inlines) => Inline::Emphasis(self.resolve_inlines(inlines)),
+            Inline::Strong(inlines) => Inline::Strong(self.resolve_inlines(inlines)),
+            Inline::Strikethrough(inlines) => Inline::Strikethrough(self.resolve_inlines(inlines)),
+            Inline::Superscript(inlines) => Inline::Superscript(self.resolve_inlines(inlines)),
+            Inline::Subscript(inlines) => Inline::Subscript(self.resolve_inlines(inlines)),
+            Inline::Highlight(inlines) => Inline::Highlight(self.resolve_inlines(inlines)),
+            Inline::Insert(inlines) => Inline::Insert(self.resolve_inlines(inlines)),
+            Inline::Span(mut span) => {
+                span.children = self.resolve_inlines(span.children);
+                Inline::Span(span)
+            }
+            Inline::LinkReference(mut link_ref) => {
+                link_ref.text = self.resolve_inlines(link_ref.text);
+                Inline::LinkReference(link_ref)
+            }
+            other => self.walk_transform_inline(other),
+        }
+    }
+
+    fn transform_heading(&mut self, mut heading: Heading) -> Heading {
+        heading.content = self.resolve_inlines(heading.content);
+        heading
+    }
+
+    fn transform_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem,
+    ) -> DefinitionListItem {
+        let term = self.resolve_inlines(std::mem::take(&mut item.term));
+        let mut item = self.walk_transform_definition_list_item(item);
+        item.term = term;
+        item
+    }
+
+    fn transform_table_cell(&mut self, mut cell: TableCell) -> TableCell {
+        cell.content = self.resolve_inlines(cell.content);
+        cell
+    }
+
+    fn transform_link(&mut self, mut link: Link) -> Link {
+        link.children = self.resolve_inlines(link.children);
+        link
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
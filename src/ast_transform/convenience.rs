@@ -55,7 +55,7 @@ pub trait Transform {
     ///         destination: "/image.jpg".to_string(),
     ///         title: None,
     ///         alt: "test".to_string(),
-    ///         attr: None,
+    ///         attributes: Attributes::default(),
     ///     })])],
     /// };
     /// let result = doc.transform_image_urls(|url| {
@@ -79,6 +79,7 @@ pub trait Transform {
     ///         destination: "http://example.com".to_string(),
     ///         title: None,
     ///         children: vec![Inline::Text("link".to_string())],
+    ///         attributes: Attributes::default(),
     ///     })])],
     /// };
     /// let result = doc.transform_link_urls(|url| {
@@ -288,7 +289,10 @@ where
 {
     fn transform_inline(&mut self, inline: Inline) -> Inline {
         match inline {
-            Inline::Autolink(url) => Inline::Autolink((self.func)(url)),
+            Inline::Autolink { url, bare } => Inline::Autolink {
+                url: (self.func)(url),
+                bare,
+            },
             other => self.walk_transform_inline(other),
         }
     }
@@ -74,6 +74,14 @@ pub trait GenericTransformer<T: Default> {
         self.walk_transform_list_item(item)
     }
 
+    /// Transform a definition list item with user data
+    fn transform_definition_list_item(
+        &mut self,
+        item: DefinitionListItem<T>,
+    ) -> DefinitionListItem<T> {
+        self.walk_transform_definition_list_item(item)
+    }
+
     /// Transform a table row with user data
     fn transform_table_row(&mut self, row: TableRow<T>) -> TableRow<T> {
         self.walk_transform_table_row(row)
@@ -141,6 +149,14 @@ pub trait GenericTransformer<T: Default> {
         vec![self.transform_list_item(item)]
     }
 
+    /// Transform a definition list item with possibility to expand into multiple items
+    fn expand_definition_list_item(
+        &mut self,
+        item: DefinitionListItem<T>,
+    ) -> Vec<DefinitionListItem<T>> {
+        vec![self.transform_definition_list_item(item)]
+    }
+
     /// Transform a table row with possibility to expand into multiple rows
     fn expand_table_row(&mut self, row: TableRow<T>) -> Vec<TableRow<T>> {
         vec![self.transform_table_row(row)]
@@ -212,7 +228,34 @@ pub trait GenericTransformer<T: Default> {
                 user_data,
             },
             Block::List(list) => Block::List(self.transform_list_item_container(list)),
+            Block::DefinitionList(mut list) => {
+                list.items = list
+                    .items
+                    .into_iter()
+                    .map(|item| self.transform_definition_list_item(item))
+                    .collect();
+                Block::DefinitionList(list)
+            }
             Block::Table(table) => Block::Table(self.transform_table(table)),
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+                user_data,
+            } => Block::Figure {
+                content: content
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect(),
+                caption: caption
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                label,
+                caption_syntax,
+                user_data,
+            },
             Block::FootnoteDefinition(footnote) => {
                 Block::FootnoteDefinition(self.transform_footnote_definition(footnote))
             }
@@ -226,6 +269,14 @@ pub trait GenericTransformer<T: Default> {
                 Block::Definition(def)
             }
             Block::CodeBlock(code_block) => Block::CodeBlock(self.transform_code_block(code_block)),
+            Block::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect();
+                Block::Extension(extension)
+            }
             // Terminal nodes - no transformation needed
             other => other,
         }
@@ -255,6 +306,34 @@ pub trait GenericTransformer<T: Default> {
                     .collect(),
                 user_data,
             },
+            Inline::Superscript { content, user_data } => Inline::Superscript {
+                content: content
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                user_data,
+            },
+            Inline::Subscript { content, user_data } => Inline::Subscript {
+                content: content
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                user_data,
+            },
+            Inline::Highlight { content, user_data } => Inline::Highlight {
+                content: content
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                user_data,
+            },
+            Inline::Insert { content, user_data } => Inline::Insert {
+                content: content
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                user_data,
+            },
             Inline::Link(link) => Inline::Link(self.transform_link(link)),
             Inline::LinkReference(mut link_ref) => {
                 link_ref.label = link_ref
@@ -270,6 +349,22 @@ pub trait GenericTransformer<T: Default> {
                 Inline::LinkReference(link_ref)
             }
             Inline::Image(image) => Inline::Image(self.transform_image(image)),
+            Inline::Span(mut span) => {
+                span.children = span
+                    .children
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect();
+                Inline::Span(span)
+            }
+            Inline::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect();
+                Inline::Extension(extension)
+            }
             // Terminal nodes - no transformation needed
             other => other,
         }
@@ -295,6 +390,29 @@ pub trait GenericTransformer<T: Default> {
         item
     }
 
+    /// Default transformation for definition list items with user data
+    fn walk_transform_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem<T>,
+    ) -> DefinitionListItem<T> {
+        item.term = item
+            .term
+            .into_iter()
+            .map(|inline| self.transform_inline(inline))
+            .collect();
+        item.definitions = item
+            .definitions
+            .into_iter()
+            .map(|blocks| {
+                blocks
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect()
+            })
+            .collect();
+        item
+    }
+
     /// Default transformation for table rows with user data
     fn walk_transform_table_row(&mut self, row: TableRow<T>) -> TableRow<T> {
         row.into_iter()
@@ -418,10 +536,37 @@ pub trait GenericTransformer<T: Default> {
                 let expanded_list = self.expand_list_container(list);
                 return expanded_list.into_iter().map(Block::List).collect();
             }
+            Block::DefinitionList(mut list) => {
+                list.items = list
+                    .items
+                    .into_iter()
+                    .flat_map(|item| self.expand_definition_list_item(item))
+                    .collect();
+                Block::DefinitionList(list)
+            }
             Block::Table(table) => {
                 let expanded_table = self.expand_table_container(table);
                 return expanded_table.into_iter().map(Block::Table).collect();
             }
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+                user_data,
+            } => Block::Figure {
+                content: content
+                    .into_iter()
+                    .flat_map(|block| self.walk_expand_block(block))
+                    .collect(),
+                caption: caption
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect(),
+                label,
+                caption_syntax,
+                user_data,
+            },
             Block::FootnoteDefinition(footnote) => {
                 let expanded_footnotes = self.expand_footnote_definition(footnote);
                 return expanded_footnotes
@@ -451,6 +596,14 @@ pub trait GenericTransformer<T: Default> {
                     .map(Block::CodeBlock)
                     .collect();
             }
+            Block::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .flat_map(|block| self.walk_expand_block(block))
+                    .collect();
+                Block::Extension(extension)
+            }
             // Terminal nodes - no transformation needed
             other => other,
         };
@@ -481,6 +634,34 @@ pub trait GenericTransformer<T: Default> {
                     .collect(),
                 user_data,
             },
+            Inline::Superscript { content, user_data } => Inline::Superscript {
+                content: content
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect(),
+                user_data,
+            },
+            Inline::Subscript { content, user_data } => Inline::Subscript {
+                content: content
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect(),
+                user_data,
+            },
+            Inline::Highlight { content, user_data } => Inline::Highlight {
+                content: content
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect(),
+                user_data,
+            },
+            Inline::Insert { content, user_data } => Inline::Insert {
+                content: content
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect(),
+                user_data,
+            },
             Inline::Link(link) => {
                 let expanded_links = self.expand_link(link);
                 return expanded_links.into_iter().map(Inline::Link).collect();
@@ -502,6 +683,22 @@ pub trait GenericTransformer<T: Default> {
                 let expanded_images = self.expand_image(image);
                 return expanded_images.into_iter().map(Inline::Image).collect();
             }
+            Inline::Span(mut span) => {
+                span.children = span
+                    .children
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect();
+                Inline::Span(span)
+            }
+            Inline::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .flat_map(|inline| self.walk_expand_inline(inline))
+                    .collect();
+                Inline::Extension(extension)
+            }
             // Terminal nodes - no transformation needed
             other => other,
         };
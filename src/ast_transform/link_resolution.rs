@@ -0,0 +1,157 @@
+//! Resolution of reference links against link definitions
+//!
+//! The parser keeps reference links (`[text][label]`) and link reference
+//! definitions (`[label]: /url "title"`) as separate nodes. [`LinkResolver`]
+//! replaces every reference that has a matching definition with an
+//! [`Inline::Link`], or an [`Inline::Image`] for `![alt][label]`, carrying the
+//! destination and title of the definition. Labels are matched with
+//! [`normalize_label`]. References without a definition are left in place
+//! and reported by [`LinkResolver::unresolved`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::ast_transform::{LinkResolver, TransformWith};
+//! use markdown_ppp::parser::{parse_markdown, MarkdownParserState};
+//!
+//! let doc = parse_markdown(
+//!     MarkdownParserState::new(),
+//!     "[Docs] and [missing]\n\n[docs]: https://docs.rs \"Docs\"\n",
+//! )
+//! .unwrap();
+//!
+//! let mut resolver = LinkResolver::new(&doc);
+//! let doc = doc.transform_with(&mut resolver);
+//!
+//! let Block::Paragraph(content) = &doc.blocks[0] else { unreachable!() };
+//! assert_eq!(
+//!     content[0],
+//!     Inline::Link(Link {
+//!         destination: "https://docs.rs".to_string(),
+//!         title: Some("Docs".to_string()),
+//!         children: vec![Inline::Text("Docs".to_string())],
+//!         attributes: Attributes::default(),
+//!     })
+//! );
+//! assert_eq!(resolver.unresolved().len(), 1);
+//! ```
+
+use super::transformer::Transformer;
+use crate::ast::*;
+use std::collections::HashMap;
+
+/// A transformer that replaces reference links with links and images
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Default)]
+pub struct LinkResolver {
+    /// Link definitions by normalized label
+    definitions: HashMap<String, LinkDefinition>,
+    /// References without a matching definition, in document order
+    unresolved: Vec<LinkReference>,
+}
+
+impl LinkResolver {
+    /// Create a resolver for the link definitions in `doc`
+    ///
+    /// When a label is defined more than once, the first definition wins.
+    pub fn new(doc: &Document) -> Self {
+        let mut resolver = Self::default();
+        resolver.add_definitions(&doc.blocks);
+        resolver
+    }
+
+    /// Create a resolver for the given link definitions
+    pub fn with_definitions<'a>(definitions: impl IntoIterator<Item = &'a LinkDefinition>) -> Self {
+        let mut resolver = Self::default();
+        for definition in definitions {
+            resolver.add_definition(definition);
+        }
+        resolver
+    }
+
+    /// References that had no matching definition, in document order
+    pub fn unresolved(&self) -> &[LinkReference] {
+        &self.unresolved
+    }
+
+    fn add_definition(&mut self, definition: &LinkDefinition) {
+        self.definitions
+            .entry(normalize_label(&definition.label))
+            .or_insert_with(|| definition.clone());
+    }
+
+    fn add_definitions(&mut self, blocks: &[Block]) {
+        for block in blocks {
+            match block {
+                Block::Definition(definition) => self.add_definition(definition),
+                Block::BlockQuote(blocks) => self.add_definitions(blocks),
+                Block::List(list) => {
+                    for item in &list.items {
+                        self.add_definitions(&item.blocks);
+                    }
+                }
+                Block::DefinitionList(list) => {
+                    for item in &list.items {
+                        item.definitions
+                            .iter()
+                            .for_each(|blocks| self.add_definitions(blocks));
+                    }
+                }
+                Block::FootnoteDefinition(footnote) => self.add_definitions(&footnote.blocks),
+                Block::GitHubAlert(alert) => self.add_definitions(&alert.blocks),
+                Block::Container(container) => self.add_definitions(&container.blocks),
+                Block::Extension(extension) => self.add_definitions(&extension.children),
+                _ => {}
+            }
+        }
+    }
+
+    /// Replace `link_ref` with a link or image if its label is defined
+    fn resolve(&mut self, link_ref: LinkReference) -> Inline {
+        let Some(definition) = self.definitions.get(&normalize_label(&link_ref.label)) else {
+            self.unresolved.push(link_ref.clone());
+            return Inline::LinkReference(link_ref);
+        };
+
+        let destination = definition.destination.clone();
+        let title = definition.title.clone();
+        if link_ref.image {
+            Inline::Image(Image {
+                destination,
+                title,
+                alt: plain_text(&link_ref.text),
+                attributes: Attributes::default(),
+            })
+        } else {
+            Inline::Link(Link {
+                destination,
+                title,
+                children: link_ref
+                    .text
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                attributes: Attributes::default(),
+            })
+        }
+    }
+}
+
+impl Transformer for LinkResolver {
+    fn transform_block(&mut self, block: Block) -> Block {
+        match block {
+            // Labels of definitions are not references
+            Block::Definition(definition) => Block::Definition(definition),
+            other => self.walk_transform_block(other),
+        }
+    }
+
+    fn transform_inline(&mut self, inline: Inline) -> Inline {
+        match inline {
+            Inline::LinkReference(link_ref) => self.resolve(link_ref),
+            other => self.walk_transform_inline(other),
+        }
+    }
+}
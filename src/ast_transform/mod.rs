@@ -6,6 +6,8 @@
 //! - Query API for finding elements by conditions
 //! - Convenience methods for common transformations
 //! - Pipeline builder for composing complex transformations
+//! - Resolution of reference links against link definitions
+//! - Resolution of abbreviations against abbreviation definitions
 //!
 //! # Examples
 //!
@@ -27,7 +29,7 @@
 //!
 //! // Find all autolinks (in a document that has them)
 //! let autolinks = doc.find_all_inlines(|inline| {
-//!     matches!(inline, Inline::Autolink(_))
+//!     matches!(inline, Inline::Autolink { .. })
 //! });
 //!
 //! // Complex pipeline
@@ -37,8 +39,10 @@
 //!     .apply(doc);
 //! ```
 
+pub mod abbreviation_resolution;
 pub mod convenience;
 pub mod generic_transformer;
+pub mod link_resolution;
 pub mod macro_expansion;
 pub mod pipeline;
 pub mod query;
@@ -48,8 +52,10 @@ pub mod visitor;
 #[cfg(test)]
 mod tests;
 
+pub use abbreviation_resolution::AbbreviationResolver;
 pub use convenience::*;
 pub use generic_transformer::*;
+pub use link_resolution::LinkResolver;
 pub use pipeline::*;
 pub use query::*;
 pub use transformer::*;
@@ -12,14 +12,17 @@
 //!     blocks: vec![
 //!         Block::Paragraph(vec![
 //!             Inline::Text("hello".to_string()),
-//!             Inline::Autolink("https://example.com".to_string()),
+//!             Inline::Autolink {
+//!                 url: "https://example.com".to_string(),
+//!                 bare: false,
+//!             },
 //!         ]),
 //!     ],
 //! };
 //!
 //! // Find all autolinks
 //! let autolinks = doc.find_all_inlines(|inline| {
-//!     matches!(inline, Inline::Autolink(_))
+//!     matches!(inline, Inline::Autolink { .. })
 //! });
 //! assert_eq!(autolinks.len(), 1);
 //!
@@ -121,15 +124,31 @@ pub trait Query {
 
     /// Find all autolinks in the document
     fn find_all_autolinks(&self) -> Vec<&str> {
-        self.find_all_inlines(|inline| matches!(inline, Inline::Autolink(_)))
+        self.find_all_inlines(|inline| matches!(inline, Inline::Autolink { .. }))
             .into_iter()
             .filter_map(|inline| match inline {
-                Inline::Autolink(url) => Some(url.as_str()),
+                Inline::Autolink { url, .. } => Some(url.as_str()),
                 _ => None,
             })
             .collect()
     }
 
+    /// Find the keys of all cited bibliography entries, each once, in order of first
+    /// citation
+    fn find_all_cited_keys(&self) -> Vec<&str> {
+        let mut keys = Vec::new();
+        for inline in self.find_all_inlines(|inline| matches!(inline, Inline::Citation(_))) {
+            if let Inline::Citation(citation) = inline {
+                for key in citation.keys() {
+                    if !keys.contains(&key) {
+                        keys.push(key);
+                    }
+                }
+            }
+        }
+        keys
+    }
+
     /// Find all text nodes in the document
     fn find_all_text(&self) -> Vec<&str> {
         self.find_all_inlines(|inline| matches!(inline, Inline::Text(_)))
@@ -336,6 +355,16 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for inline in &item.term {
+                    collect_inlines_from_inline(inline, predicate, results);
+                }
+                for block in item.definitions.iter().flatten() {
+                    collect_inlines_from_block(block, predicate, results);
+                }
+            }
+        }
         Block::Table(table) => {
             for row in &table.rows {
                 for cell in row {
@@ -345,6 +374,16 @@ where
                 }
             }
         }
+        Block::Figure {
+            content, caption, ..
+        } => {
+            for block in content {
+                collect_inlines_from_block(block, predicate, results);
+            }
+            for inline in caption {
+                collect_inlines_from_inline(inline, predicate, results);
+            }
+        }
         Block::FootnoteDefinition(footnote) => {
             for block in &footnote.blocks {
                 collect_inlines_from_block(block, predicate, results);
@@ -360,6 +399,11 @@ where
                 collect_inlines_from_inline(inline, predicate, results);
             }
         }
+        Block::Extension(extension) => {
+            for block in &extension.children {
+                collect_inlines_from_block(block, predicate, results);
+            }
+        }
         _ => {} // Terminal blocks
     }
 }
@@ -376,7 +420,13 @@ fn collect_inlines_from_inline<'a, F>(
     }
 
     match inline {
-        Inline::Emphasis(inlines) | Inline::Strong(inlines) | Inline::Strikethrough(inlines) => {
+        Inline::Emphasis(inlines)
+        | Inline::Strong(inlines)
+        | Inline::Strikethrough(inlines)
+        | Inline::Superscript(inlines)
+        | Inline::Subscript(inlines)
+        | Inline::Highlight(inlines)
+        | Inline::Insert(inlines) => {
             for inline in inlines {
                 collect_inlines_from_inline(inline, predicate, results);
             }
@@ -394,6 +444,16 @@ fn collect_inlines_from_inline<'a, F>(
                 collect_inlines_from_inline(inline, predicate, results);
             }
         }
+        Inline::Span(span) => {
+            for inline in &span.children {
+                collect_inlines_from_inline(inline, predicate, results);
+            }
+        }
+        Inline::Extension(extension) => {
+            for inline in &extension.children {
+                collect_inlines_from_inline(inline, predicate, results);
+            }
+        }
         _ => {} // Terminal inlines
     }
 }
@@ -419,6 +479,18 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for block in item.definitions.iter().flatten() {
+                    collect_blocks_from_block(block, predicate, results);
+                }
+            }
+        }
+        Block::Figure { content, .. } => {
+            for block in content {
+                collect_blocks_from_block(block, predicate, results);
+            }
+        }
         Block::FootnoteDefinition(footnote) => {
             for block in &footnote.blocks {
                 collect_blocks_from_block(block, predicate, results);
@@ -429,6 +501,11 @@ where
                 collect_blocks_from_block(block, predicate, results);
             }
         }
+        Block::Extension(extension) => {
+            for block in &extension.children {
+                collect_blocks_from_block(block, predicate, results);
+            }
+        }
         _ => {} // Terminal or inline-containing blocks
     }
 }
@@ -468,6 +545,20 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for inline in &item.term {
+                    if let Some(found) = find_first_inline_in_inline(inline, predicate) {
+                        return Some(found);
+                    }
+                }
+                for block in item.definitions.iter().flatten() {
+                    if let Some(found) = find_first_inline_in_block(block, predicate) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
         Block::Table(table) => {
             for row in &table.rows {
                 for cell in row {
@@ -479,6 +570,20 @@ where
                 }
             }
         }
+        Block::Figure {
+            content, caption, ..
+        } => {
+            for block in content {
+                if let Some(found) = find_first_inline_in_block(block, predicate) {
+                    return Some(found);
+                }
+            }
+            for inline in caption {
+                if let Some(found) = find_first_inline_in_inline(inline, predicate) {
+                    return Some(found);
+                }
+            }
+        }
         Block::FootnoteDefinition(footnote) => {
             for block in &footnote.blocks {
                 if let Some(found) = find_first_inline_in_block(block, predicate) {
@@ -500,6 +605,13 @@ where
                 }
             }
         }
+        Block::Extension(extension) => {
+            for block in &extension.children {
+                if let Some(found) = find_first_inline_in_block(block, predicate) {
+                    return Some(found);
+                }
+            }
+        }
         _ => {} // Terminal blocks
     }
     None
@@ -514,7 +626,13 @@ where
     }
 
     match inline {
-        Inline::Emphasis(inlines) | Inline::Strong(inlines) | Inline::Strikethrough(inlines) => {
+        Inline::Emphasis(inlines)
+        | Inline::Strong(inlines)
+        | Inline::Strikethrough(inlines)
+        | Inline::Superscript(inlines)
+        | Inline::Subscript(inlines)
+        | Inline::Highlight(inlines)
+        | Inline::Insert(inlines) => {
             for inline in inlines {
                 if let Some(found) = find_first_inline_in_inline(inline, predicate) {
                     return Some(found);
@@ -540,6 +658,20 @@ where
                 }
             }
         }
+        Inline::Span(span) => {
+            for inline in &span.children {
+                if let Some(found) = find_first_inline_in_inline(inline, predicate) {
+                    return Some(found);
+                }
+            }
+        }
+        Inline::Extension(extension) => {
+            for inline in &extension.children {
+                if let Some(found) = find_first_inline_in_inline(inline, predicate) {
+                    return Some(found);
+                }
+            }
+        }
         _ => {} // Terminal inlines
     }
     None
@@ -570,6 +702,22 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for block in item.definitions.iter().flatten() {
+                    if let Some(found) = find_first_block_in_block(block, predicate) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
+        Block::Figure { content, .. } => {
+            for block in content {
+                if let Some(found) = find_first_block_in_block(block, predicate) {
+                    return Some(found);
+                }
+            }
+        }
         Block::FootnoteDefinition(footnote) => {
             for block in &footnote.blocks {
                 if let Some(found) = find_first_block_in_block(block, predicate) {
@@ -584,6 +732,13 @@ where
                 }
             }
         }
+        Block::Extension(extension) => {
+            for block in &extension.children {
+                if let Some(found) = find_first_block_in_block(block, predicate) {
+                    return Some(found);
+                }
+            }
+        }
         _ => {} // Terminal or inline-containing blocks
     }
     None
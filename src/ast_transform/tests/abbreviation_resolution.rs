@@ -0,0 +1,92 @@
+use crate::{
+    ast::*,
+    ast_transform::{AbbreviationResolver, TransformWith},
+    parser::{config::*, parse_markdown, MarkdownParserState},
+};
+
+fn resolve(input: &str) -> Document {
+    let config = MarkdownParserConfig::default()
+        .with_block_abbreviation_definition_behavior(ElementBehavior::Parse);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), input).unwrap();
+    let mut resolver = AbbreviationResolver::new(&doc);
+    doc.transform_with(&mut resolver)
+}
+
+fn abbr(text: &str, title: &str) -> Inline {
+    Inline::Abbreviation {
+        text: text.to_string(),
+        title: title.to_string(),
+    }
+}
+
+#[test]
+fn test_resolve_whole_words() {
+    let doc =
+        resolve("HTML, XHTML and HTML5 are not HTML_\n\n*[HTML]: HyperText Markup Language\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![
+            abbr("HTML", "HyperText Markup Language"),
+            Inline::Text(", XHTML and HTML5 are not HTML_".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_longest_abbreviation_wins() {
+    let doc = resolve("*[W3C]: World Wide Web Consortium\n*[W3C WG]: W3C Working Group\n\nThe W3C WG of the W3C.\n");
+    assert_eq!(
+        doc.blocks[2],
+        Block::Paragraph(vec![
+            Inline::Text("The ".to_string()),
+            abbr("W3C WG", "W3C Working Group"),
+            Inline::Text(" of the ".to_string()),
+            abbr("W3C", "World Wide Web Consortium"),
+            Inline::Text(".".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_resolve_nested_inlines_and_headings() {
+    let doc =
+        resolve("# CSS\n\n*CSS* and `CSS` in [CSS](/css)\n\n*[CSS]: Cascading Style Sheets\n");
+    let css = || abbr("CSS", "Cascading Style Sheets");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Heading(Heading {
+            kind: HeadingKind::Atx(1),
+            attributes: Attributes::default(),
+            content: vec![css()],
+        })
+    );
+    assert_eq!(
+        doc.blocks[1],
+        Block::Paragraph(vec![
+            Inline::Emphasis(vec![css()]),
+            Inline::Text(" and ".to_string()),
+            Inline::Code("CSS".to_string()),
+            Inline::Text(" in ".to_string()),
+            Inline::Link(Link {
+                destination: "/css".to_string(),
+                title: None,
+                children: vec![css()],
+                attributes: Attributes::default(),
+            }),
+        ])
+    );
+}
+
+#[test]
+fn test_first_definition_wins() {
+    let mut resolver =
+        AbbreviationResolver::with_definitions([("API", "first"), ("API", "second")]);
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text("An API".to_string())])],
+    }
+    .transform_with(&mut resolver);
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![Inline::Text("An ".to_string()), abbr("API", "first")])
+    );
+}
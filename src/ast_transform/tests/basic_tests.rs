@@ -86,3 +86,91 @@ fn test_basic_transform_with() {
         assert_eq!(inlines[0], Inline::Text(">> Hello world".to_string()));
     }
 }
+
+#[test]
+fn test_basic_extension_children_are_traversed() {
+    use crate::ast_transform::Query;
+
+    let doc = Document {
+        blocks: vec![Block::Extension(BlockExtension {
+            name: "aside".to_string(),
+            attrs: vec![("kind".to_string(), "tip".to_string())],
+            children: vec![Block::Paragraph(vec![Inline::Extension(InlineExtension {
+                name: "kbd".to_string(),
+                attrs: vec![],
+                children: vec![Inline::Text("Ctrl".to_string())],
+            })])],
+        })],
+    };
+
+    assert_eq!(doc.find_all_text(), vec!["Ctrl"]);
+    assert_eq!(
+        doc.count_blocks(|block| matches!(block, Block::Paragraph(_))),
+        1
+    );
+
+    let result = doc.transform_text(|text| text.to_uppercase());
+    assert_eq!(result.find_all_text(), vec!["CTRL"]);
+    if let Block::Extension(extension) = &result.blocks[0] {
+        assert_eq!(extension.name, "aside");
+        assert_eq!(extension.attrs.len(), 1);
+    } else {
+        panic!("Expected extension block");
+    }
+}
+
+#[test]
+fn test_basic_definition_list_is_traversed() {
+    use crate::ast_transform::Query;
+
+    let doc = Document {
+        blocks: vec![Block::DefinitionList(DefinitionList {
+            items: vec![DefinitionListItem {
+                term: vec![Inline::Text("term".to_string())],
+                definitions: vec![
+                    vec![Block::Paragraph(vec![Inline::Text("first".to_string())])],
+                    vec![Block::Paragraph(vec![Inline::Text("second".to_string())])],
+                ],
+            }],
+        })],
+    };
+
+    assert_eq!(doc.find_all_text(), vec!["term", "first", "second"]);
+    assert_eq!(
+        doc.count_blocks(|block| matches!(block, Block::Paragraph(_))),
+        2
+    );
+
+    let result = doc.transform_text(|text| text.to_uppercase());
+    assert_eq!(result.find_all_text(), vec!["TERM", "FIRST", "SECOND"]);
+}
+
+#[test]
+fn test_basic_find_all_cited_keys() {
+    use crate::ast_transform::Query;
+
+    let cite = |keys: &[&str]| {
+        Inline::Citation(Citation {
+            items: keys
+                .iter()
+                .map(|key| CitationItem {
+                    key: key.to_string(),
+                    prefix: None,
+                    locator: None,
+                    suppress_author: false,
+                })
+                .collect(),
+            in_text: false,
+        })
+    };
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![cite(&["doe99", "smith04"])]),
+            Block::BlockQuote(vec![Block::Paragraph(vec![Inline::Emphasis(vec![cite(
+                &["roe12", "doe99"],
+            )])])]),
+        ],
+    };
+
+    assert_eq!(doc.find_all_cited_keys(), vec!["doe99", "smith04", "roe12"]);
+}
@@ -14,13 +14,15 @@ fn create_test_doc() -> Document {
                     destination: "/image.jpg".to_string(),
                     title: None,
                     alt: "test".to_string(),
-                    attr: None,
+                    attributes: Attributes::default(),
                 }),
                 Inline::Text(" and ".to_string()),
                 Inline::Link(Link {
                     destination: "http://example.com".to_string(),
                     title: None,
                     children: vec![Inline::Text("link".to_string())],
+
+                    attributes: Attributes::default(),
                 }),
             ]),
         ],
@@ -80,9 +82,15 @@ fn test_transform_autolink_urls() {
     let doc = Document {
         blocks: vec![Block::Paragraph(vec![
             Inline::Text("Check out ".to_string()),
-            Inline::Autolink("http://example.com".to_string()),
+            Inline::Autolink {
+                url: "http://example.com".to_string(),
+                bare: false,
+            },
             Inline::Text(" and ".to_string()),
-            Inline::Autolink("mailto:test@example.com".to_string()),
+            Inline::Autolink {
+                url: "mailto:test@example.com".to_string(),
+                bare: false,
+            },
         ])],
     };
 
@@ -98,10 +106,10 @@ fn test_transform_autolink_urls() {
 
     // Check paragraph autolinks
     if let Block::Paragraph(inlines) = &result.blocks[0] {
-        if let Inline::Autolink(url) = &inlines[1] {
+        if let Inline::Autolink { url, .. } = &inlines[1] {
             assert_eq!(url, "https://example.com");
         }
-        if let Inline::Autolink(url) = &inlines[3] {
+        if let Inline::Autolink { url, .. } = &inlines[3] {
             assert_eq!(url, "email:test@example.com");
         }
     }
@@ -207,6 +215,8 @@ fn test_transform_with_custom_transformer() {
                     info: Some("rust".to_string()),
                 },
                 literal: "fn main() {}".to_string(),
+
+                attributes: Attributes::default(),
             }),
         ],
     };
@@ -288,6 +298,8 @@ fn test_filter_blocks() {
             Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Indented,
                 literal: "Remove this".to_string(),
+
+                attributes: Attributes::default(),
             }),
         ],
     };
@@ -330,7 +342,7 @@ fn test_transform_image_urls_in_container() {
                 destination: "/image.jpg".to_string(),
                 title: None,
                 alt: "test".to_string(),
-                attr: None,
+                attributes: Attributes::default(),
             })])],
         })],
     };
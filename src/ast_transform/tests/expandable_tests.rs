@@ -174,6 +174,7 @@ fn test_heading_expander() {
     let doc = Document {
         blocks: vec![Block::Heading(Heading {
             kind: HeadingKind::Atx(2),
+            attributes: Attributes::default(),
             content: vec![Inline::Text("Test Heading".to_string())],
         })],
     };
@@ -336,6 +337,7 @@ fn test_complex_expansion() {
         blocks: vec![
             Block::Heading(Heading {
                 kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("Main EXPAND Title".to_string())],
             }),
             Block::Paragraph(vec![
@@ -1,7 +1,7 @@
 //! Tests for generic expandable transformations with user data
 
 use crate::ast::generic::*;
-use crate::ast_transform::{GenericExpandWith, GenericTransformer};
+use crate::ast_transform::{GenericExpandWith, GenericTransformWith, GenericTransformer};
 
 /// Test user data type for tracking node IDs
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -415,3 +415,81 @@ fn test_user_data_modification() {
         panic!("Expected paragraph");
     }
 }
+
+/// Transformer that upper-cases text wherever it sits
+struct TextUppercaser;
+
+impl GenericTransformer<NodeId> for TextUppercaser {
+    fn transform_inline(&mut self, inline: Inline<NodeId>) -> Inline<NodeId> {
+        match inline {
+            Inline::Text { content, user_data } => Inline::Text {
+                content: content.to_uppercase(),
+                user_data,
+            },
+            other => self.walk_transform_inline(other),
+        }
+    }
+}
+
+fn text(content: &str) -> Inline<NodeId> {
+    Inline::Text {
+        content: content.to_string(),
+        user_data: NodeId(0),
+    }
+}
+
+#[test]
+fn test_transform_walks_extension_children() {
+    let block = Block::Extension(BlockExtension {
+        name: "note".to_string(),
+        attrs: vec![],
+        children: vec![Block::Paragraph {
+            content: vec![Inline::Extension(InlineExtension {
+                name: "kbd".to_string(),
+                attrs: vec![],
+                children: vec![text("ctrl")],
+                user_data: NodeId(0),
+            })],
+            user_data: NodeId(0),
+        }],
+        user_data: NodeId(0),
+    });
+
+    let expected = Block::Extension(BlockExtension {
+        name: "note".to_string(),
+        attrs: vec![],
+        children: vec![Block::Paragraph {
+            content: vec![Inline::Extension(InlineExtension {
+                name: "kbd".to_string(),
+                attrs: vec![],
+                children: vec![text("CTRL")],
+                user_data: NodeId(0),
+            })],
+            user_data: NodeId(0),
+        }],
+        user_data: NodeId(0),
+    });
+    assert_eq!(block.transform_with(&mut TextUppercaser), expected);
+}
+
+#[test]
+fn test_transform_walks_definition_list() {
+    let item = |term, definition| DefinitionListItem {
+        term: vec![text(term)],
+        definitions: vec![vec![Block::Paragraph {
+            content: vec![text(definition)],
+            user_data: NodeId(0),
+        }]],
+        user_data: NodeId(0),
+    };
+    let block = Block::DefinitionList(DefinitionList {
+        items: vec![item("apple", "fruit")],
+        user_data: NodeId(0),
+    });
+
+    let expected = Block::DefinitionList(DefinitionList {
+        items: vec![item("APPLE", "FRUIT")],
+        user_data: NodeId(0),
+    });
+    assert_eq!(block.transform_with(&mut TextUppercaser), expected);
+}
@@ -0,0 +1,152 @@
+use crate::{
+    ast::*,
+    ast_transform::{LinkResolver, TransformWith},
+    parser::{parse_markdown, MarkdownParserState},
+};
+
+fn resolve(input: &str) -> (Document, LinkResolver) {
+    let doc = parse_markdown(MarkdownParserState::default(), input).unwrap();
+    let mut resolver = LinkResolver::new(&doc);
+    let doc = doc.transform_with(&mut resolver);
+    (doc, resolver)
+}
+
+fn link(destination: &str, title: Option<&str>, text: &str) -> Inline {
+    Inline::Link(Link {
+        destination: destination.to_string(),
+        title: title.map(str::to_string),
+        children: vec![Inline::Text(text.to_string())],
+
+        attributes: Attributes::default(),
+    })
+}
+
+#[test]
+fn test_resolve_reference_forms() {
+    let (doc, resolver) = resolve("[full][Foo] [Foo][] [FOO]\n\n[foo]: /url \"Title\"\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![
+            link("/url", Some("Title"), "full"),
+            Inline::Text(" ".to_string()),
+            link("/url", Some("Title"), "Foo"),
+            Inline::Text(" ".to_string()),
+            link("/url", Some("Title"), "FOO"),
+        ])
+    );
+    assert!(resolver.unresolved().is_empty());
+}
+
+#[test]
+fn test_resolve_normalizes_labels() {
+    let (doc, _) = resolve("[Foo\n  bar] [ẞ]\n\n[foo bar]: /a\n[SS]: /b\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![
+            link("/a", None, "Foo\nbar"),
+            Inline::Text(" ".to_string()),
+            link("/b", None, "ẞ"),
+        ])
+    );
+}
+
+#[test]
+fn test_resolve_image_reference() {
+    let (doc, _) = resolve("Look! ![a *logo*][logo]\n\n[logo]: /logo.png\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![
+            Inline::Text("Look! ".to_string()),
+            Inline::Image(Image {
+                destination: "/logo.png".to_string(),
+                title: None,
+                alt: "a logo".to_string(),
+                attributes: Attributes::default(),
+            }),
+        ])
+    );
+}
+
+#[test]
+fn test_resolve_escaped_bang_keeps_link() {
+    let (doc, _) = resolve("\\![logo]\n\n[logo]: /logo.png\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![
+            Inline::Text("!".to_string()),
+            link("/logo.png", None, "logo"),
+        ])
+    );
+    let (doc, _) = resolve("See \\![logo]\n\n[logo]: /logo.png\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![
+            Inline::Text("See \\!".to_string()),
+            link("/logo.png", None, "logo"),
+        ])
+    );
+}
+
+#[test]
+fn test_resolve_reports_missing_definitions() {
+    let (doc, resolver) = resolve("[known] and [unknown]\n\n[known]: /k\n");
+    let Block::Paragraph(content) = &doc.blocks[0] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(content[0], link("/k", None, "known"));
+    assert!(matches!(content[2], Inline::LinkReference(_)));
+    assert_eq!(
+        resolver.unresolved(),
+        &[LinkReference {
+            label: vec![Inline::Text("unknown".to_string())],
+            text: vec![Inline::Text("unknown".to_string())],
+            image: false,
+        }]
+    );
+}
+
+#[test]
+fn test_resolve_first_definition_wins() {
+    let (doc, _) = resolve("[a]\n\n> [a]: /first\n\n[A]: /second\n");
+    assert_eq!(
+        doc.blocks[0],
+        Block::Paragraph(vec![link("/first", None, "a")])
+    );
+}
+
+#[test]
+fn test_resolve_nested_references() {
+    let (doc, _) = resolve("# *[a]*\n\n| [a] |\n|---|\n| ~~[a]~~ |\n\n[a]: /x\n");
+    let Block::Heading(heading) = &doc.blocks[0] else {
+        panic!("expected heading");
+    };
+    assert_eq!(
+        heading.content,
+        vec![Inline::Emphasis(vec![link("/x", None, "a")])]
+    );
+    let Block::Table(table) = &doc.blocks[1] else {
+        panic!("expected table");
+    };
+    assert_eq!(table.rows[0][0].content, vec![link("/x", None, "a")]);
+    assert_eq!(
+        table.rows[1][0].content,
+        vec![Inline::Strikethrough(vec![link("/x", None, "a")])]
+    );
+}
+
+#[test]
+fn test_resolve_with_definitions() {
+    let definitions = [LinkDefinition {
+        label: vec![Inline::Text("Ext".to_string())],
+        destination: "/ext".to_string(),
+        title: None,
+    }];
+    let mut resolver = LinkResolver::with_definitions(&definitions);
+    let inline = Inline::LinkReference(LinkReference {
+        label: vec![Inline::Text("ext".to_string())],
+        text: vec![Inline::Text("ext".to_string())],
+        image: false,
+    })
+    .transform_with(&mut resolver);
+    assert_eq!(inline, link("/ext", None, "ext"));
+}
@@ -20,3 +20,9 @@ mod generic_expandable_tests;
 
 #[cfg(test)]
 mod macro_expansion;
+
+#[cfg(test)]
+mod link_resolution;
+
+#[cfg(test)]
+mod abbreviation_resolution;
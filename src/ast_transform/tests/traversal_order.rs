@@ -114,6 +114,7 @@ mod traversal_order_tests {
             blocks: vec![
                 Block::Heading(Heading {
                     kind: HeadingKind::Atx(1),
+                    attributes: Attributes::default(),
                     content: vec![
                         Inline::Text("Title".to_string()),
                         Inline::Strong(vec![Inline::Text("Bold".to_string())]),
@@ -130,6 +131,8 @@ mod traversal_order_tests {
                         destination: "https://example.com".to_string(),
                         title: None,
                         children: vec![Inline::Text("Link".to_string())],
+
+                        attributes: Attributes::default(),
                     }),
                     Inline::Text("Text3".to_string()),
                 ]),
@@ -220,6 +223,7 @@ mod traversal_order_tests {
                             Inline::Text("link ".to_string()),
                             Inline::Strong(vec![Inline::Text("text".to_string())]),
                         ],
+                        image: false,
                     }),
                     Inline::Text(" and ".to_string()),
                     Inline::LinkReference(LinkReference {
@@ -228,6 +232,7 @@ mod traversal_order_tests {
                             Inline::Emphasis(vec![Inline::Text("label".to_string())]),
                         ],
                         text: vec![Inline::Text("more text".to_string())],
+                        image: false,
                     }),
                 ]),
                 Block::Definition(LinkDefinition {
@@ -20,16 +20,22 @@ fn create_test_doc() -> Document {
                     destination: "http://example.com".to_string(),
                     title: Some("Example".to_string()),
                     children: vec![Inline::Text("link".to_string())],
+
+                    attributes: Attributes::default(),
                 }),
             ]),
             // Heading with correct structure
             Block::Heading(Heading {
                 kind: HeadingKind::Atx(2),
+                attributes: Attributes::default(),
                 content: vec![
                     Inline::Text("Heading with ".to_string()),
                     Inline::Strikethrough(vec![Inline::Text("strikethrough".to_string())]),
                     Inline::Text(" and ".to_string()),
-                    Inline::Autolink("mailto:test@example.com".to_string()),
+                    Inline::Autolink {
+                        url: "mailto:test@example.com".to_string(),
+                        bare: false,
+                    },
                 ],
             }),
             // Code block with correct structure
@@ -38,6 +44,8 @@ fn create_test_doc() -> Document {
                     info: Some("rust".to_string()),
                 },
                 literal: "fn main() { println!(\"Hello\"); }".to_string(),
+
+                attributes: Attributes::default(),
             }),
             // Blockquote with nested blocks
             Block::BlockQuote(vec![Block::Paragraph(vec![
@@ -61,6 +69,7 @@ fn create_test_doc() -> Document {
                             Inline::LinkReference(LinkReference {
                                 label: vec![Inline::Text("ref".to_string())],
                                 text: vec![Inline::Text("reference".to_string())],
+                                image: false,
                             }),
                         ])],
                     },
@@ -104,6 +113,8 @@ fn create_test_doc() -> Document {
                     ],
                 ],
                 alignments: vec![Alignment::Left, Alignment::Left],
+
+                attributes: Attributes::default(),
             }),
             // Footnote definition
             Block::FootnoteDefinition(FootnoteDefinition {
@@ -211,14 +222,25 @@ impl Visitor for NodeCounter {
             Inline::Link(_) => self.link_count += 1,
             Inline::Image(_) => self.image_count += 1,
             Inline::Code(_) => self.code_count += 1,
-            Inline::Autolink(_) => self.autolink_count += 1,
+            Inline::Autolink { .. } => self.autolink_count += 1,
             Inline::Html(_) => self.html_count += 1,
             Inline::Strikethrough(_) => self.strikethrough_count += 1,
+            Inline::Superscript(_)
+            | Inline::Subscript(_)
+            | Inline::Highlight(_)
+            | Inline::Insert(_) => {}
             Inline::LinkReference(_) => self.link_ref_count += 1,
             Inline::FootnoteReference(_) => self.footnote_ref_count += 1,
             Inline::LineBreak => {}
             Inline::Empty => {}
-            Inline::Latex(_) => {}
+            Inline::Latex { .. } => {}
+            Inline::GitHubReference(_) => {}
+            Inline::Citation(_) => {}
+            Inline::Emoji { .. } => {}
+            Inline::Abbreviation { .. } => {}
+            Inline::Extension(_) => {}
+            Inline::WikiLink(_) => {}
+            Inline::Span(_) => {}
         }
         self.walk_inline(inline);
     }
@@ -238,8 +260,13 @@ impl Visitor for NodeCounter {
             Block::Definition(_) => self.definition_count += 1,
             Block::Empty => {}
             Block::Container(_) => {} // Add this line
-            Block::LatexBlock(_) => {}
+            Block::LatexBlock { .. } => {}
             Block::MacroBlock(_) => {}
+            Block::FrontMatter { .. } => {}
+            Block::AbbreviationDefinition { .. } => {}
+            Block::Figure { .. } => {}
+            Block::Extension(_) => {}
+            Block::DefinitionList(_) => {}
         }
         self.walk_block(block);
     }
@@ -354,6 +381,8 @@ fn test_visitor_deep_nesting() {
                         children: vec![Inline::Strong(vec![Inline::Emphasis(vec![Inline::Text(
                             "Deeply nested text".to_string(),
                         )])])],
+
+                        attributes: Attributes::default(),
                     }),
                 ])])],
             }],
@@ -78,6 +78,11 @@ pub trait Transformer {
         self.walk_transform_list_item(item)
     }
 
+    /// Transform a definition list item
+    fn transform_definition_list_item(&mut self, item: DefinitionListItem) -> DefinitionListItem {
+        self.walk_transform_definition_list_item(item)
+    }
+
     /// Transform a table row
     fn transform_table_row(&mut self, row: TableRow) -> TableRow {
         self.walk_transform_table_row(row)
@@ -142,6 +147,14 @@ pub trait Transformer {
                     .collect();
                 Block::Container(container)
             }
+            Block::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect();
+                Block::Extension(extension)
+            }
             Block::Paragraph(inlines) => Block::Paragraph(
                 inlines
                     .into_iter()
@@ -163,6 +176,14 @@ pub trait Transformer {
                     .collect();
                 Block::List(list)
             }
+            Block::DefinitionList(mut list) => {
+                list.items = list
+                    .items
+                    .into_iter()
+                    .map(|item| self.transform_definition_list_item(item))
+                    .collect();
+                Block::DefinitionList(list)
+            }
             Block::Table(mut table) => {
                 table.rows = table
                     .rows
@@ -171,6 +192,23 @@ pub trait Transformer {
                     .collect();
                 Block::Table(table)
             }
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+            } => Block::Figure {
+                content: content
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect(),
+                caption: caption
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+                label,
+                caption_syntax,
+            },
             Block::FootnoteDefinition(footnote) => {
                 Block::FootnoteDefinition(self.transform_footnote_definition(footnote))
             }
@@ -210,6 +248,30 @@ pub trait Transformer {
                     .map(|inline| self.transform_inline(inline))
                     .collect(),
             ),
+            Inline::Superscript(inlines) => Inline::Superscript(
+                inlines
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+            ),
+            Inline::Subscript(inlines) => Inline::Subscript(
+                inlines
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+            ),
+            Inline::Highlight(inlines) => Inline::Highlight(
+                inlines
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+            ),
+            Inline::Insert(inlines) => Inline::Insert(
+                inlines
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect(),
+            ),
             Inline::Link(link) => Inline::Link(self.transform_link(link)),
             Inline::LinkReference(mut link_ref) => {
                 link_ref.label = link_ref
@@ -226,6 +288,22 @@ pub trait Transformer {
             }
             Inline::Image(image) => Inline::Image(self.transform_image(image)),
             Inline::Text(text) => Inline::Text(self.transform_text(text)),
+            Inline::Span(mut span) => {
+                span.children = span
+                    .children
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect();
+                Inline::Span(span)
+            }
+            Inline::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .map(|inline| self.transform_inline(inline))
+                    .collect();
+                Inline::Extension(extension)
+            }
             // Terminal nodes - no transformation needed
             other => other,
         }
@@ -251,6 +329,29 @@ pub trait Transformer {
         item
     }
 
+    /// Default transformation for definition list items
+    fn walk_transform_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem,
+    ) -> DefinitionListItem {
+        item.term = item
+            .term
+            .into_iter()
+            .map(|inline| self.transform_inline(inline))
+            .collect();
+        item.definitions = item
+            .definitions
+            .into_iter()
+            .map(|blocks| {
+                blocks
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect()
+            })
+            .collect();
+        item
+    }
+
     /// Default transformation for table rows
     fn walk_transform_table_row(&mut self, row: TableRow) -> TableRow {
         row.into_iter()
@@ -346,6 +447,11 @@ pub trait Transformer {
         vec![self.transform_list_item(item)]
     }
 
+    /// Transform a definition list item with possibility to expand into multiple items
+    fn expand_definition_list_item(&mut self, item: DefinitionListItem) -> Vec<DefinitionListItem> {
+        vec![self.transform_definition_list_item(item)]
+    }
+
     /// Transform a table row with possibility to expand into multiple rows
     fn expand_table_row(&mut self, row: TableRow) -> Vec<TableRow> {
         vec![self.transform_table_row(row)]
@@ -443,6 +549,14 @@ pub trait Transformer {
                     .collect();
                 vec![Block::List(list)]
             }
+            Block::DefinitionList(mut list) => {
+                list.items = list
+                    .items
+                    .into_iter()
+                    .flat_map(|item| self.expand_definition_list_item(item))
+                    .collect();
+                vec![Block::DefinitionList(list)]
+            }
             Block::Table(mut table) => {
                 table.rows = table
                     .rows
@@ -475,6 +589,14 @@ pub trait Transformer {
                     .collect();
                 vec![Block::Definition(def)]
             }
+            Block::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .flat_map(|block| self.expand_block(block))
+                    .collect();
+                vec![Block::Extension(extension)]
+            }
             // Terminal nodes - no transformation needed
             other => vec![other],
         }
@@ -498,6 +620,22 @@ pub trait Transformer {
                 let inlines = inlines.into_iter().flat_map(|i| self.expand_inline(i)).collect();
                 vec![Inline::Strikethrough(inlines)]
             }
+            Inline::Superscript(inlines) => {
+                let inlines = inlines.into_iter().flat_map(|i| self.expand_inline(i)).collect();
+                vec![Inline::Superscript(inlines)]
+            }
+            Inline::Subscript(inlines) => {
+                let inlines = inlines.into_iter().flat_map(|i| self.expand_inline(i)).collect();
+                vec![Inline::Subscript(inlines)]
+            }
+            Inline::Highlight(inlines) => {
+                let inlines = inlines.into_iter().flat_map(|i| self.expand_inline(i)).collect();
+                vec![Inline::Highlight(inlines)]
+            }
+            Inline::Insert(inlines) => {
+                let inlines = inlines.into_iter().flat_map(|i| self.expand_inline(i)).collect();
+                vec![Inline::Insert(inlines)]
+            }
             Inline::Link(mut link) => {
                 link.children = link
                     .children
@@ -512,6 +650,22 @@ pub trait Transformer {
                 link_ref.text = link_ref.text.into_iter().flat_map(|i| self.expand_inline(i)).collect();
                 vec![Inline::LinkReference(link_ref)]
             }
+            Inline::Span(mut span) => {
+                span.children = span
+                    .children
+                    .into_iter()
+                    .flat_map(|i| self.expand_inline(i))
+                    .collect();
+                vec![Inline::Span(span)]
+            }
+            Inline::Extension(mut extension) => {
+                extension.children = extension
+                    .children
+                    .into_iter()
+                    .flat_map(|i| self.expand_inline(i))
+                    .collect();
+                vec![Inline::Extension(extension)]
+            }
             // Terminal nodes - no transformation needed
             other => vec![other],
         }
@@ -537,6 +691,29 @@ pub trait Transformer {
         vec![item]
     }
 
+    /// Walk definition list item with expandable transformations
+    fn walk_expand_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem,
+    ) -> Vec<DefinitionListItem> {
+        item.term = item
+            .term
+            .into_iter()
+            .flat_map(|i| self.expand_inline(i))
+            .collect();
+        item.definitions = item
+            .definitions
+            .into_iter()
+            .map(|blocks| {
+                blocks
+                    .into_iter()
+                    .flat_map(|block| self.expand_block(block))
+                    .collect()
+            })
+            .collect();
+        vec![item]
+    }
+
     /// Walk table row with expandable transformations
     fn walk_expand_table_row(&mut self, row: TableRow) -> Vec<TableRow> {
         let expanded_row = row
@@ -85,6 +85,11 @@ pub trait Visitor {
         self.walk_list_item(item);
     }
 
+    /// Visit a definition list item
+    fn visit_definition_list_item(&mut self, item: &DefinitionListItem) {
+        self.walk_definition_list_item(item);
+    }
+
     /// Visit a table row
     fn visit_table_row(&mut self, row: &TableRow) {
         self.walk_table_row(row);
@@ -153,11 +158,26 @@ pub trait Visitor {
                     self.visit_list_item(item);
                 }
             }
+            Block::DefinitionList(list) => {
+                for item in &list.items {
+                    self.visit_definition_list_item(item);
+                }
+            }
             Block::Table(table) => {
                 for row in &table.rows {
                     self.visit_table_row(row);
                 }
             }
+            Block::Figure {
+                content, caption, ..
+            } => {
+                for block in content {
+                    self.visit_block(block);
+                }
+                for inline in caption {
+                    self.visit_inline(inline);
+                }
+            }
             Block::FootnoteDefinition(footnote) => {
                 self.visit_footnote_definition(footnote);
             }
@@ -176,13 +196,20 @@ pub trait Visitor {
             Block::ThematicBreak
             | Block::HtmlBlock(_)
             | Block::Empty
-            | Block::LatexBlock(_)
-            | Block::MacroBlock(_) => {}
+            | Block::LatexBlock { .. }
+            | Block::MacroBlock(_)
+            | Block::AbbreviationDefinition { .. }
+            | Block::FrontMatter { .. } => {}
             Block::Container(container) => {
                 for block in &container.blocks {
                     self.visit_block(block);
                 }
             }
+            Block::Extension(extension) => {
+                for block in &extension.children {
+                    self.visit_block(block);
+                }
+            }
         }
     }
 
@@ -191,7 +218,11 @@ pub trait Visitor {
         match inline {
             Inline::Emphasis(inlines)
             | Inline::Strong(inlines)
-            | Inline::Strikethrough(inlines) => {
+            | Inline::Strikethrough(inlines)
+            | Inline::Superscript(inlines)
+            | Inline::Subscript(inlines)
+            | Inline::Highlight(inlines)
+            | Inline::Insert(inlines) => {
                 for inline in inlines {
                     self.visit_inline(inline);
                 }
@@ -213,13 +244,28 @@ pub trait Visitor {
             Inline::Text(text) => {
                 self.visit_text(text);
             }
+            Inline::Span(span) => {
+                for inline in &span.children {
+                    self.visit_inline(inline);
+                }
+            }
+            Inline::Extension(extension) => {
+                for inline in &extension.children {
+                    self.visit_inline(inline);
+                }
+            }
             // Terminal nodes - no traversal needed
             Inline::LineBreak
             | Inline::Code(_)
             | Inline::Html(_)
-            | Inline::Autolink(_)
+            | Inline::Autolink { .. }
             | Inline::FootnoteReference(_)
-            | Inline::Latex(_)
+            | Inline::WikiLink(_)
+            | Inline::Latex { .. }
+            | Inline::GitHubReference(_)
+            | Inline::Citation(_)
+            | Inline::Emoji { .. }
+            | Inline::Abbreviation { .. }
             | Inline::Empty => {}
         }
     }
@@ -238,6 +284,16 @@ pub trait Visitor {
         }
     }
 
+    /// Default traversal for definition list items
+    fn walk_definition_list_item(&mut self, item: &DefinitionListItem) {
+        for inline in &item.term {
+            self.visit_inline(inline);
+        }
+        for block in item.definitions.iter().flatten() {
+            self.visit_block(block);
+        }
+    }
+
     /// Default traversal for table rows
     fn walk_table_row(&mut self, row: &TableRow) {
         for cell in row {
@@ -0,0 +1,428 @@
+use crate::ast::*;
+use crate::html_printer::util::{escape_html, html_attributes};
+use crate::html_printer::{State, ToDoc};
+use crate::render_util::{render_to_string, verbatim};
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+impl<'a> ToDoc<'a> for Vec<Block> {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        let refs: Vec<_> = self.iter().collect();
+        refs.to_doc(state)
+    }
+}
+
+impl<'a> ToDoc<'a> for Vec<&Block> {
+    /// Every rendered block is terminated by a newline, like the CommonMark
+    /// reference renderer does.
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        state.arena.concat(
+            self.iter()
+                .filter(|block| !renders_nothing(block))
+                .map(|block| block.to_doc(state).append(state.arena.hardline())),
+        )
+    }
+}
+
+/// Blocks that produce no HTML output at the position they appear in.
+fn renders_nothing(block: &Block) -> bool {
+    matches!(
+        block,
+        Block::Definition(_)
+            | Block::AbbreviationDefinition { .. }
+            | Block::FootnoteDefinition(_)
+            | Block::Empty
+            | Block::MacroBlock(_)
+            | Block::FrontMatter { .. }
+    )
+}
+
+/// Build an opening tag with a class and `data-*` attributes.
+pub(crate) fn open_tag(tag: &str, class: &str, attrs: &[(String, String)]) -> String {
+    let mut open = format!(r#"<{tag} class="{}""#, escape_html(class));
+    for (key, value) in attrs {
+        open.push_str(&format!(
+            r#" data-{}="{}""#,
+            escape_html(key),
+            escape_html(value)
+        ));
+    }
+    open.push('>');
+    open
+}
+
+/// Wrap block content into an opening and a closing tag, each on its own line.
+fn wrap_blocks<'a>(
+    state: &'a State<'a>,
+    open: String,
+    content: DocBuilder<'a, Arena<'a>, ()>,
+    close: &'static str,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    state
+        .arena
+        .text(open)
+        .append(state.arena.hardline())
+        .append(content)
+        .append(state.arena.text(close))
+}
+
+impl<'a> ToDoc<'a> for Block {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        match self {
+            Block::Paragraph(inlines) => state
+                .arena
+                .text("<p>")
+                .append(inlines.to_doc(state))
+                .append("</p>"),
+
+            Block::Heading(heading) => {
+                let level = match heading.kind {
+                    HeadingKind::Atx(level) => level,
+                    HeadingKind::Setext(SetextHeading::Level1) => 1,
+                    HeadingKind::Setext(SetextHeading::Level2) => 2,
+                };
+                let attributes = Attributes {
+                    id: state.heading_id(heading),
+                    ..heading.attributes.clone()
+                };
+                let open = format!("<h{level}{}>", html_attributes(&attributes));
+                state
+                    .arena
+                    .text(open)
+                    .append(heading.content.to_doc(state))
+                    .append(state.arena.text(format!("</h{level}>")))
+            }
+
+            Block::ThematicBreak => state.arena.text("<hr />"),
+
+            Block::BlockQuote(blocks) => wrap_blocks(
+                state,
+                "<blockquote>".to_string(),
+                blocks.to_doc(state),
+                "</blockquote>",
+            ),
+
+            Block::List(list) => list.to_doc(state),
+
+            Block::DefinitionList(list) => list.to_doc(state),
+
+            Block::CodeBlock(code_block) => {
+                let lang = match &code_block.kind {
+                    CodeBlockKind::Fenced { info: Some(info) } => info.split_whitespace().next(),
+                    _ => None,
+                };
+                let pre = format!("<pre{}>", html_attributes(&code_block.attributes));
+                let open = match lang {
+                    Some(lang) => format!(r#"{pre}<code class="language-{}">"#, escape_html(lang)),
+                    None => format!("{pre}<code>"),
+                };
+                let mut literal = escape_html(&code_block.literal);
+                if !literal.is_empty() && !literal.ends_with('\n') {
+                    literal.push('\n');
+                }
+                state
+                    .arena
+                    .text(open)
+                    .append(verbatim(state.arena, &literal))
+                    .append(state.arena.text("</code></pre>"))
+            }
+
+            Block::HtmlBlock(html) => verbatim(state.arena, html.trim_end_matches('\n')),
+
+            Block::Definition(_) | Block::AbbreviationDefinition { .. } => state.arena.nil(),
+
+            Block::Table(table) => table.to_doc(state),
+
+            Block::Figure {
+                content,
+                caption,
+                label,
+                ..
+            } => {
+                let open = match label {
+                    Some(label) => format!(r#"<figure id="{}">"#, escape_html(label)),
+                    None => "<figure>".to_string(),
+                };
+                let content = match content.as_slice() {
+                    // A standalone image is not wrapped into a paragraph
+                    [Block::Paragraph(inlines)] => {
+                        inlines.to_doc(state).append(state.arena.hardline())
+                    }
+                    _ => content.to_doc(state),
+                };
+                let content = content
+                    .append(state.arena.text("<figcaption>"))
+                    .append(caption.to_doc(state))
+                    .append(state.arena.text("</figcaption>"))
+                    .append(state.arena.hardline());
+                wrap_blocks(state, open, content, "</figure>")
+            }
+
+            Block::FootnoteDefinition(_) => state.arena.nil(),
+
+            Block::GitHubAlert(alert) => {
+                let (class, title) = match &alert.alert_type {
+                    GitHubAlertType::Note => ("note".to_string(), "Note"),
+                    GitHubAlertType::Tip => ("tip".to_string(), "Tip"),
+                    GitHubAlertType::Important => ("important".to_string(), "Important"),
+                    GitHubAlertType::Warning => ("warning".to_string(), "Warning"),
+                    GitHubAlertType::Caution => ("caution".to_string(), "Caution"),
+                    GitHubAlertType::Custom(s) => (s.to_lowercase(), s.as_str()),
+                };
+                let content = state
+                    .arena
+                    .text(format!(
+                        r#"<p class="markdown-alert-title">{}</p>"#,
+                        escape_html(title)
+                    ))
+                    .append(state.arena.hardline())
+                    .append(alert.blocks.to_doc(state));
+                wrap_blocks(
+                    state,
+                    format!(
+                        r#"<div class="markdown-alert markdown-alert-{}">"#,
+                        escape_html(&class)
+                    ),
+                    content,
+                    "</div>",
+                )
+            }
+
+            Block::Empty => state.arena.nil(),
+
+            Block::LatexBlock { content: latex, .. } => state
+                .arena
+                .text(r#"<div class="math display">\["#)
+                .append(verbatim(state.arena, &escape_html(latex)))
+                .append(state.arena.text(r"\]</div>")),
+
+            Block::Container(container) => wrap_blocks(
+                state,
+                open_tag("div", &container.kind, &container.params),
+                container.blocks.to_doc(state),
+                "</div>",
+            ),
+
+            Block::Extension(extension) => {
+                let children = extension.children.to_doc(state);
+                if let Some(renderer) = &state.config.block_extension_renderer {
+                    let rendered = render_to_string(children.clone(), state.config.width);
+                    if let Some(html) = renderer(extension, rendered.trim_end_matches('\n')) {
+                        return verbatim(state.arena, &html);
+                    }
+                }
+                wrap_blocks(
+                    state,
+                    open_tag("div", &extension.name, &extension.attrs),
+                    children,
+                    "</div>",
+                )
+            }
+
+            Block::MacroBlock(_) | Block::FrontMatter { .. } => state.arena.nil(),
+        }
+    }
+}
+
+impl<'a> ToDoc<'a> for List {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        let (open, close) = match &self.kind {
+            ListKind::Ordered(ListOrderedKindOptions { start: 1 }) => ("<ol>".to_string(), "</ol>"),
+            ListKind::Ordered(options) => (format!(r#"<ol start="{}">"#, options.start), "</ol>"),
+            ListKind::Bullet(_) => ("<ul>".to_string(), "</ul>"),
+        };
+
+        // The AST does not record whether a list was tight, so a list is
+        // rendered tight unless one of its items holds several paragraphs.
+        let tight = self.items.iter().all(|item| {
+            item.blocks
+                .iter()
+                .filter(|block| matches!(block, Block::Paragraph(_)))
+                .count()
+                <= 1
+        });
+
+        let items = state.arena.concat(
+            self.items
+                .iter()
+                .map(|item| list_item_to_doc(item, tight, state).append(state.arena.hardline())),
+        );
+
+        wrap_blocks(state, open, items, close)
+    }
+}
+
+fn list_item_to_doc<'a>(
+    item: &ListItem,
+    tight: bool,
+    state: &'a State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let mut doc = state.arena.text("<li>");
+    if let Some(task) = item.task {
+        let checkbox = match task {
+            TaskState::Complete => r#"<input type="checkbox" checked="" disabled="" /> "#,
+            TaskState::Incomplete => r#"<input type="checkbox" disabled="" /> "#,
+        };
+        doc = doc.append(state.arena.text(checkbox));
+    }
+
+    let blocks: Vec<&Block> = item
+        .blocks
+        .iter()
+        .filter(|block| !renders_nothing(block))
+        .collect();
+
+    if !tight {
+        if blocks.is_empty() {
+            return doc.append(state.arena.text("</li>"));
+        }
+        return doc
+            .append(state.arena.hardline())
+            .append(blocks.to_doc(state))
+            .append(state.arena.text("</li>"));
+    }
+
+    // Tight items render paragraphs without the `<p>` wrapper; other
+    // blocks are placed on their own lines.
+    let mut previous_was_paragraph = true;
+    for (index, block) in blocks.iter().enumerate() {
+        match block {
+            Block::Paragraph(inlines) => {
+                if index > 0 {
+                    doc = doc.append(state.arena.hardline());
+                }
+                doc = doc.append(inlines.to_doc(state));
+                previous_was_paragraph = true;
+            }
+            _ => {
+                doc = doc
+                    .append(state.arena.hardline())
+                    .append(block.to_doc(state));
+                previous_was_paragraph = false;
+            }
+        }
+    }
+    if !previous_was_paragraph {
+        doc = doc.append(state.arena.hardline());
+    }
+    doc.append(state.arena.text("</li>"))
+}
+
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        let mut items = state.arena.nil();
+        for item in &self.items {
+            items = items
+                .append(state.arena.text("<dt>"))
+                .append(item.term.to_doc(state))
+                .append(state.arena.text("</dt>"))
+                .append(state.arena.hardline());
+            for definition in &item.definitions {
+                items = items
+                    .append(definition_to_doc(definition, state))
+                    .append(state.arena.hardline());
+            }
+        }
+
+        wrap_blocks(state, "<dl>".to_string(), items, "</dl>")
+    }
+}
+
+/// A definition made of a single paragraph is rendered without the `<p>`
+/// wrapper, like a tight list item.
+fn definition_to_doc<'a>(blocks: &[Block], state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+    let blocks: Vec<&Block> = blocks
+        .iter()
+        .filter(|block| !renders_nothing(block))
+        .collect();
+    match blocks.as_slice() {
+        [] => state.arena.text("<dd></dd>"),
+        [Block::Paragraph(inlines)] => state
+            .arena
+            .text("<dd>")
+            .append(inlines.to_doc(state))
+            .append(state.arena.text("</dd>")),
+        _ => wrap_blocks(state, "<dd>".to_string(), blocks.to_doc(state), "</dd>"),
+    }
+}
+
+/// Render the footnotes section for all footnotes referenced so far
+///
+/// Footnote definitions may reference further footnotes, which are appended
+/// to the section as they are discovered.
+pub(crate) fn footnotes_section<'a>(state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+    let mut items = Vec::new();
+    let mut index = 0;
+    loop {
+        let label = match state.footnote_order.borrow().get(index) {
+            Some(label) => label.clone(),
+            None => break,
+        };
+        index += 1;
+        let Some(definition) = state.footnote_definitions.get(&label) else {
+            continue;
+        };
+        items.push(footnote_item(&label, index, definition, state));
+    }
+
+    if items.is_empty() {
+        return state.arena.nil();
+    }
+
+    let mut content = state.arena.nil();
+    if let Some(title) = &state.config.footnotes_title {
+        content = content
+            .append(state.arena.text(format!("<h2>{}</h2>", escape_html(title))))
+            .append(state.arena.hardline());
+    }
+    let list = state.arena.concat(
+        items
+            .into_iter()
+            .map(|item| item.append(state.arena.hardline())),
+    );
+    content = content.append(wrap_blocks(state, "<ol>".to_string(), list, "</ol>"));
+
+    wrap_blocks(
+        state,
+        r#"<section class="footnotes">"#.to_string(),
+        content.append(state.arena.hardline()),
+        "</section>",
+    )
+    .append(state.arena.hardline())
+}
+
+fn footnote_item<'a>(
+    label: &str,
+    number: usize,
+    definition: &FootnoteDefinition,
+    state: &'a State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let label = escape_html(label);
+    let backref = state.arena.text(format!(
+        r##"<a href="#fnref-{label}" class="footnote-backref" aria-label="Back to reference {number}">↩</a>"##
+    ));
+
+    let blocks: Vec<&Block> = definition
+        .blocks
+        .iter()
+        .filter(|block| !renders_nothing(block))
+        .collect();
+
+    // The back reference goes inside the last paragraph when there is one.
+    let content = match blocks.split_last() {
+        Some((Block::Paragraph(inlines), rest)) => rest
+            .to_vec()
+            .to_doc(state)
+            .append(state.arena.text("<p>"))
+            .append(inlines.to_doc(state))
+            .append(state.arena.text(" "))
+            .append(backref)
+            .append(state.arena.text("</p>"))
+            .append(state.arena.hardline()),
+        _ => blocks
+            .to_doc(state)
+            .append(backref)
+            .append(state.arena.hardline()),
+    };
+
+    wrap_blocks(state, format!(r#"<li id="fn-{label}">"#), content, "</li>")
+}
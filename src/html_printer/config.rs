@@ -0,0 +1,170 @@
+//! Configuration for HTML rendering
+//!
+//! This module provides configuration options to customize the HTML output
+//! style and format.
+
+use crate::ast::{BlockExtension, InlineExtension};
+use std::rc::Rc;
+
+/// Renders a [`BlockExtension`] node to HTML
+///
+/// The second argument is the node's children, already rendered to HTML.
+/// Returning `None` falls back to the default `<div>` rendering.
+pub type BlockExtensionRenderer = Rc<dyn Fn(&BlockExtension, &str) -> Option<String>>;
+
+/// Renders an [`InlineExtension`] node to HTML
+///
+/// The second argument is the node's children, already rendered to HTML.
+/// Returning `None` falls back to the default `<span>` rendering.
+pub type InlineExtensionRenderer = Rc<dyn Fn(&InlineExtension, &str) -> Option<String>>;
+
+/// Configuration for HTML rendering
+///
+/// This struct controls various aspects of how the Markdown AST is converted
+/// to HTML. Use the builder methods to customize the output style.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::html_printer::config::*;
+///
+/// // Default configuration
+/// let config = Config::default();
+///
+/// // Custom configuration
+/// let config = Config::default()
+///     .with_width(120)
+///     .with_footnotes_title(Some("Notes".to_string()));
+/// ```
+pub struct Config {
+    pub(crate) width: usize,
+    pub(crate) footnotes_title: Option<String>,
+    pub(crate) heading_ids: bool,
+    pub(crate) block_extension_renderer: Option<BlockExtensionRenderer>,
+    pub(crate) inline_extension_renderer: Option<InlineExtensionRenderer>,
+}
+
+impl Default for Config {
+    /// Create a default configuration
+    ///
+    /// Default settings:
+    /// - Width: 80 characters
+    /// - Footnotes title: none
+    /// - Heading ids: only explicit `{#id}` identifiers
+    /// - Extension renderers: none
+    fn default() -> Self {
+        Self {
+            width: 80,
+            footnotes_title: None,
+            heading_ids: false,
+            block_extension_renderer: None,
+            inline_extension_renderer: None,
+        }
+    }
+}
+
+impl Config {
+    /// Set the line width for pretty-printing
+    ///
+    /// Controls how the pretty-printer wraps long lines. This affects the
+    /// formatting of the generated HTML, not the content itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Maximum line width in characters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::html_printer::config::Config;
+    ///
+    /// let config = Config::default().with_width(120);
+    /// ```
+    pub fn with_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Set a heading for the footnotes section
+    ///
+    /// When set, the footnotes section rendered at the end of the document
+    /// starts with an `<h2>` containing this title.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::html_printer::config::Config;
+    ///
+    /// let config = Config::default().with_footnotes_title(Some("Footnotes".to_string()));
+    /// ```
+    pub fn with_footnotes_title(self, footnotes_title: Option<String>) -> Self {
+        Self {
+            footnotes_title,
+            ..self
+        }
+    }
+
+    /// Emit an `id` attribute on every heading
+    ///
+    /// Headings without an explicit `{#id}` get the GitHub-compatible slug of
+    /// their text, made unique with a numeric suffix (see
+    /// [`Slugger`](crate::ast::Slugger)). Explicit identifiers are always
+    /// emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::html_printer::config::Config;
+    ///
+    /// let config = Config::default().with_heading_ids(true);
+    /// ```
+    pub fn with_heading_ids(self, heading_ids: bool) -> Self {
+        Self {
+            heading_ids,
+            ..self
+        }
+    }
+
+    /// Set the renderer for [`Block::Extension`](crate::ast::Block::Extension) nodes
+    ///
+    /// Without a renderer, or when it returns `None`, an extension block is
+    /// rendered as `<div class="name" data-key="value">` around its children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::html_printer::config::Config;
+    /// use std::rc::Rc;
+    ///
+    /// let config = Config::default().with_block_extension_renderer(Rc::new(|ext, children| {
+    ///     (ext.name == "aside").then(|| format!("<aside>\n{children}\n</aside>"))
+    /// }));
+    /// ```
+    pub fn with_block_extension_renderer(self, renderer: BlockExtensionRenderer) -> Self {
+        Self {
+            block_extension_renderer: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Set the renderer for [`Inline::Extension`](crate::ast::Inline::Extension) nodes
+    ///
+    /// Without a renderer, or when it returns `None`, an extension inline is
+    /// rendered as `<span class="name" data-key="value">` around its children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::html_printer::config::Config;
+    /// use std::rc::Rc;
+    ///
+    /// let config = Config::default().with_inline_extension_renderer(Rc::new(|ext, children| {
+    ///     (ext.name == "kbd").then(|| format!("<kbd>{children}</kbd>"))
+    /// }));
+    /// ```
+    pub fn with_inline_extension_renderer(self, renderer: InlineExtensionRenderer) -> Self {
+        Self {
+            inline_extension_renderer: Some(renderer),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::ast::*;
+use crate::html_printer::block::open_tag;
+use crate::html_printer::util::{escape_html, escape_url, html_attributes};
+use crate::html_printer::{State, ToDoc};
+use crate::render_util::{render_to_string, verbatim};
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+impl<'a> ToDoc<'a> for Vec<Inline> {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        state
+            .arena
+            .concat(self.iter().map(|inline| inline.to_doc(state)))
+    }
+}
+
+/// Build the opening `<a>` tag for a link destination and optional title.
+fn link_open(destination: &str, title: &Option<String>, attributes: &Attributes) -> String {
+    match title {
+        Some(title) => format!(
+            r#"<a href="{}" title="{}"{}>"#,
+            escape_url(destination),
+            escape_html(title),
+            html_attributes(attributes)
+        ),
+        None => format!(
+            r#"<a href="{}"{}>"#,
+            escape_url(destination),
+            html_attributes(attributes)
+        ),
+    }
+}
+
+impl<'a> ToDoc<'a> for Inline {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        match self {
+            Inline::Text(text) => verbatim(state.arena, &escape_html(text)),
+
+            Inline::LineBreak => state.arena.text("<br />").append(state.arena.hardline()),
+
+            Inline::Code(code) => state
+                .arena
+                .text("<code>")
+                .append(verbatim(state.arena, &escape_html(code)))
+                .append(state.arena.text("</code>")),
+
+            Inline::Html(html) => verbatim(state.arena, html),
+
+            Inline::Link(link) => state
+                .arena
+                .text(link_open(&link.destination, &link.title, &link.attributes))
+                .append(link.children.to_doc(state))
+                .append(state.arena.text("</a>")),
+
+            Inline::LinkReference(link_ref) => {
+                if let Some(definition) = state.get_link_definition(&link_ref.label) {
+                    if link_ref.image {
+                        return Inline::Image(Image {
+                            destination: definition.destination.clone(),
+                            title: definition.title.clone(),
+                            alt: plain_text(&link_ref.text),
+                            attributes: Attributes::default(),
+                        })
+                        .to_doc(state);
+                    }
+                    state
+                        .arena
+                        .text(link_open(
+                            &definition.destination,
+                            &definition.title,
+                            &Attributes::default(),
+                        ))
+                        .append(link_ref.text.to_doc(state))
+                        .append(state.arena.text("</a>"))
+                } else {
+                    // Unresolved references are rendered as the source text
+                    let text = state
+                        .arena
+                        .text(if link_ref.image { "![" } else { "[" })
+                        .append(link_ref.text.to_doc(state))
+                        .append(state.arena.text("]"));
+                    if link_ref.label == link_ref.text {
+                        text
+                    } else {
+                        text.append(state.arena.text("["))
+                            .append(link_ref.label.to_doc(state))
+                            .append(state.arena.text("]"))
+                    }
+                }
+            }
+
+            Inline::Image(image) => {
+                let mut res = format!(
+                    r#"<img src="{}" alt="{}""#,
+                    escape_url(&image.destination),
+                    escape_html(&image.alt)
+                );
+                if let Some(title) = &image.title {
+                    res.push_str(&format!(r#" title="{}""#, escape_html(title)));
+                }
+                res.push_str(&html_attributes(&image.attributes));
+                res.push_str(" />");
+                state.arena.text(res)
+            }
+
+            Inline::Emphasis(content) => state
+                .arena
+                .text("<em>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</em>")),
+
+            Inline::Strong(content) => state
+                .arena
+                .text("<strong>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</strong>")),
+
+            Inline::Strikethrough(content) => state
+                .arena
+                .text("<del>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</del>")),
+
+            Inline::Superscript(content) => state
+                .arena
+                .text("<sup>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</sup>")),
+
+            Inline::Subscript(content) => state
+                .arena
+                .text("<sub>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</sub>")),
+
+            Inline::Highlight(content) => state
+                .arena
+                .text("<mark>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</mark>")),
+
+            Inline::Insert(content) => state
+                .arena
+                .text("<ins>")
+                .append(content.to_doc(state))
+                .append(state.arena.text("</ins>")),
+
+            Inline::WikiLink(wiki_link) => state.arena.text(format!(
+                "{}{}</a>",
+                link_open(&wiki_link.destination(), &None, &Attributes::default()),
+                escape_html(&wiki_link.text())
+            )),
+
+            Inline::Span(span) => state
+                .arena
+                .text(format!("<span{}>", html_attributes(&span.attributes)))
+                .append(span.children.to_doc(state))
+                .append(state.arena.text("</span>")),
+
+            Inline::GitHubReference(reference) => match &reference.destination {
+                Some(destination) => state.arena.text(format!(
+                    r#"<a href="{}">{}</a>"#,
+                    escape_url(destination),
+                    escape_html(&reference.text())
+                )),
+                None => state.arena.text(escape_html(&reference.text())),
+            },
+
+            Inline::Citation(citation) => state.arena.text(format!(
+                r#"<span class="citation" data-cites="{}">{}</span>"#,
+                escape_html(&citation.keys().collect::<Vec<_>>().join(" ")),
+                escape_html(&citation.text())
+            )),
+
+            Inline::Emoji { glyph, .. } => state.arena.text(escape_html(glyph)),
+
+            Inline::Abbreviation { text, title } => state.arena.text(if title.is_empty() {
+                format!("<abbr>{}</abbr>", escape_html(text))
+            } else {
+                format!(
+                    r#"<abbr title="{}">{}</abbr>"#,
+                    escape_html(title),
+                    escape_html(text)
+                )
+            }),
+
+            Inline::Extension(extension) => {
+                let children = extension.children.to_doc(state);
+                if let Some(renderer) = &state.config.inline_extension_renderer {
+                    let rendered = render_to_string(children.clone(), state.config.width);
+                    if let Some(html) = renderer(extension, &rendered) {
+                        return verbatim(state.arena, &html);
+                    }
+                }
+                state
+                    .arena
+                    .text(open_tag("span", &extension.name, &extension.attrs))
+                    .append(children)
+                    .append(state.arena.text("</span>"))
+            }
+
+            Inline::Autolink { url, .. } => {
+                let href = if url.contains('@') && !url.contains(':') {
+                    format!("mailto:{url}")
+                } else if url.starts_with("www.") {
+                    format!("http://{url}")
+                } else {
+                    url.clone()
+                };
+                state.arena.text(format!(
+                    r#"<a href="{}">{}</a>"#,
+                    escape_url(&href),
+                    escape_html(url)
+                ))
+            }
+
+            Inline::FootnoteReference(label) => match state.footnote_number(label) {
+                Some(number) => {
+                    let label = escape_html(label);
+                    state.arena.text(format!(
+                        r##"<sup class="footnote-ref"><a href="#fn-{label}" id="fnref-{label}">{number}</a></sup>"##
+                    ))
+                }
+                None => state.arena.text(format!("[^{}]", escape_html(label))),
+            },
+
+            Inline::Empty => state.arena.nil(),
+
+            Inline::Latex { content: latex, .. } => state
+                .arena
+                .text(r#"<span class="math inline">\("#)
+                .append(verbatim(state.arena, &escape_html(latex)))
+                .append(state.arena.text(r"\)</span>")),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! HTML printer for Markdown AST
+//!
+//! This module provides functionality to render a Markdown Abstract Syntax Tree (AST)
+//! into HTML. The output follows the conventions of the CommonMark reference
+//! renderer and GitHub, so it can be dropped into any web page as-is.
+//!
+//! # Features
+//!
+//! - **Full AST coverage**: All block and inline elements from CommonMark + GFM
+//! - **Proper HTML escaping**: Text, attributes and URLs are escaped
+//! - **GitHub extensions**: Alerts, task lists, footnotes, strikethrough, tables
+//! - **Extended tables**: `colspan` and `rowspan` for merged cells
+//! - **LaTeX passthrough**: Math is emitted verbatim for MathJax/KaTeX
+//! - **Width control**: Configurable line width for pretty-printing
+//!
+//! # Basic Usage
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::html_printer::{render_html, config::Config};
+//!
+//! let doc = Document {
+//!     blocks: vec![
+//!         Block::Heading(Heading {
+//!             kind: HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
+//!             content: vec![Inline::Text("Hello HTML".to_string())],
+//!         }),
+//!         Block::Paragraph(vec![
+//!             Inline::Text("This is ".to_string()),
+//!             Inline::Strong(vec![Inline::Text("bold".to_string())]),
+//!             Inline::Text(" text.".to_string()),
+//!         ]),
+//!     ],
+//! };
+//!
+//! let html = render_html(&doc, Config::default());
+//! assert_eq!(html, "<h1>Hello HTML</h1>\n<p>This is <strong>bold</strong> text.</p>\n");
+//! ```
+//!
+//! # HTML Element Mappings
+//!
+//! | Markdown          | HTML                                               |
+//! |-------------------|----------------------------------------------------|
+//! | `# Heading`       | `<h1>Heading</h1>`                                 |
+//! | `**bold**`        | `<strong>bold</strong>`                            |
+//! | `*italic*`        | `<em>italic</em>`                                  |
+//! | `~~strike~~`      | `<del>strike</del>`                                |
+//! | `` `code` ``      | `<code>code</code>`                                |
+//! | `> quote`         | `<blockquote>...</blockquote>`                     |
+//! | `- list`          | `<ul><li>...</li></ul>`                            |
+//! | `1. ordered`      | `<ol><li>...</li></ol>`                            |
+//! | `[link](url)`     | `<a href="url">link</a>`                           |
+//! | `![img](url)`     | `<img src="url" alt="img" />`                      |
+//! | `[^note]`         | `<sup class="footnote-ref"><a ...>1</a></sup>`     |
+//! | `> [!NOTE]`       | `<div class="markdown-alert markdown-alert-note">` |
+//! | `$x$`             | `<span class="math inline">\(x\)</span>`           |
+//! | `$$x$$`           | `<div class="math display">\[x\]</div>`            |
+//! | `:::kind`         | `<div class="kind">...</div>`                      |
+//! | `[@key, p. 33]`   | `<span class="citation" data-cites="key">...</span>` |
+//! | Tables            | `<table>...</table>`                               |
+//! | Figures           | `<figure>...<figcaption>...</figcaption></figure>` |
+//! | Code blocks       | `<pre><code class="language-x">...</code></pre>`   |
+
+mod block;
+pub mod config;
+mod inline;
+mod table;
+pub mod util;
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::*;
+use pretty::{Arena, DocBuilder};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Internal state for HTML rendering
+///
+/// This structure holds the rendering context including the pretty-printer arena,
+/// configuration, and pre-processed indices for footnotes and link definitions.
+pub(crate) struct State<'a> {
+    arena: &'a Arena<'a>,
+    config: &'a crate::html_printer::config::Config,
+    /// Mapping of footnote labels to their definitions.
+    footnote_definitions: &'a HashMap<String, FootnoteDefinition>,
+    /// Mapping of normalized link labels to their definitions.
+    link_definitions: &'a HashMap<String, LinkDefinition>,
+    /// Footnote labels in order of their first reference.
+    footnote_order: RefCell<Vec<String>>,
+    /// Identifiers of the headings rendered so far.
+    slugger: RefCell<Slugger>,
+}
+
+impl<'a> State<'a> {
+    /// Create a new rendering state
+    pub fn new(
+        arena: &'a Arena<'a>,
+        config: &'a crate::html_printer::config::Config,
+        footnote_definitions: &'a HashMap<String, FootnoteDefinition>,
+        link_definitions: &'a HashMap<String, LinkDefinition>,
+    ) -> Self {
+        Self {
+            arena,
+            config,
+            footnote_definitions,
+            link_definitions,
+            footnote_order: RefCell::new(Vec::new()),
+            slugger: RefCell::new(Slugger::new()),
+        }
+    }
+
+    /// Get the `id` to emit for a heading, if any
+    ///
+    /// Identifiers are assigned in rendering order.
+    pub fn heading_id(&self, heading: &Heading) -> Option<String> {
+        let id = if self.config.heading_ids {
+            self.slugger.borrow_mut().heading_id(heading)
+        } else {
+            heading.attributes.id.clone()?
+        };
+        // A heading without text has an empty slug
+        (!id.is_empty()).then_some(id)
+    }
+
+    /// Get the link definition for a reference link
+    ///
+    /// Labels are matched as described in [`normalize_label`].
+    /// Returns `None` if the link reference is not defined in the document.
+    pub fn get_link_definition(&self, label: &[Inline]) -> Option<&LinkDefinition> {
+        self.link_definitions.get(&normalize_label(label))
+    }
+
+    /// Get the number of a footnote, registering it on first reference
+    ///
+    /// Footnotes are numbered in the order they are first referenced.
+    /// Returns `None` if the footnote is not defined in the document.
+    pub fn footnote_number(&self, label: &str) -> Option<usize> {
+        if !self.footnote_definitions.contains_key(label) {
+            return None;
+        }
+        let mut order = self.footnote_order.borrow_mut();
+        match order.iter().position(|l| l == label) {
+            Some(index) => Some(index + 1),
+            None => {
+                order.push(label.to_string());
+                Some(order.len())
+            }
+        }
+    }
+}
+
+/// Render the given Markdown AST to HTML
+///
+/// This is the main entry point for HTML rendering. It takes a parsed Markdown
+/// document and configuration, then produces an HTML fragment. Footnotes that
+/// are referenced in the document are collected into a
+/// `<section class="footnotes">` at the end.
+///
+/// # Arguments
+///
+/// * `ast` - The parsed Markdown document as an AST
+/// * `config` - Configuration for rendering (width, etc.)
+///
+/// # Returns
+///
+/// HTML source code as a string.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::html_printer::{render_html, config::Config};
+///
+/// let doc = Document {
+///     blocks: vec![
+///         Block::Paragraph(vec![
+///             Inline::Text("Visit ".to_string()),
+///             Inline::Link(Link {
+///                 destination: "https://example.com".to_string(),
+///                 title: None,
+///                 children: vec![Inline::Text("this link".to_string())],
+///                 attributes: Attributes::default(),
+///             }),
+///         ]),
+///         Block::List(List {
+///             kind: ListKind::Bullet(ListBulletKind::Star),
+///             items: vec![ListItem {
+///                 task: Some(TaskState::Complete),
+///                 blocks: vec![Block::Paragraph(vec![Inline::Text("Done".to_string())])],
+///             }],
+///         }),
+///     ],
+/// };
+///
+/// let html = render_html(&doc, Config::default());
+/// assert!(html.contains(r#"<a href="https://example.com">this link</a>"#));
+/// assert!(html.contains(r#"<input type="checkbox" checked="" disabled="" /> Done"#));
+/// ```
+pub fn render_html(ast: &Document, config: crate::html_printer::config::Config) -> String {
+    let (footnote_definitions, link_definitions) = get_indices(ast);
+    let arena = Arena::new();
+    let state = State::new(&arena, &config, &footnote_definitions, &link_definitions);
+    let doc = ast.to_doc(&state);
+
+    let mut buf = Vec::new();
+    doc.render(config.width, &mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+/// Internal trait for converting AST nodes to pretty-printer documents
+///
+/// This trait is implemented by all AST node types and provides the core
+/// rendering logic for each element type.
+trait ToDoc<'a> {
+    /// Convert this AST node to a pretty-printer document
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()>;
+}
+
+impl<'a> ToDoc<'a> for Document {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        let body = self.blocks.to_doc(state);
+        // Footnotes are numbered while the body is rendered, so the section
+        // has to be built afterwards.
+        body.append(block::footnotes_section(state))
+    }
+}
+
+/// Extract footnote and link definition indices from the document
+///
+/// This function performs a pre-processing pass over the AST to collect
+/// footnote definitions by label and link definitions by normalized label.
+/// When a link label is defined more than once, the first definition wins.
+fn get_indices(
+    ast: &Document,
+) -> (
+    HashMap<String, FootnoteDefinition>,
+    HashMap<String, LinkDefinition>,
+) {
+    let mut footnote_definitions = HashMap::new();
+    let mut link_definitions = HashMap::new();
+
+    fn process_blocks(
+        blocks: &[Block],
+        footnote_definitions: &mut HashMap<String, FootnoteDefinition>,
+        link_definitions: &mut HashMap<String, LinkDefinition>,
+    ) {
+        for block in blocks {
+            match block {
+                Block::FootnoteDefinition(def) => {
+                    footnote_definitions
+                        .entry(def.label.clone())
+                        .or_insert_with(|| def.clone());
+                }
+                Block::Definition(def) => {
+                    link_definitions
+                        .entry(normalize_label(&def.label))
+                        .or_insert_with(|| def.clone());
+                }
+                Block::List(list) => {
+                    for item in &list.items {
+                        process_blocks(&item.blocks, footnote_definitions, link_definitions);
+                    }
+                }
+                Block::DefinitionList(list) => {
+                    for definition in list.items.iter().flat_map(|item| &item.definitions) {
+                        process_blocks(definition, footnote_definitions, link_definitions);
+                    }
+                }
+                Block::BlockQuote(blocks) => {
+                    process_blocks(blocks, footnote_definitions, link_definitions);
+                }
+                Block::GitHubAlert(alert) => {
+                    process_blocks(&alert.blocks, footnote_definitions, link_definitions);
+                }
+                Block::Container(container) => {
+                    process_blocks(&container.blocks, footnote_definitions, link_definitions);
+                }
+                Block::Extension(extension) => {
+                    process_blocks(&extension.children, footnote_definitions, link_definitions);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    process_blocks(
+        &ast.blocks,
+        &mut footnote_definitions,
+        &mut link_definitions,
+    );
+
+    (footnote_definitions, link_definitions)
+}
@@ -0,0 +1,76 @@
+use crate::ast::*;
+use crate::html_printer::util::html_attributes;
+use crate::html_printer::{State, ToDoc};
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+impl<'a> ToDoc<'a> for Table {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        if self.rows.is_empty() {
+            return state.arena.nil();
+        }
+
+        let mut content = state
+            .arena
+            .text(format!("<table{}>", html_attributes(&self.attributes)))
+            .append(state.arena.hardline())
+            .append(state.arena.text("<thead>"))
+            .append(state.arena.hardline())
+            .append(row_to_doc(self, &self.rows[0], "th", state))
+            .append(state.arena.hardline())
+            .append(state.arena.text("</thead>"))
+            .append(state.arena.hardline());
+
+        if self.rows.len() > 1 {
+            content = content
+                .append(state.arena.text("<tbody>"))
+                .append(state.arena.hardline());
+            for row in &self.rows[1..] {
+                content = content
+                    .append(row_to_doc(self, row, "td", state))
+                    .append(state.arena.hardline());
+            }
+            content = content
+                .append(state.arena.text("</tbody>"))
+                .append(state.arena.hardline());
+        }
+
+        content.append(state.arena.text("</table>"))
+    }
+}
+
+fn row_to_doc<'a>(
+    table: &Table,
+    row: &TableRow,
+    tag: &'static str,
+    state: &'a State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let mut doc = state.arena.text("<tr>").append(state.arena.hardline());
+    for (column, cell) in row.iter().enumerate() {
+        // Cells covered by a neighbour's colspan/rowspan are not emitted
+        if cell.removed_by_extended_table {
+            continue;
+        }
+
+        let mut open = format!("<{tag}");
+        match table.alignments.get(column) {
+            Some(Alignment::Left) => open.push_str(r#" align="left""#),
+            Some(Alignment::Center) => open.push_str(r#" align="center""#),
+            Some(Alignment::Right) => open.push_str(r#" align="right""#),
+            Some(Alignment::None) | None => {}
+        }
+        if let Some(colspan) = cell.colspan.filter(|&colspan| colspan > 1) {
+            open.push_str(&format!(r#" colspan="{colspan}""#));
+        }
+        if let Some(rowspan) = cell.rowspan.filter(|&rowspan| rowspan > 1) {
+            open.push_str(&format!(r#" rowspan="{rowspan}""#));
+        }
+        open.push('>');
+
+        doc = doc
+            .append(state.arena.text(open))
+            .append(cell.content.to_doc(state))
+            .append(state.arena.text(format!("</{tag}>")))
+            .append(state.arena.hardline());
+    }
+    doc.append(state.arena.text("</tr>"))
+}
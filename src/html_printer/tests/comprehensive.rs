@@ -0,0 +1,504 @@
+use crate::ast::*;
+use crate::html_printer::{config::*, render_html};
+
+fn paragraph(text: &str) -> Block {
+    Block::Paragraph(vec![Inline::Text(text.to_string())])
+}
+
+#[test]
+fn test_thematic_break_and_html_block() {
+    let doc = Document {
+        blocks: vec![
+            Block::ThematicBreak,
+            Block::HtmlBlock("<div>\nRaw HTML\n</div>".to_string()),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<hr />\n<div>\nRaw HTML\n</div>\n");
+}
+
+#[test]
+fn test_tight_bullet_list_with_tasks() {
+    let doc = Document {
+        blocks: vec![Block::List(List {
+            kind: ListKind::Bullet(ListBulletKind::Dash),
+            items: vec![
+                ListItem {
+                    task: Some(TaskState::Complete),
+                    blocks: vec![paragraph("done")],
+                },
+                ListItem {
+                    task: Some(TaskState::Incomplete),
+                    blocks: vec![paragraph("todo")],
+                },
+            ],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<ul>\n<li><input type=\"checkbox\" checked=\"\" disabled=\"\" /> done</li>\n<li><input type=\"checkbox\" disabled=\"\" /> todo</li>\n</ul>\n"
+    );
+}
+
+#[test]
+fn test_ordered_list_start_and_nesting() {
+    let doc = Document {
+        blocks: vec![Block::List(List {
+            kind: ListKind::Ordered(ListOrderedKindOptions { start: 3 }),
+            items: vec![ListItem {
+                task: None,
+                blocks: vec![
+                    paragraph("outer"),
+                    Block::List(List {
+                        kind: ListKind::Bullet(ListBulletKind::Star),
+                        items: vec![ListItem {
+                            task: None,
+                            blocks: vec![paragraph("inner")],
+                        }],
+                    }),
+                ],
+            }],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<ol start=\"3\">\n<li>outer\n<ul>\n<li>inner</li>\n</ul>\n</li>\n</ol>\n"
+    );
+}
+
+#[test]
+fn test_loose_list() {
+    let doc = Document {
+        blocks: vec![Block::List(List {
+            kind: ListKind::Bullet(ListBulletKind::Star),
+            items: vec![ListItem {
+                task: None,
+                blocks: vec![paragraph("one"), paragraph("two")],
+            }],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>\n");
+}
+
+#[test]
+fn test_github_alerts() {
+    let alert_types = vec![
+        (GitHubAlertType::Note, "note", "Note"),
+        (GitHubAlertType::Tip, "tip", "Tip"),
+        (GitHubAlertType::Important, "important", "Important"),
+        (GitHubAlertType::Warning, "warning", "Warning"),
+        (GitHubAlertType::Caution, "caution", "Caution"),
+        (GitHubAlertType::Custom("Info".to_string()), "info", "Info"),
+    ];
+
+    for (alert_type, class, title) in alert_types {
+        let doc = Document {
+            blocks: vec![Block::GitHubAlert(GitHubAlert {
+                alert_type,
+                blocks: vec![paragraph("Body")],
+            })],
+        };
+
+        let result = render_html(&doc, Config::default());
+        assert_eq!(
+            result,
+            format!("<div class=\"markdown-alert markdown-alert-{class}\">\n<p class=\"markdown-alert-title\">{title}</p>\n<p>Body</p>\n</div>\n")
+        );
+    }
+}
+
+#[test]
+fn test_footnotes_in_reference_order() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::Text("A".to_string()),
+                Inline::FootnoteReference("second".to_string()),
+                Inline::Text(" B".to_string()),
+                Inline::FootnoteReference("first".to_string()),
+                Inline::FootnoteReference("second".to_string()),
+            ]),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "first".to_string(),
+                blocks: vec![paragraph("First note.")],
+            }),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "second".to_string(),
+                blocks: vec![paragraph("Second note.")],
+            }),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "unused".to_string(),
+                blocks: vec![paragraph("Never referenced.")],
+            }),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    let expected = concat!(
+        "<p>A<sup class=\"footnote-ref\"><a href=\"#fn-second\" id=\"fnref-second\">1</a></sup>",
+        " B<sup class=\"footnote-ref\"><a href=\"#fn-first\" id=\"fnref-first\">2</a></sup>",
+        "<sup class=\"footnote-ref\"><a href=\"#fn-second\" id=\"fnref-second\">1</a></sup></p>\n",
+        "<section class=\"footnotes\">\n<ol>\n",
+        "<li id=\"fn-second\">\n<p>Second note. <a href=\"#fnref-second\" class=\"footnote-backref\" aria-label=\"Back to reference 1\">↩</a></p>\n</li>\n",
+        "<li id=\"fn-first\">\n<p>First note. <a href=\"#fnref-first\" class=\"footnote-backref\" aria-label=\"Back to reference 2\">↩</a></p>\n</li>\n",
+        "</ol>\n</section>\n",
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_heading_ids() {
+    let heading = |text: &str, id: Option<&str>| {
+        Block::Heading(Heading {
+            kind: HeadingKind::Atx(2),
+            attributes: Attributes {
+                id: id.map(str::to_string),
+                ..Default::default()
+            },
+            content: vec![Inline::Text(text.to_string())],
+        })
+    };
+    let doc = Document {
+        blocks: vec![
+            heading("Usage", None),
+            heading("Usage", Some("usage-1")),
+            heading("Usage", None),
+            heading("Q&A <b>", Some("faq")),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert!(result.starts_with("<h2>Usage</h2>\n<h2 id=\"usage-1\">Usage</h2>\n<h2>Usage</h2>\n"));
+
+    let result = render_html(&doc, Config::default().with_heading_ids(true));
+    assert_eq!(
+        result,
+        concat!(
+            "<h2 id=\"usage\">Usage</h2>\n",
+            "<h2 id=\"usage-1\">Usage</h2>\n",
+            "<h2 id=\"usage-2\">Usage</h2>\n",
+            "<h2 id=\"faq\">Q&amp;A &lt;b&gt;</h2>\n",
+        )
+    );
+}
+
+#[test]
+fn test_extension_nodes() {
+    let doc = Document {
+        blocks: vec![Block::Extension(BlockExtension {
+            name: "aside".to_string(),
+            attrs: vec![("kind".to_string(), "tip".to_string())],
+            children: vec![Block::Paragraph(vec![
+                Inline::Text("Press ".to_string()),
+                Inline::Extension(InlineExtension {
+                    name: "kbd".to_string(),
+                    attrs: vec![],
+                    children: vec![Inline::Text("Ctrl".to_string())],
+                }),
+            ])],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<div class=\"aside\" data-kind=\"tip\">\n<p>Press <span class=\"kbd\">Ctrl</span></p>\n</div>\n"
+    );
+
+    let config = Config::default()
+        .with_block_extension_renderer(std::rc::Rc::new(|extension, children| {
+            (extension.name == "aside").then(|| format!("<aside>\n{children}\n</aside>"))
+        }))
+        .with_inline_extension_renderer(std::rc::Rc::new(|extension, children| {
+            (extension.name == "kbd").then(|| format!("<kbd>{children}</kbd>"))
+        }));
+    let result = render_html(&doc, config);
+    assert_eq!(result, "<aside>\n<p>Press <kbd>Ctrl</kbd></p>\n</aside>\n");
+}
+
+#[test]
+fn test_wiki_link() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::WikiLink(WikiLink {
+            target: "Q&A".to_string(),
+            fragment: Some("Setup".to_string()),
+            alias: Some("<setup>".to_string()),
+            embed: false,
+        })])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><a href=\"Q&amp;A#Setup\">&lt;setup&gt;</a></p>\n"
+    );
+}
+
+#[test]
+fn test_definition_list() {
+    let doc = Document {
+        blocks: vec![Block::DefinitionList(DefinitionList {
+            items: vec![DefinitionListItem {
+                term: vec![Inline::Strong(vec![Inline::Text("Apple".to_string())])],
+                definitions: vec![
+                    vec![paragraph("A fruit")],
+                    vec![paragraph("Red"), paragraph("or green")],
+                ],
+            }],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<dl>\n<dt><strong>Apple</strong></dt>\n<dd>A fruit</dd>\n<dd>\n<p>Red</p>\n<p>or green</p>\n</dd>\n</dl>\n"
+    );
+}
+
+#[test]
+fn test_attributes() {
+    let attributes = Attributes {
+        id: Some("main".to_string()),
+        classes: vec!["a".to_string(), "b".to_string()],
+        pairs: vec![("data-x".to_string(), "\"1\"".to_string())],
+    };
+    let doc = Document {
+        blocks: vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes {
+                    classes: vec!["title".to_string()],
+                    ..Default::default()
+                },
+                content: vec![Inline::Text("Title".to_string())],
+            }),
+            Block::CodeBlock(CodeBlock {
+                kind: CodeBlockKind::Fenced {
+                    info: Some("rust".to_string()),
+                },
+                literal: "fn main() {}".to_string(),
+                attributes: attributes.clone(),
+            }),
+            Block::Paragraph(vec![Inline::Link(Link {
+                destination: "/docs".to_string(),
+                title: None,
+                children: vec![Inline::Text("docs".to_string())],
+                attributes: Attributes {
+                    pairs: vec![("target".to_string(), "_blank".to_string())],
+                    ..Default::default()
+                },
+            })]),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert!(
+        result.contains(r#"<h1 class="title">Title</h1>"#),
+        "{result}"
+    );
+    assert!(
+        result.contains(r#"<pre id="main" class="a b" data-x="&quot;1&quot;">"#),
+        "{result}"
+    );
+    assert!(
+        result.contains(r#"<a href="/docs" target="_blank">docs</a>"#),
+        "{result}"
+    );
+}
+
+#[test]
+fn test_span() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Span(Span {
+            attributes: Attributes {
+                id: Some("key".to_string()),
+                classes: vec!["kbd".to_string()],
+                ..Default::default()
+            },
+            children: vec![Inline::Text("Ctrl".to_string())],
+        })])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><span id=\"key\" class=\"kbd\">Ctrl</span></p>\n"
+    );
+}
+
+#[test]
+fn test_emoji() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Text("Ship it ".to_string()),
+            Inline::Emoji {
+                shortcode: "rocket".to_string(),
+                glyph: "🚀".to_string(),
+            },
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<p>Ship it 🚀</p>\n");
+}
+
+#[test]
+fn test_github_reference() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::GitHubReference(GitHubReference {
+                kind: GitHubReferenceKind::Issue {
+                    repository: None,
+                    number: 12,
+                },
+                destination: Some("https://github.com/o/r/issues/12".to_string()),
+            }),
+            Inline::Text(" by ".to_string()),
+            Inline::GitHubReference(GitHubReference {
+                kind: GitHubReferenceKind::Mention("alice".to_string()),
+                destination: None,
+            }),
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><a href=\"https://github.com/o/r/issues/12\">#12</a> by @alice</p>\n"
+    );
+}
+
+#[test]
+fn test_footnotes_title() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![Inline::FootnoteReference("a".to_string())]),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "a".to_string(),
+                blocks: vec![paragraph("Note")],
+            }),
+        ],
+    };
+
+    let result = render_html(
+        &doc,
+        Config::default().with_footnotes_title(Some("Notes".to_string())),
+    );
+    assert!(result.contains("<section class=\"footnotes\">\n<h2>Notes</h2>\n<ol>\n"));
+}
+
+#[test]
+fn test_undefined_footnote_reference() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::FootnoteReference(
+            "missing".to_string(),
+        )])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<p>[^missing]</p>\n");
+}
+
+#[test]
+fn test_latex_passthrough() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::Text("Inline ".to_string()),
+                Inline::Latex {
+                    delimiter: InlineMathDelimiter::Dollar,
+                    content: "a < b".to_string(),
+                },
+            ]),
+            Block::LatexBlock {
+                delimiter: DisplayMathDelimiter::DoubleDollar,
+                content: "\\sum_{i=1}^n i".to_string(),
+            },
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p>Inline <span class=\"math inline\">\\(a &lt; b\\)</span></p>\n<div class=\"math display\">\\[\\sum_{i=1}^n i\\]</div>\n"
+    );
+}
+
+#[test]
+fn test_container() {
+    let doc = Document {
+        blocks: vec![Block::Container(Container {
+            kind: "warning".to_string(),
+            params: vec![("title".to_string(), "Be careful".to_string())],
+            blocks: vec![paragraph("Content")],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<div class=\"warning\" data-title=\"Be careful\">\n<p>Content</p>\n</div>\n"
+    );
+}
+
+#[test]
+fn test_autolinks() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Autolink {
+                url: "https://example.com".to_string(),
+                bare: false,
+            },
+            Inline::Text(" ".to_string()),
+            Inline::Autolink {
+                url: "user@example.com".to_string(),
+                bare: false,
+            },
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><a href=\"https://example.com\">https://example.com</a> <a href=\"mailto:user@example.com\">user@example.com</a></p>\n"
+    );
+}
+
+#[test]
+fn test_bare_www_autolink() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Autolink {
+            url: "www.example.com".to_string(),
+            bare: true,
+        }])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><a href=\"http://www.example.com\">www.example.com</a></p>\n"
+    );
+}
+
+#[test]
+fn test_line_break_and_raw_html() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Text("one".to_string()),
+            Inline::LineBreak,
+            Inline::Html("<kbd>".to_string()),
+            Inline::Text("two".to_string()),
+            Inline::Html("</kbd>".to_string()),
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<p>one<br />\n<kbd>two</kbd></p>\n");
+}
@@ -0,0 +1,122 @@
+use crate::ast::*;
+use crate::html_printer::{config::*, render_html};
+
+#[test]
+fn test_empty_document() {
+    let doc = Document { blocks: vec![] };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "");
+}
+
+#[test]
+fn test_invisible_blocks() {
+    let doc = Document {
+        blocks: vec![
+            Block::Empty,
+            Block::MacroBlock("macro".to_string()),
+            Block::Definition(LinkDefinition {
+                label: vec![Inline::Text("example".to_string())],
+                destination: "https://example.com".to_string(),
+                title: None,
+            }),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "note".to_string(),
+                blocks: vec![Block::Paragraph(vec![Inline::Text("Note".to_string())])],
+            }),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "");
+}
+
+#[test]
+fn test_empty_paragraph() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<p></p>\n");
+}
+
+#[test]
+fn test_empty_list_item() {
+    let doc = Document {
+        blocks: vec![Block::List(List {
+            kind: ListKind::Bullet(ListBulletKind::Star),
+            items: vec![ListItem {
+                task: None,
+                blocks: vec![],
+            }],
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<ul>\n<li></li>\n</ul>\n");
+}
+
+#[test]
+fn test_indented_code_block_without_language() {
+    let doc = Document {
+        blocks: vec![Block::CodeBlock(CodeBlock {
+            kind: CodeBlockKind::Indented,
+            literal: "x & y\n".to_string(),
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<pre><code>x &amp; y\n</code></pre>\n");
+}
+
+#[test]
+fn test_nested_footnote_references() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![Inline::FootnoteReference("a".to_string())]),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "a".to_string(),
+                blocks: vec![Block::Paragraph(vec![
+                    Inline::Text("See".to_string()),
+                    Inline::FootnoteReference("b".to_string()),
+                ])],
+            }),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "b".to_string(),
+                blocks: vec![Block::CodeBlock(CodeBlock {
+                    kind: CodeBlockKind::Indented,
+                    literal: "code".to_string(),
+
+                    attributes: Attributes::default(),
+                })],
+            }),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert!(result.contains("<li id=\"fn-a\">"));
+    assert!(result.contains(
+        "<li id=\"fn-b\">\n<pre><code>code\n</code></pre>\n<a href=\"#fnref-b\" class=\"footnote-backref\" aria-label=\"Back to reference 2\">↩</a>\n</li>"
+    ));
+}
+
+#[test]
+fn test_escaped_attributes() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Image(Image {
+            destination: "a\"b.png".to_string(),
+            title: Some("say \"hi\"".to_string()),
+            alt: "<alt>".to_string(),
+            attributes: Attributes::default(),
+        })])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><img src=\"a%22b.png\" alt=\"&lt;alt&gt;\" title=\"say &quot;hi&quot;\" /></p>\n"
+    );
+}
@@ -0,0 +1,332 @@
+mod comprehensive;
+mod edge_cases;
+mod table;
+
+use crate::ast::*;
+use crate::html_printer::{config::*, render_html};
+
+#[test]
+fn test_simple_paragraph() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "Hello, world!".to_string(),
+        )])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<p>Hello, world!</p>\n");
+}
+
+#[test]
+fn test_html_escaping() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "Special chars: <tag> & \"quotes\"".to_string(),
+        )])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p>Special chars: &lt;tag&gt; &amp; &quot;quotes&quot;</p>\n"
+    );
+}
+
+#[test]
+fn test_headings() {
+    let doc = Document {
+        blocks: vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(3),
+                attributes: Attributes::default(),
+                content: vec![Inline::Text("Atx".to_string())],
+            }),
+            Block::Heading(Heading {
+                kind: HeadingKind::Setext(SetextHeading::Level2),
+                attributes: Attributes::default(),
+                content: vec![Inline::Text("Setext".to_string())],
+            }),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<h3>Atx</h3>\n<h2>Setext</h2>\n");
+}
+
+#[test]
+fn test_inline_formatting() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Strong(vec![Inline::Text("bold".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Strikethrough(vec![Inline::Text("gone".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Code("a < b".to_string()),
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><strong>bold</strong> <em>italic</em> <del>gone</del> <code>a &lt; b</code></p>\n"
+    );
+}
+
+#[test]
+fn test_superscript_subscript_highlight_insert() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Text("x".to_string()),
+            Inline::Superscript(vec![Inline::Text("2".to_string())]),
+            Inline::Text(" H".to_string()),
+            Inline::Subscript(vec![Inline::Text("2".to_string())]),
+            Inline::Text("O ".to_string()),
+            Inline::Highlight(vec![Inline::Text("key".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Insert(vec![Inline::Text("new".to_string())]),
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p>x<sup>2</sup> H<sub>2</sub>O <mark>key</mark> <ins>new</ins></p>\n"
+    );
+}
+
+#[test]
+fn test_figure() {
+    let doc = Document {
+        blocks: vec![
+            Block::Figure {
+                content: vec![Block::Paragraph(vec![Inline::Image(Image {
+                    destination: "cat.png".to_string(),
+                    title: None,
+                    alt: "A cat".to_string(),
+                    attributes: Attributes::default(),
+                })])],
+                caption: vec![Inline::Text("A cat".to_string())],
+                label: Some("fig-cat".to_string()),
+                caption_syntax: CaptionSyntax::Image,
+            },
+            Block::Figure {
+                content: vec![Block::Table(Table {
+                    rows: vec![vec![TableCell {
+                        content: vec![Inline::Text("a".to_string())],
+                        colspan: None,
+                        rowspan: None,
+                        removed_by_extended_table: false,
+                    }]],
+                    alignments: vec![Alignment::None],
+                    attributes: Attributes::default(),
+                })],
+                caption: vec![Inline::Text("Results".to_string())],
+                label: None,
+                caption_syntax: CaptionSyntax::Colon,
+            },
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<figure id=\"fig-cat\">\n<img src=\"cat.png\" alt=\"A cat\" />\n<figcaption>A cat</figcaption>\n</figure>\n\
+         <figure>\n<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>\n<figcaption>Results</figcaption>\n</figure>\n"
+    );
+}
+
+#[test]
+fn test_abbreviation() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::Abbreviation {
+                    text: "HTML".to_string(),
+                    title: "HyperText \"Markup\" Language".to_string(),
+                },
+                Inline::Text(" and ".to_string()),
+                Inline::Abbreviation {
+                    text: "TBD".to_string(),
+                    title: String::new(),
+                },
+            ]),
+            Block::AbbreviationDefinition {
+                abbreviation: "HTML".to_string(),
+                expansion: "HyperText \"Markup\" Language".to_string(),
+            },
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><abbr title=\"HyperText &quot;Markup&quot; Language\">HTML</abbr> and <abbr>TBD</abbr></p>\n"
+    );
+}
+
+#[test]
+fn test_citation() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Citation(Citation {
+            items: vec![
+                CitationItem {
+                    key: "doe99".to_string(),
+                    prefix: Some("see".to_string()),
+                    locator: Some("p. <33>".to_string()),
+                    suppress_author: false,
+                },
+                CitationItem {
+                    key: "smith04".to_string(),
+                    prefix: None,
+                    locator: None,
+                    suppress_author: true,
+                },
+            ],
+            in_text: false,
+        })])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><span class=\"citation\" data-cites=\"doe99 smith04\">[see @doe99, p. &lt;33&gt;; -@smith04]</span></p>\n"
+    );
+}
+
+#[test]
+fn test_link_and_image() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Link(Link {
+                destination: "https://example.com/a b".to_string(),
+                title: Some("Title".to_string()),
+                children: vec![Inline::Text("link".to_string())],
+
+                attributes: Attributes::default(),
+            }),
+            Inline::Image(Image {
+                destination: "img.png".to_string(),
+                title: None,
+                alt: "alt".to_string(),
+                attributes: Attributes {
+                    pairs: vec![("width".to_string(), "100".to_string())],
+                    ..Default::default()
+                },
+            }),
+        ])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><a href=\"https://example.com/a%20b\" title=\"Title\">link</a><img src=\"img.png\" alt=\"alt\" width=\"100\" /></p>\n"
+    );
+}
+
+#[test]
+fn test_reference_link_resolution() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("Foo  Bar".to_string())],
+                    text: vec![Inline::Text("text".to_string())],
+                    image: false,
+                }),
+                Inline::Text(" ".to_string()),
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("missing".to_string())],
+                    text: vec![Inline::Text("missing".to_string())],
+                    image: false,
+                }),
+            ]),
+            Block::Definition(LinkDefinition {
+                label: vec![Inline::Text("foo bar".to_string())],
+                destination: "/url".to_string(),
+                title: None,
+            }),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<p><a href=\"/url\">text</a> [missing]</p>\n");
+}
+
+#[test]
+fn test_image_reference_resolution() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("logo".to_string())],
+                    text: vec![Inline::Emphasis(vec![Inline::Text("Logo".to_string())])],
+                    image: true,
+                }),
+                Inline::Text(" ".to_string()),
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("missing".to_string())],
+                    text: vec![Inline::Text("missing".to_string())],
+                    image: true,
+                }),
+            ]),
+            Block::Definition(LinkDefinition {
+                label: vec![Inline::Text("logo".to_string())],
+                destination: "/logo.png".to_string(),
+                title: Some("Logo".to_string()),
+            }),
+        ],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<p><img src=\"/logo.png\" alt=\"Logo\" title=\"Logo\" /> ![missing]</p>\n"
+    );
+}
+
+#[test]
+fn test_code_block() {
+    let doc = Document {
+        blocks: vec![Block::CodeBlock(CodeBlock {
+            kind: CodeBlockKind::Fenced {
+                info: Some("rust extra".to_string()),
+            },
+            literal: "fn main() {\n    println!(\"<hi>\");\n}".to_string(),
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<pre><code class=\"language-rust\">fn main() {\n    println!(&quot;&lt;hi&gt;&quot;);\n}\n</code></pre>\n"
+    );
+}
+
+#[test]
+fn test_blockquote() {
+    let doc = Document {
+        blocks: vec![Block::BlockQuote(vec![Block::Paragraph(vec![
+            Inline::Text("quoted".to_string()),
+        ])])],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(result, "<blockquote>\n<p>quoted</p>\n</blockquote>\n");
+}
+
+#[test]
+fn test_config_width() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "A rather long paragraph that is not wrapped regardless of the width".to_string(),
+        )])],
+    };
+
+    let narrow = render_html(&doc, Config::default().with_width(20));
+    let wide = render_html(&doc, Config::default().with_width(200));
+    assert_eq!(narrow, wide);
+}
@@ -0,0 +1,86 @@
+use crate::ast::*;
+use crate::html_printer::{config::*, render_html};
+
+fn cell(text: &str) -> TableCell {
+    TableCell {
+        content: vec![Inline::Text(text.to_string())],
+        colspan: None,
+        rowspan: None,
+        removed_by_extended_table: false,
+    }
+}
+
+fn removed() -> TableCell {
+    TableCell {
+        content: vec![],
+        colspan: None,
+        rowspan: None,
+        removed_by_extended_table: true,
+    }
+}
+
+#[test]
+fn test_simple_table_with_alignment() {
+    let doc = Document {
+        blocks: vec![Block::Table(Table {
+            rows: vec![vec![cell("a"), cell("b")], vec![cell("1"), cell("2")]],
+            alignments: vec![Alignment::None, Alignment::Right],
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        concat!(
+            "<table>\n<thead>\n<tr>\n<th>a</th>\n<th align=\"right\">b</th>\n</tr>\n</thead>\n",
+            "<tbody>\n<tr>\n<td>1</td>\n<td align=\"right\">2</td>\n</tr>\n</tbody>\n</table>\n"
+        )
+    );
+}
+
+#[test]
+fn test_header_only_table() {
+    let doc = Document {
+        blocks: vec![Block::Table(Table {
+            rows: vec![vec![cell("a")]],
+            alignments: vec![Alignment::Center],
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert_eq!(
+        result,
+        "<table>\n<thead>\n<tr>\n<th align=\"center\">a</th>\n</tr>\n</thead>\n</table>\n"
+    );
+}
+
+#[test]
+fn test_colspan_and_rowspan() {
+    let mut wide = cell("wide");
+    wide.colspan = Some(2);
+    let mut tall = cell("tall");
+    tall.rowspan = Some(2);
+
+    let doc = Document {
+        blocks: vec![Block::Table(Table {
+            rows: vec![
+                vec![cell("h1"), cell("h2")],
+                vec![wide, removed()],
+                vec![tall, cell("x")],
+                vec![removed(), cell("y")],
+            ],
+            alignments: vec![Alignment::None, Alignment::None],
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_html(&doc, Config::default());
+    assert!(result.contains("<tr>\n<td colspan=\"2\">wide</td>\n</tr>"));
+    assert!(result.contains("<tr>\n<td rowspan=\"2\">tall</td>\n<td>x</td>\n</tr>"));
+    assert!(result.contains("<tr>\n<td>y</td>\n</tr>"));
+}
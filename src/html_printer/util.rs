@@ -0,0 +1,112 @@
+//! Utility functions for HTML rendering
+//!
+//! This module provides helper functions for HTML generation including
+//! text escaping, URL encoding and label normalization.
+
+use crate::ast::Attributes;
+
+/// Escape HTML special characters in text
+///
+/// # HTML Special Characters
+///
+/// The following characters are escaped:
+/// - `&` → `&amp;`
+/// - `<` → `&lt;`
+/// - `>` → `&gt;`
+/// - `"` → `&quot;`
+///
+/// # Examples
+///
+/// ```rust
+/// # use markdown_ppp::html_printer::util::escape_html;
+/// assert_eq!(escape_html("<b>Tom & Jerry</b>"), "&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;");
+/// assert_eq!(escape_html("\"quoted\""), "&quot;quoted&quot;");
+/// ```
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Escape a URL for use inside an HTML attribute
+///
+/// Characters that are not allowed in URLs are percent-encoded (existing
+/// `%XX` escapes are preserved), then the result is HTML-escaped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use markdown_ppp::html_printer::util::escape_url;
+/// assert_eq!(escape_url("https://example.com/a b"), "https://example.com/a%20b");
+/// assert_eq!(escape_url("/search?q=1&r=2"), "/search?q=1&amp;r=2");
+/// assert_eq!(escape_url("/caf%C3%A9"), "/caf%C3%A9");
+/// ```
+pub fn escape_url(url: &str) -> String {
+    let bytes = url.as_bytes();
+    let mut encoded = String::with_capacity(url.len());
+    for (i, &b) in bytes.iter().enumerate() {
+        let is_escape = b == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit();
+        if b.is_ascii_alphanumeric() || b"-_.!~*'();/?:@&=+$,#".contains(&b) || is_escape {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{b:02X}"));
+        }
+    }
+    escape_html(&encoded)
+}
+
+/// Render an attribute list as HTML attributes, each preceded by a space
+///
+/// The id comes first, then the classes in a single `class` attribute, then
+/// the key-value pairs in order.
+pub(crate) fn html_attributes(attributes: &Attributes) -> String {
+    let mut result = String::new();
+    if let Some(id) = &attributes.id {
+        result.push_str(&format!(r#" id="{}""#, escape_html(id)));
+    }
+    if !attributes.classes.is_empty() {
+        result.push_str(&format!(
+            r#" class="{}""#,
+            escape_html(&attributes.classes.join(" "))
+        ));
+    }
+    for (key, value) in &attributes.pairs {
+        result.push_str(&format!(
+            r#" {}="{}""#,
+            escape_html(key),
+            escape_html(value)
+        ));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html("hello"), "hello");
+        assert_eq!(escape_html("a < b > c"), "a &lt; b &gt; c");
+        assert_eq!(escape_html("&amp;"), "&amp;amp;");
+    }
+
+    #[test]
+    fn test_escape_url() {
+        assert_eq!(escape_url("https://example.com"), "https://example.com");
+        assert_eq!(escape_url("/ä"), "/%C3%A4");
+        assert_eq!(escape_url("a\"b"), "a%22b");
+        assert_eq!(escape_url("100%"), "100%25");
+    }
+}
@@ -0,0 +1,297 @@
+use crate::ast::*;
+use crate::latex_printer::config::{CodeBlockStyle, TableStyle};
+use crate::latex_printer::table::table_to_doc;
+use crate::latex_printer::util::{command, environment, escape_latex, label_id};
+use crate::latex_printer::{State, ToDoc};
+use crate::render_util::verbatim;
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+impl<'a> ToDoc<'a> for Vec<Block> {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        let refs: Vec<_> = self.iter().collect();
+        refs.to_doc(state)
+    }
+}
+
+impl<'a> ToDoc<'a> for Vec<&Block> {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        state.arena.intersperse(
+            self.iter()
+                .filter(|block| !renders_nothing(block))
+                .map(|block| block.to_doc(state)),
+            state.arena.hardline().append(state.arena.hardline()),
+        )
+    }
+}
+
+/// Blocks that produce no LaTeX output at the position they appear in.
+fn renders_nothing(block: &Block) -> bool {
+    matches!(
+        block,
+        Block::Definition(_)
+            | Block::AbbreviationDefinition { .. }
+            | Block::FootnoteDefinition(_)
+            | Block::HtmlBlock(_)
+            | Block::Empty
+            | Block::MacroBlock(_)
+            | Block::FrontMatter { .. }
+    )
+}
+
+impl<'a> ToDoc<'a> for Block {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        match self {
+            Block::Paragraph(inlines) => inlines.to_doc(state),
+
+            Block::Heading(heading) => {
+                let level = match heading.kind {
+                    HeadingKind::Atx(level) => level,
+                    HeadingKind::Setext(SetextHeading::Level1) => 1,
+                    HeadingKind::Setext(SetextHeading::Level2) => 2,
+                };
+                let name = match level {
+                    1 => "section",
+                    2 => "subsection",
+                    3 => "subsubsection",
+                    4 => "paragraph",
+                    _ => "subparagraph",
+                };
+                command(state.arena, name, heading.content.to_doc(state))
+            }
+
+            Block::ThematicBreak => environment(
+                state.arena,
+                "center",
+                "",
+                state.arena.text(r"\rule{0.5\linewidth}{0.5pt}"),
+            ),
+
+            Block::BlockQuote(blocks) => {
+                environment(state.arena, "quote", "", blocks.to_doc(state))
+            }
+
+            Block::List(list) => list.to_doc(state),
+
+            Block::DefinitionList(list) => list.to_doc(state),
+
+            Block::CodeBlock(code_block) => {
+                let lang = match &code_block.kind {
+                    CodeBlockKind::Fenced { info: Some(info) } => info.split_whitespace().next(),
+                    _ => None,
+                };
+                let literal = verbatim(state.arena, code_block.literal.trim_end_matches('\n'));
+                match state.config.code_block_style {
+                    CodeBlockStyle::Listings => {
+                        let args = lang
+                            .map(|lang| format!("[language={lang}]"))
+                            .unwrap_or_default();
+                        environment(state.arena, "lstlisting", &args, literal)
+                    }
+                    CodeBlockStyle::Minted => {
+                        let args = format!("{{{}}}", lang.unwrap_or("text"));
+                        environment(state.arena, "minted", &args, literal)
+                    }
+                }
+            }
+
+            Block::HtmlBlock(_) => state.arena.nil(),
+
+            Block::Definition(_) | Block::AbbreviationDefinition { .. } => state.arena.nil(),
+
+            Block::Table(table) => table.to_doc(state),
+
+            Block::Figure {
+                content,
+                caption,
+                label,
+                ..
+            } => {
+                let mut caption = command(state.arena, "caption", caption.to_doc(state));
+                if let Some(label) = label {
+                    caption = caption.append(command(
+                        state.arena,
+                        "label",
+                        state.arena.text(label_id(label)),
+                    ));
+                }
+                match content.as_slice() {
+                    // A longtable breaks across pages and cannot float, it holds the caption itself
+                    [Block::Table(table)] if state.config.table_style == TableStyle::Longtable => {
+                        table_to_doc(table, Some(caption), state)
+                    }
+                    [Block::Table(_)] => environment(
+                        state.arena,
+                        "table",
+                        "[htbp]",
+                        state
+                            .arena
+                            .text(r"\centering")
+                            .append(state.arena.hardline())
+                            .append(caption)
+                            .append(state.arena.hardline())
+                            .append(content.to_doc(state)),
+                    ),
+                    _ => environment(
+                        state.arena,
+                        "figure",
+                        "[htbp]",
+                        state
+                            .arena
+                            .text(r"\centering")
+                            .append(state.arena.hardline())
+                            .append(content.to_doc(state))
+                            .append(state.arena.hardline())
+                            .append(caption),
+                    ),
+                }
+            }
+
+            Block::FootnoteDefinition(_) => state.arena.nil(),
+
+            Block::GitHubAlert(alert) => {
+                let title = match &alert.alert_type {
+                    GitHubAlertType::Note => "Note",
+                    GitHubAlertType::Tip => "Tip",
+                    GitHubAlertType::Important => "Important",
+                    GitHubAlertType::Warning => "Warning",
+                    GitHubAlertType::Caution => "Caution",
+                    GitHubAlertType::Custom(s) => s,
+                };
+                let mut content =
+                    command(state.arena, "textbf", state.arena.text(escape_latex(title)));
+                if !alert.blocks.is_empty() {
+                    content = content
+                        .append(state.arena.hardline())
+                        .append(state.arena.hardline())
+                        .append(alert.blocks.to_doc(state));
+                }
+                environment(state.arena, "quote", "", content)
+            }
+
+            Block::Empty => state.arena.nil(),
+
+            Block::LatexBlock { content: latex, .. } => state
+                .arena
+                .text(r"\[")
+                .append(state.arena.hardline())
+                .append(verbatim(state.arena, latex))
+                .append(state.arena.hardline())
+                .append(state.arena.text(r"\]")),
+
+            Block::Container(container) => {
+                if container.kind == "figure" {
+                    let mut content = state
+                        .arena
+                        .text(r"\centering")
+                        .append(state.arena.hardline())
+                        .append(container.blocks.to_doc(state));
+                    if let Some((_, caption)) =
+                        container.params.iter().find(|(k, _)| k == "caption")
+                    {
+                        content = content.append(state.arena.hardline()).append(command(
+                            state.arena,
+                            "caption",
+                            state.arena.text(escape_latex(caption)),
+                        ));
+                    }
+                    environment(state.arena, "figure", "[htbp]", content)
+                } else {
+                    container.blocks.to_doc(state)
+                }
+            }
+
+            Block::Extension(extension) => extension.children.to_doc(state),
+
+            Block::MacroBlock(_) | Block::FrontMatter { .. } => state.arena.nil(),
+        }
+    }
+}
+
+impl<'a> ToDoc<'a> for List {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        if self.items.is_empty() {
+            return state.arena.nil();
+        }
+
+        let (name, start) = match &self.kind {
+            ListKind::Ordered(options) => ("enumerate", Some(options.start)),
+            ListKind::Bullet(_) => ("itemize", None),
+        };
+
+        let depth = state.enumerate_depth.get();
+        let mut content = state.arena.nil();
+        if let Some(start) = start {
+            state.enumerate_depth.set(depth + 1);
+            if start != 1 {
+                // LaTeX names the counters of nested enumerations enumi…enumiv
+                let counter = ["enumi", "enumii", "enumiii", "enumiv"]
+                    .get(depth)
+                    .copied()
+                    .unwrap_or("enumiv");
+                content = content
+                    .append(state.arena.text(format!(
+                        r"\setcounter{{{counter}}}{{{}}}",
+                        start.saturating_sub(1)
+                    )))
+                    .append(state.arena.hardline());
+            }
+        }
+
+        content = content.append(state.arena.intersperse(
+            self.items.iter().map(|item| list_item_to_doc(item, state)),
+            state.arena.hardline(),
+        ));
+        state.enumerate_depth.set(depth);
+
+        environment(state.arena, name, "", content)
+    }
+}
+
+fn list_item_to_doc<'a>(item: &ListItem, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+    let marker = match item.task {
+        Some(TaskState::Complete) => r"\item[$\boxtimes$]",
+        Some(TaskState::Incomplete) => r"\item[$\square$]",
+        None => r"\item",
+    };
+    let blocks: Vec<&Block> = item.blocks.iter().collect();
+    if blocks.iter().all(|block| renders_nothing(block)) {
+        return state.arena.text(marker);
+    }
+    state
+        .arena
+        .text(marker)
+        .append(state.arena.text(" "))
+        .append(blocks.to_doc(state))
+}
+
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        if self.items.is_empty() {
+            return state.arena.nil();
+        }
+
+        let items = self.items.iter().map(|item| {
+            // The braces keep a `]` in the term from ending the optional argument
+            let marker = state
+                .arena
+                .text(r"\item[{")
+                .append(item.term.to_doc(state))
+                .append(state.arena.text("}]"));
+            // Several definitions of one term are set as consecutive paragraphs
+            let blocks: Vec<&Block> = item.definitions.iter().flatten().collect();
+            if blocks.iter().all(|block| renders_nothing(block)) {
+                return marker;
+            }
+            marker
+                .append(state.arena.text(" "))
+                .append(blocks.to_doc(state))
+        });
+
+        environment(
+            state.arena,
+            "description",
+            "",
+            state.arena.intersperse(items, state.arena.hardline()),
+        )
+    }
+}
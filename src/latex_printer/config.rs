@@ -0,0 +1,123 @@
+//! Configuration for LaTeX rendering
+//!
+//! This module provides configuration options to customize the LaTeX output
+//! style and format.
+
+/// Package used to typeset fenced and indented code blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeBlockStyle {
+    /// `\begin{lstlisting}[language=...]` from the `listings` package
+    Listings,
+
+    /// `\begin{minted}{...}` from the `minted` package
+    Minted,
+}
+
+/// Environment used to typeset tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStyle {
+    /// The standard `tabular` environment
+    Tabular,
+
+    /// `longtable` from the `longtable` package, which can break across pages
+    Longtable,
+}
+
+/// Configuration for LaTeX rendering
+///
+/// This struct controls various aspects of how the Markdown AST is converted
+/// to LaTeX. Use the builder methods to customize the output style.
+///
+/// The generated LaTeX is a document body. Depending on the content it relies
+/// on `hyperref`, `graphicx`, `ulem`, `soul`, `amssymb`, `multirow` and either
+/// `listings` or `minted` (and `longtable` if selected).
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::latex_printer::config::*;
+///
+/// // Default configuration
+/// let config = Config::default();
+///
+/// // Custom configuration
+/// let config = Config::default()
+///     .with_width(120)
+///     .with_code_block_style(CodeBlockStyle::Minted)
+///     .with_table_style(TableStyle::Longtable);
+/// ```
+pub struct Config {
+    pub(crate) width: usize,
+    pub(crate) code_block_style: CodeBlockStyle,
+    pub(crate) table_style: TableStyle,
+}
+
+impl Default for Config {
+    /// Create a default configuration
+    ///
+    /// Default settings:
+    /// - Width: 80 characters
+    /// - Code blocks: `listings`
+    /// - Tables: `tabular`
+    fn default() -> Self {
+        Self {
+            width: 80,
+            code_block_style: CodeBlockStyle::Listings,
+            table_style: TableStyle::Tabular,
+        }
+    }
+}
+
+impl Config {
+    /// Set the line width for pretty-printing
+    ///
+    /// Controls how the pretty-printer wraps long lines. This affects the
+    /// formatting of the generated LaTeX, not the typeset document.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Maximum line width in characters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::latex_printer::config::Config;
+    ///
+    /// let config = Config::default().with_width(120);
+    /// ```
+    pub fn with_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Set the package used for code blocks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::latex_printer::config::{CodeBlockStyle, Config};
+    ///
+    /// let config = Config::default().with_code_block_style(CodeBlockStyle::Minted);
+    /// ```
+    pub fn with_code_block_style(self, code_block_style: CodeBlockStyle) -> Self {
+        Self {
+            code_block_style,
+            ..self
+        }
+    }
+
+    /// Set the environment used for tables
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::latex_printer::config::{Config, TableStyle};
+    ///
+    /// let config = Config::default().with_table_style(TableStyle::Longtable);
+    /// ```
+    pub fn with_table_style(self, table_style: TableStyle) -> Self {
+        Self {
+            table_style,
+            ..self
+        }
+    }
+}
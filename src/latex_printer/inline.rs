@@ -0,0 +1,207 @@
+use crate::ast::*;
+use crate::latex_printer::util::{command, escape_latex, escape_url};
+use crate::latex_printer::{State, ToDoc};
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+impl<'a> ToDoc<'a> for Vec<Inline> {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        state
+            .arena
+            .concat(self.iter().map(|inline| inline.to_doc(state)))
+    }
+}
+
+/// Render escaped text, allowing line breaks between words.
+fn words<'a>(arena: &'a Arena<'a>, text: &str) -> DocBuilder<'a, Arena<'a>, ()> {
+    arena.intersperse(
+        text.split([' ', '\n'])
+            .map(|word| arena.text(escape_latex(word))),
+        arena.softline(),
+    )
+}
+
+/// Build `\href{url}{text}`.
+fn href<'a>(
+    state: &'a State<'a>,
+    destination: &str,
+    children: DocBuilder<'a, Arena<'a>, ()>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    state
+        .arena
+        .text(format!(r"\href{{{}}}{{", escape_url(destination)))
+        .append(children)
+        .append(state.arena.text("}"))
+}
+
+/// Convert an image dimension to a LaTeX length, if possible.
+///
+/// Percentages are taken relative to `\linewidth`, TeX units are passed
+/// through and anything else is ignored.
+fn latex_length(value: &str) -> Option<String> {
+    let value = value.trim();
+    if let Some(percent) = value.strip_suffix('%') {
+        let percent: f64 = percent.trim().parse().ok()?;
+        return Some(format!(r"{}\linewidth", percent / 100.0));
+    }
+    let units = ["pt", "mm", "cm", "in", "em", "ex", "px"];
+    units.iter().find_map(|unit| {
+        let number = value.strip_suffix(unit)?;
+        number.trim().parse::<f64>().ok()?;
+        Some(value.to_string())
+    })
+}
+
+impl<'a> ToDoc<'a> for Inline {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        match self {
+            Inline::Text(text) => words(state.arena, text),
+
+            Inline::LineBreak => state.arena.text(r"\\").append(state.arena.hardline()),
+
+            Inline::Code(code) => {
+                command(state.arena, "texttt", state.arena.text(escape_latex(code)))
+            }
+
+            Inline::Html(_) => state.arena.nil(),
+
+            Inline::Link(link) => href(state, &link.destination, link.children.to_doc(state)),
+
+            Inline::LinkReference(link_ref) => {
+                if let Some(definition) = state.get_link_definition(&link_ref.label) {
+                    if link_ref.image {
+                        return Inline::Image(Image {
+                            destination: definition.destination.clone(),
+                            title: definition.title.clone(),
+                            alt: plain_text(&link_ref.text),
+                            attributes: Attributes::default(),
+                        })
+                        .to_doc(state);
+                    }
+                    href(state, &definition.destination, link_ref.text.to_doc(state))
+                } else if link_ref.image {
+                    state.arena.text("!").append(link_ref.text.to_doc(state))
+                } else {
+                    link_ref.text.to_doc(state)
+                }
+            }
+
+            Inline::Image(image) => {
+                let mut options = Vec::new();
+                if let Some(width) = image.attributes.get("width").and_then(latex_length) {
+                    options.push(format!("width={width}"));
+                }
+                if let Some(height) = image.attributes.get("height").and_then(latex_length) {
+                    options.push(format!("height={height}"));
+                }
+                let options = if options.is_empty() {
+                    String::new()
+                } else {
+                    format!("[{}]", options.join(","))
+                };
+                state.arena.text(format!(
+                    r"\includegraphics{}{{{}}}",
+                    options,
+                    escape_url(&image.destination)
+                ))
+            }
+
+            Inline::Emphasis(content) => command(state.arena, "emph", content.to_doc(state)),
+
+            Inline::Strong(content) => command(state.arena, "textbf", content.to_doc(state)),
+
+            Inline::Strikethrough(content) => command(state.arena, "sout", content.to_doc(state)),
+
+            Inline::Superscript(content) => {
+                command(state.arena, "textsuperscript", content.to_doc(state))
+            }
+
+            Inline::Subscript(content) => {
+                command(state.arena, "textsubscript", content.to_doc(state))
+            }
+
+            Inline::Highlight(content) => command(state.arena, "hl", content.to_doc(state)),
+
+            Inline::Insert(content) => command(state.arena, "uline", content.to_doc(state)),
+
+            Inline::WikiLink(wiki_link) => href(
+                state,
+                &wiki_link.destination(),
+                state.arena.text(escape_latex(&wiki_link.text())),
+            ),
+
+            Inline::Span(span) => span.children.to_doc(state),
+
+            Inline::GitHubReference(reference) => {
+                let text = words(state.arena, &reference.text());
+                match &reference.destination {
+                    Some(destination) => href(state, destination, text),
+                    None => text,
+                }
+            }
+
+            Inline::Citation(citation) => {
+                if citation
+                    .items
+                    .iter()
+                    .all(|item| item.prefix.is_none() && item.locator.is_none())
+                {
+                    let keys: Vec<_> = citation.keys().collect();
+                    return state.arena.text(format!("\\cite{{{}}}", keys.join(",")));
+                }
+                state.arena.intersperse(
+                    citation.items.iter().map(|item| {
+                        let prefix = match &item.prefix {
+                            Some(prefix) => words(state.arena, prefix).append(state.arena.space()),
+                            None => state.arena.nil(),
+                        };
+                        prefix.append(state.arena.text(match &item.locator {
+                            Some(locator) => {
+                                format!("\\cite[{}]{{{}}}", escape_latex(locator), item.key)
+                            }
+                            None => format!("\\cite{{{}}}", item.key),
+                        }))
+                    }),
+                    state.arena.text(";").append(state.arena.softline()),
+                )
+            }
+
+            Inline::Emoji { glyph, .. } => words(state.arena, glyph),
+
+            Inline::Abbreviation { text, .. } => words(state.arena, text),
+
+            Inline::Extension(extension) => extension.children.to_doc(state),
+
+            Inline::Autolink { url, .. } => {
+                if url.contains('@') && !url.contains(':') {
+                    state.arena.text(format!(
+                        r"\href{{mailto:{}}}{{\nolinkurl{{{}}}}}",
+                        escape_url(url),
+                        escape_url(url)
+                    ))
+                } else if url.starts_with("www.") {
+                    state.arena.text(format!(
+                        r"\href{{http://{}}}{{\nolinkurl{{{}}}}}",
+                        escape_url(url),
+                        escape_url(url)
+                    ))
+                } else {
+                    state.arena.text(format!(r"\url{{{}}}", escape_url(url)))
+                }
+            }
+
+            Inline::FootnoteReference(label) => {
+                if let Some(def) = state.get_footnote_definition(label) {
+                    command(state.arena, "footnote", def.blocks.to_doc(state))
+                } else {
+                    state
+                        .arena
+                        .text(format!("[{}]", escape_latex(&format!("^{label}"))))
+                }
+            }
+
+            Inline::Empty => state.arena.nil(),
+
+            Inline::Latex { content: latex, .. } => state.arena.text(format!("${latex}$")),
+        }
+    }
+}
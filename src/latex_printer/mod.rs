@@ -0,0 +1,253 @@
+//! LaTeX printer for Markdown AST
+//!
+//! This module provides functionality to render a Markdown Abstract Syntax Tree (AST)
+//! into LaTeX. The output is a document body that can be included into any
+//! document whose preamble loads the required packages.
+//!
+//! # Features
+//!
+//! - **Full AST coverage**: All block and inline elements from CommonMark + GFM
+//! - **Proper LaTeX escaping**: All special characters are properly escaped
+//! - **Sectioning**: Headings map to `\section` … `\subparagraph`
+//! - **Tables**: `tabular` or `longtable`, with `\multicolumn`/`\multirow`
+//!   for merged cells
+//! - **Code blocks**: `listings` or `minted`
+//! - **Math passthrough**: LaTeX formulas are emitted verbatim
+//! - **Width control**: Configurable line width for pretty-printing
+//!
+//! # Basic Usage
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::latex_printer::{render_latex, config::Config};
+//!
+//! let doc = Document {
+//!     blocks: vec![
+//!         Block::Heading(Heading {
+//!             kind: HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
+//!             content: vec![Inline::Text("Hello LaTeX".to_string())],
+//!         }),
+//!         Block::Paragraph(vec![
+//!             Inline::Text("This is ".to_string()),
+//!             Inline::Strong(vec![Inline::Text("bold".to_string())]),
+//!             Inline::Text(" text.".to_string()),
+//!         ]),
+//!     ],
+//! };
+//!
+//! let latex = render_latex(&doc, Config::default());
+//! assert_eq!(latex, "\\section{Hello LaTeX}\n\nThis is \\textbf{bold} text.");
+//! ```
+//!
+//! # LaTeX Element Mappings
+//!
+//! | Markdown          | LaTeX                                  |
+//! |-------------------|----------------------------------------|
+//! | `# Heading`       | `\section{Heading}`                    |
+//! | `## Heading`      | `\subsection{Heading}`                 |
+//! | `**bold**`        | `\textbf{bold}`                        |
+//! | `*italic*`        | `\emph{italic}`                        |
+//! | `~~strike~~`      | `\sout{strike}`                        |
+//! | `` `code` ``      | `\texttt{code}`                        |
+//! | `> quote`         | `\begin{quote}...\end{quote}`          |
+//! | `- list`          | `\begin{itemize}\item ...`             |
+//! | `1. ordered`      | `\begin{enumerate}\item ...`           |
+//! | `[link](url)`     | `\href{url}{link}`                     |
+//! | `![img](url)`     | `\includegraphics{url}`                |
+//! | `[^note]`         | `\footnote{...}`                       |
+//! | `$x$`             | `$x$`                                  |
+//! | `$$x$$`           | `\[x\]`                                |
+//! | `[@key, p. 33]`   | `\cite[p. 33]{key}`                    |
+//! | Tables            | `\begin{tabular}...` / `longtable`     |
+//! | Figures           | `figure` / `table` with `\caption`     |
+//! | Code blocks       | `lstlisting` / `minted`                |
+
+mod block;
+pub mod config;
+mod inline;
+mod table;
+pub mod util;
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::*;
+use pretty::{Arena, DocBuilder};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+/// Internal state for LaTeX rendering
+///
+/// This structure holds the rendering context including the pretty-printer arena,
+/// configuration, and pre-processed indices for footnotes and link definitions.
+pub(crate) struct State<'a> {
+    arena: &'a Arena<'a>,
+    config: &'a crate::latex_printer::config::Config,
+    /// Mapping of footnote labels to their definitions.
+    footnote_definitions: &'a HashMap<String, FootnoteDefinition>,
+    /// Mapping of link labels to their definitions.
+    link_definitions: &'a HashMap<String, LinkDefinition>,
+    /// Nesting depth of `enumerate` environments, used to pick the counter.
+    enumerate_depth: Cell<usize>,
+}
+
+impl<'a> State<'a> {
+    /// Create a new rendering state
+    pub fn new(
+        arena: &'a Arena<'a>,
+        config: &'a crate::latex_printer::config::Config,
+        footnote_definitions: &'a HashMap<String, FootnoteDefinition>,
+        link_definitions: &'a HashMap<String, LinkDefinition>,
+    ) -> Self {
+        Self {
+            arena,
+            config,
+            footnote_definitions,
+            link_definitions,
+            enumerate_depth: Cell::new(0),
+        }
+    }
+
+    /// Get the footnote definition for a label
+    ///
+    /// Returns `None` if the footnote is not defined in the document.
+    pub fn get_footnote_definition(&self, label: &str) -> Option<&FootnoteDefinition> {
+        self.footnote_definitions.get(label)
+    }
+
+    /// Get the link definition for a reference link
+    ///
+    /// Labels are matched as described in [`normalize_label`].
+    /// Returns `None` if the link reference is not defined in the document.
+    pub fn get_link_definition(&self, label: &[Inline]) -> Option<&LinkDefinition> {
+        self.link_definitions.get(&normalize_label(label))
+    }
+}
+
+/// Render the given Markdown AST to LaTeX
+///
+/// This is the main entry point for LaTeX rendering. It takes a parsed Markdown
+/// document and configuration, then produces the LaTeX body of a document.
+///
+/// # Arguments
+///
+/// * `ast` - The parsed Markdown document as an AST
+/// * `config` - Configuration for rendering (width, code block and table style)
+///
+/// # Returns
+///
+/// LaTeX source code as a string.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::latex_printer::{render_latex, config::*};
+///
+/// let doc = Document {
+///     blocks: vec![Block::CodeBlock(CodeBlock {
+///         kind: CodeBlockKind::Fenced {
+///             info: Some("rust".to_string()),
+///         },
+///         literal: "fn main() {}".to_string(),
+///         attributes: Attributes::default(),
+///     })],
+/// };
+///
+/// let latex = render_latex(
+///     &doc,
+///     Config::default().with_code_block_style(CodeBlockStyle::Minted),
+/// );
+/// assert_eq!(latex, "\\begin{minted}{rust}\nfn main() {}\n\\end{minted}");
+/// ```
+pub fn render_latex(ast: &Document, config: crate::latex_printer::config::Config) -> String {
+    let (footnote_definitions, link_definitions) = get_indices(ast);
+    let arena = Arena::new();
+    let state = State::new(&arena, &config, &footnote_definitions, &link_definitions);
+    let doc = ast.to_doc(&state);
+
+    let mut buf = Vec::new();
+    doc.render(config.width, &mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+/// Internal trait for converting AST nodes to pretty-printer documents
+///
+/// This trait is implemented by all AST node types and provides the core
+/// rendering logic for each element type.
+trait ToDoc<'a> {
+    /// Convert this AST node to a pretty-printer document
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()>;
+}
+
+impl<'a> ToDoc<'a> for Document {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        self.blocks.to_doc(state)
+    }
+}
+
+/// Extract footnote and link definition indices from the document
+///
+/// This function performs a pre-processing pass over the AST to collect
+/// footnote definitions (rendered in place as `\footnote`) and link
+/// definitions for reference link resolution.
+fn get_indices(
+    ast: &Document,
+) -> (
+    HashMap<String, FootnoteDefinition>,
+    HashMap<String, LinkDefinition>,
+) {
+    let mut footnote_definitions = HashMap::new();
+    let mut link_definitions = HashMap::new();
+
+    fn process_blocks(
+        blocks: &[Block],
+        footnote_definitions: &mut HashMap<String, FootnoteDefinition>,
+        link_definitions: &mut HashMap<String, LinkDefinition>,
+    ) {
+        for block in blocks {
+            match block {
+                Block::FootnoteDefinition(def) => {
+                    footnote_definitions.insert(def.label.clone(), def.clone());
+                }
+                Block::Definition(def) => {
+                    link_definitions
+                        .entry(normalize_label(&def.label))
+                        .or_insert_with(|| def.clone());
+                }
+                Block::List(list) => {
+                    for item in &list.items {
+                        process_blocks(&item.blocks, footnote_definitions, link_definitions);
+                    }
+                }
+                Block::DefinitionList(list) => {
+                    for definition in list.items.iter().flat_map(|item| &item.definitions) {
+                        process_blocks(definition, footnote_definitions, link_definitions);
+                    }
+                }
+                Block::BlockQuote(blocks) => {
+                    process_blocks(blocks, footnote_definitions, link_definitions);
+                }
+                Block::GitHubAlert(alert) => {
+                    process_blocks(&alert.blocks, footnote_definitions, link_definitions);
+                }
+                Block::Container(container) => {
+                    process_blocks(&container.blocks, footnote_definitions, link_definitions);
+                }
+                Block::Extension(extension) => {
+                    process_blocks(&extension.children, footnote_definitions, link_definitions);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    process_blocks(
+        &ast.blocks,
+        &mut footnote_definitions,
+        &mut link_definitions,
+    );
+
+    (footnote_definitions, link_definitions)
+}
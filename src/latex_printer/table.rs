@@ -0,0 +1,163 @@
+use crate::ast::*;
+use crate::latex_printer::config::TableStyle;
+use crate::latex_printer::{State, ToDoc};
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+/// Column specifier letter for an alignment.
+fn column_spec(alignment: &Alignment) -> &'static str {
+    match alignment {
+        Alignment::Left | Alignment::None => "l",
+        Alignment::Center => "c",
+        Alignment::Right => "r",
+    }
+}
+
+impl<'a> ToDoc<'a> for Table {
+    fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        table_to_doc(self, None, state)
+    }
+}
+
+/// Render a table, with the `\caption` of a figure when it is a `longtable`
+///
+/// A `tabular` is captioned by the `table` environment around it instead.
+pub(crate) fn table_to_doc<'a>(
+    table: &Table,
+    caption: Option<DocBuilder<'a, Arena<'a>, ()>>,
+    state: &'a State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    if table.rows.is_empty() {
+        return state.arena.nil();
+    }
+
+    let columns = table
+        .rows
+        .iter()
+        .map(|row| row.len())
+        .max()
+        .unwrap_or(0)
+        .max(table.alignments.len());
+    let spec: String = (0..columns)
+        .map(|column| column_spec(table.alignments.get(column).unwrap_or(&Alignment::None)))
+        .collect();
+
+    let mut rows = grid_rows(table, columns, state).into_iter();
+    let header = rows.next().unwrap_or_else(|| state.arena.nil());
+    let hline = || state.arena.text(r"\hline");
+
+    let mut content = match caption {
+        Some(caption) => caption
+            .append(state.arena.text(r" \\"))
+            .append(state.arena.hardline())
+            .append(hline()),
+        None => hline(),
+    };
+    content = content.append(state.arena.hardline()).append(header);
+    content = content.append(state.arena.hardline()).append(hline());
+    let name = match state.config.table_style {
+        TableStyle::Tabular => "tabular",
+        TableStyle::Longtable => {
+            content = content
+                .append(state.arena.hardline())
+                .append(state.arena.text(r"\endhead"));
+            "longtable"
+        }
+    };
+    for row in rows {
+        content = content.append(state.arena.hardline()).append(row);
+    }
+    content = content.append(state.arena.hardline()).append(hline());
+
+    state
+        .arena
+        .text(format!("\\begin{{{name}}}{{{spec}}}"))
+        .append(state.arena.hardline())
+        .append(content)
+        .append(state.arena.hardline())
+        .append(state.arena.text(format!("\\end{{{name}}}")))
+}
+
+/// Render every row as `cell & cell & ... \\`.
+///
+/// Merged cells become `\multicolumn`/`\multirow`. Positions covered by a
+/// `\multirow` from a previous row still need an (empty) cell, while
+/// positions covered by a `\multicolumn` are skipped.
+fn grid_rows<'a>(
+    table: &Table,
+    columns: usize,
+    state: &'a State<'a>,
+) -> Vec<DocBuilder<'a, Arena<'a>, ()>> {
+    // For every position covered by a cell spanning several rows, the
+    // column span of that cell.
+    let mut covered: Vec<Vec<Option<usize>>> = vec![vec![None; columns]; table.rows.len()];
+    for (row_index, row) in table.rows.iter().enumerate() {
+        for (column, cell) in row.iter().enumerate() {
+            let rowspan = cell.rowspan.unwrap_or(1);
+            if cell.removed_by_extended_table || rowspan <= 1 {
+                continue;
+            }
+            let colspan = cell.colspan.unwrap_or(1);
+            for covered_row in covered.iter_mut().skip(row_index + 1).take(rowspan - 1) {
+                if let Some(position) = covered_row.get_mut(column) {
+                    *position = Some(colspan);
+                }
+            }
+        }
+    }
+
+    table
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let mut cells = Vec::new();
+            let mut column = 0;
+            while column < columns {
+                let alignment = table.alignments.get(column).unwrap_or(&Alignment::None);
+                let cell = row.get(column);
+                match cell {
+                    Some(cell) if !cell.removed_by_extended_table => {
+                        let colspan = cell.colspan.unwrap_or(1).max(1);
+                        let rowspan = cell.rowspan.unwrap_or(1);
+                        let mut doc = cell.content.to_doc(state);
+                        if rowspan > 1 {
+                            doc = state
+                                .arena
+                                .text(format!(r"\multirow{{{rowspan}}}{{*}}{{"))
+                                .append(doc)
+                                .append(state.arena.text("}"));
+                        }
+                        if colspan > 1 {
+                            doc = state
+                                .arena
+                                .text(format!(
+                                    r"\multicolumn{{{colspan}}}{{{}}}{{",
+                                    column_spec(alignment)
+                                ))
+                                .append(doc)
+                                .append(state.arena.text("}"));
+                        }
+                        cells.push(doc);
+                        column += colspan;
+                    }
+                    _ => {
+                        let colspan = covered[row_index][column].unwrap_or(1).max(1);
+                        if colspan > 1 {
+                            cells.push(state.arena.text(format!(
+                                r"\multicolumn{{{colspan}}}{{{}}}{{}}",
+                                column_spec(alignment)
+                            )));
+                        } else {
+                            cells.push(state.arena.nil());
+                        }
+                        column += colspan;
+                    }
+                }
+            }
+            state
+                .arena
+                .intersperse(cells, state.arena.text(" & "))
+                .append(state.arena.text(r" \\"))
+        })
+        .collect()
+}
@@ -0,0 +1,340 @@
+use crate::ast::*;
+use crate::latex_printer::{config::*, render_latex};
+
+fn paragraph(text: &str) -> Block {
+    Block::Paragraph(vec![Inline::Text(text.to_string())])
+}
+
+#[test]
+fn test_code_block_listings() {
+    let doc = Document {
+        blocks: vec![
+            Block::CodeBlock(CodeBlock {
+                kind: CodeBlockKind::Fenced {
+                    info: Some("python".to_string()),
+                },
+                literal: "def f():\n    return {}".to_string(),
+
+                attributes: Attributes::default(),
+            }),
+            Block::CodeBlock(CodeBlock {
+                kind: CodeBlockKind::Indented,
+                literal: "plain\n".to_string(),
+
+                attributes: Attributes::default(),
+            }),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        concat!(
+            "\\begin{lstlisting}[language=python]\ndef f():\n    return {}\n\\end{lstlisting}\n\n",
+            "\\begin{lstlisting}\nplain\n\\end{lstlisting}"
+        )
+    );
+}
+
+#[test]
+fn test_code_block_minted() {
+    let doc = Document {
+        blocks: vec![Block::CodeBlock(CodeBlock {
+            kind: CodeBlockKind::Indented,
+            literal: "plain".to_string(),
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_latex(
+        &doc,
+        Config::default().with_code_block_style(CodeBlockStyle::Minted),
+    );
+    assert_eq!(result, "\\begin{minted}{text}\nplain\n\\end{minted}");
+}
+
+#[test]
+fn test_lists() {
+    let doc = Document {
+        blocks: vec![Block::List(List {
+            kind: ListKind::Ordered(ListOrderedKindOptions { start: 3 }),
+            items: vec![
+                ListItem {
+                    task: None,
+                    blocks: vec![
+                        paragraph("outer"),
+                        Block::List(List {
+                            kind: ListKind::Ordered(ListOrderedKindOptions { start: 2 }),
+                            items: vec![ListItem {
+                                task: None,
+                                blocks: vec![paragraph("inner")],
+                            }],
+                        }),
+                    ],
+                },
+                ListItem {
+                    task: None,
+                    blocks: vec![Block::List(List {
+                        kind: ListKind::Bullet(ListBulletKind::Dash),
+                        items: vec![
+                            ListItem {
+                                task: Some(TaskState::Complete),
+                                blocks: vec![paragraph("done")],
+                            },
+                            ListItem {
+                                task: Some(TaskState::Incomplete),
+                                blocks: vec![],
+                            },
+                        ],
+                    })],
+                },
+            ],
+        })],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        concat!(
+            "\\begin{enumerate}\n\\setcounter{enumi}{2}\n",
+            "\\item outer\n\n\\begin{enumerate}\n\\setcounter{enumii}{1}\n\\item inner\n\\end{enumerate}\n",
+            "\\item \\begin{itemize}\n\\item[$\\boxtimes$] done\n\\item[$\\square$]\n\\end{itemize}\n",
+            "\\end{enumerate}"
+        )
+    );
+}
+
+#[test]
+fn test_blockquote_alert_and_thematic_break() {
+    let doc = Document {
+        blocks: vec![
+            Block::BlockQuote(vec![paragraph("quoted")]),
+            Block::ThematicBreak,
+            Block::GitHubAlert(GitHubAlert {
+                alert_type: GitHubAlertType::Warning,
+                blocks: vec![paragraph("Careful")],
+            }),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        concat!(
+            "\\begin{quote}\nquoted\n\\end{quote}\n\n",
+            "\\begin{center}\n\\rule{0.5\\linewidth}{0.5pt}\n\\end{center}\n\n",
+            "\\begin{quote}\n\\textbf{Warning}\n\nCareful\n\\end{quote}"
+        )
+    );
+}
+
+#[test]
+fn test_figure_container() {
+    let doc = Document {
+        blocks: vec![Block::Container(Container {
+            kind: "figure".to_string(),
+            params: vec![("caption".to_string(), "A 50% figure".to_string())],
+            blocks: vec![Block::Paragraph(vec![Inline::Image(Image {
+                destination: "a.png".to_string(),
+                title: None,
+                alt: String::new(),
+                attributes: Attributes::default(),
+            })])],
+        })],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        "\\begin{figure}[htbp]\n\\centering\n\\includegraphics{a.png}\n\\caption{A 50\\% figure}\n\\end{figure}"
+    );
+}
+
+fn figure(content: Block, label: Option<&str>) -> Document {
+    Document {
+        blocks: vec![Block::Figure {
+            content: vec![content],
+            caption: vec![Inline::Text("Results".to_string())],
+            label: label.map(str::to_string),
+            caption_syntax: CaptionSyntax::Table,
+        }],
+    }
+}
+
+fn one_cell_table() -> Block {
+    Block::Table(Table {
+        rows: vec![vec![TableCell {
+            content: vec![Inline::Text("a".to_string())],
+            colspan: None,
+            rowspan: None,
+            removed_by_extended_table: false,
+        }]],
+        alignments: vec![Alignment::None],
+        attributes: Attributes::default(),
+    })
+}
+
+#[test]
+fn test_image_figure() {
+    let image = Block::Paragraph(vec![Inline::Image(Image {
+        destination: "a.png".to_string(),
+        title: None,
+        alt: "Results".to_string(),
+        attributes: Attributes::default(),
+    })]);
+
+    let result = render_latex(&figure(image, Some("fig-a")), Config::default());
+    assert_eq!(
+        result,
+        "\\begin{figure}[htbp]\n\\centering\n\\includegraphics{a.png}\n\\caption{Results}\\label{fig-a}\n\\end{figure}"
+    );
+}
+
+#[test]
+fn test_figure_label_is_sanitized() {
+    let image = Block::Paragraph(vec![Inline::Image(Image {
+        destination: "a.png".to_string(),
+        title: None,
+        alt: "Results".to_string(),
+        attributes: Attributes::default(),
+    })]);
+
+    let result = render_latex(&figure(image, Some("fig:a_1 {b}#%")), Config::default());
+    assert!(result.contains("\\label{fig:a_1--b---}"), "{result}");
+}
+
+#[test]
+fn test_table_figure() {
+    let result = render_latex(&figure(one_cell_table(), None), Config::default());
+    assert_eq!(
+        result,
+        "\\begin{table}[htbp]\n\\centering\n\\caption{Results}\n\\begin{tabular}{l}\n\\hline\na \\\\\n\\hline\n\\hline\n\\end{tabular}\n\\end{table}"
+    );
+
+    let result = render_latex(
+        &figure(one_cell_table(), Some("tbl-a")),
+        Config::default().with_table_style(TableStyle::Longtable),
+    );
+    assert_eq!(
+        result,
+        "\\begin{longtable}{l}\n\\caption{Results}\\label{tbl-a} \\\\\n\\hline\na \\\\\n\\hline\n\\endhead\n\\hline\n\\end{longtable}"
+    );
+}
+
+#[test]
+fn test_invisible_blocks_are_skipped() {
+    let doc = Document {
+        blocks: vec![
+            paragraph("first"),
+            Block::HtmlBlock("<div></div>".to_string()),
+            Block::Empty,
+            Block::MacroBlock("macro".to_string()),
+            Block::Definition(LinkDefinition {
+                label: vec![Inline::Text("x".to_string())],
+                destination: "/x".to_string(),
+                title: None,
+            }),
+            paragraph("second"),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, "first\n\nsecond");
+}
+
+#[test]
+fn test_reference_link() {
+    let label = vec![Inline::Text("ref".to_string())];
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![Inline::LinkReference(LinkReference {
+                label: label.clone(),
+                text: vec![Inline::Text("text".to_string())],
+                image: false,
+            })]),
+            Block::Definition(LinkDefinition {
+                label,
+                destination: "/url".to_string(),
+                title: None,
+            }),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, r"\href{/url}{text}");
+}
+
+#[test]
+fn test_reference_link_normalized_label() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![Inline::LinkReference(LinkReference {
+                label: vec![Inline::Text("The  Ref".to_string())],
+                text: vec![Inline::Text("text".to_string())],
+                image: false,
+            })]),
+            Block::Definition(LinkDefinition {
+                label: vec![Inline::Text("the ref".to_string())],
+                destination: "/url".to_string(),
+                title: None,
+            }),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, r"\href{/url}{text}");
+}
+
+#[test]
+fn test_citation() {
+    let item = |key: &str, locator: Option<&str>| CitationItem {
+        key: key.to_string(),
+        prefix: None,
+        locator: locator.map(str::to_string),
+        suppress_author: false,
+    };
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Citation(Citation {
+                items: vec![
+                    CitationItem {
+                        prefix: Some("see".to_string()),
+                        ..item("doe99", Some("p. 33"))
+                    },
+                    item("smith04", None),
+                ],
+                in_text: false,
+            }),
+            Inline::Text(" and ".to_string()),
+            Inline::Citation(Citation {
+                items: vec![item("doe99", None), item("smith04", None)],
+                in_text: false,
+            }),
+        ])],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        "see \\cite[p. 33]{doe99}; \\cite{smith04} and \\cite{doe99,smith04}"
+    );
+}
+
+#[test]
+fn test_definition_list_term_with_bracket() {
+    let doc = Document {
+        blocks: vec![Block::DefinitionList(DefinitionList {
+            items: vec![DefinitionListItem {
+                term: vec![Inline::Text("a[1]".to_string())],
+                definitions: vec![vec![paragraph("first")]],
+            }],
+        })],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        "\\begin{description}\n\\item[{a[1]}] first\n\\end{description}"
+    );
+}
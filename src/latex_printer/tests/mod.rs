@@ -0,0 +1,190 @@
+mod comprehensive;
+mod table;
+
+use crate::ast::*;
+use crate::latex_printer::{config::*, render_latex};
+
+#[test]
+fn test_simple_paragraph() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "Hello, world!".to_string(),
+        )])],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, "Hello, world!");
+}
+
+#[test]
+fn test_latex_escaping() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "100% of $5 & #1_a {b}".to_string(),
+        )])],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, r"100\% of \$5 \& \#1\_a \{b\}");
+}
+
+#[test]
+fn test_sectioning_commands() {
+    let doc = Document {
+        blocks: (1..=6)
+            .map(|level| {
+                Block::Heading(Heading {
+                    kind: HeadingKind::Atx(level),
+                    attributes: Attributes::default(),
+                    content: vec![Inline::Text(format!("H{level}"))],
+                })
+            })
+            .collect(),
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        concat!(
+            "\\section{H1}\n\n\\subsection{H2}\n\n\\subsubsection{H3}\n\n",
+            "\\paragraph{H4}\n\n\\subparagraph{H5}\n\n\\subparagraph{H6}"
+        )
+    );
+}
+
+#[test]
+fn test_inline_formatting() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Strong(vec![Inline::Text("bold".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Strikethrough(vec![Inline::Text("gone".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Code("a_b".to_string()),
+        ])],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        r"\textbf{bold} \emph{italic} \sout{gone} \texttt{a\_b}"
+    );
+}
+
+#[test]
+fn test_superscript_subscript_highlight_insert() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Superscript(vec![Inline::Text("2".to_string())]),
+            Inline::Subscript(vec![Inline::Text("i".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Highlight(vec![Inline::Text("key".to_string())]),
+            Inline::Text(" ".to_string()),
+            Inline::Insert(vec![Inline::Text("new".to_string())]),
+        ])],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        r"\textsuperscript{2}\textsubscript{i} \hl{key} \uline{new}"
+    );
+}
+
+#[test]
+fn test_width_wraps_text() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "one two three four five six".to_string(),
+        )])],
+    };
+
+    let result = render_latex(&doc, Config::default().with_width(10));
+    assert_eq!(result, "one two\nthree four\nfive six");
+}
+
+#[test]
+fn test_links_and_images() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Link(Link {
+                destination: "https://example.com/#top".to_string(),
+                title: None,
+                children: vec![Inline::Text("link".to_string())],
+
+                attributes: Attributes::default(),
+            }),
+            Inline::Text(" ".to_string()),
+            Inline::Autolink {
+                url: "https://example.com".to_string(),
+                bare: false,
+            },
+            Inline::Text(" ".to_string()),
+            Inline::Image(Image {
+                destination: "img.png".to_string(),
+                title: None,
+                alt: "alt".to_string(),
+                attributes: Attributes {
+                    pairs: vec![
+                        ("width".to_string(), "50%".to_string()),
+                        ("height".to_string(), "3cm".to_string()),
+                    ],
+                    ..Default::default()
+                },
+            }),
+        ])],
+    };
+
+    let result = render_latex(&doc, Config::default().with_width(200));
+    assert_eq!(
+        result,
+        r"\href{https://example.com/\#top}{link} \url{https://example.com} \includegraphics[width=0.5\linewidth,height=3cm]{img.png}"
+    );
+}
+
+#[test]
+fn test_footnote() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::Text("Text".to_string()),
+                Inline::FootnoteReference("1".to_string()),
+                Inline::FootnoteReference("missing".to_string()),
+            ]),
+            Block::FootnoteDefinition(FootnoteDefinition {
+                label: "1".to_string(),
+                blocks: vec![Block::Paragraph(vec![Inline::Text("Note.".to_string())])],
+            }),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, r"Text\footnote{Note.}[\textasciicircum{}missing]");
+}
+
+#[test]
+fn test_latex_passthrough() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::Text("Euler: ".to_string()),
+                Inline::Latex {
+                    delimiter: InlineMathDelimiter::Dollar,
+                    content: r"e^{i\pi} + 1 = 0".to_string(),
+                },
+            ]),
+            Block::LatexBlock {
+                delimiter: DisplayMathDelimiter::DoubleDollar,
+                content: r"\int_0^1 x\,dx".to_string(),
+            },
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        "Euler: $e^{i\\pi} + 1 = 0$\n\n\\[\n\\int_0^1 x\\,dx\n\\]"
+    );
+}
@@ -0,0 +1,103 @@
+use crate::ast::*;
+use crate::latex_printer::{config::*, render_latex};
+
+fn cell(text: &str) -> TableCell {
+    TableCell {
+        content: vec![Inline::Text(text.to_string())],
+        colspan: None,
+        rowspan: None,
+        removed_by_extended_table: false,
+    }
+}
+
+fn removed() -> TableCell {
+    TableCell {
+        content: vec![],
+        colspan: None,
+        rowspan: None,
+        removed_by_extended_table: true,
+    }
+}
+
+fn simple_table() -> Document {
+    Document {
+        blocks: vec![Block::Table(Table {
+            rows: vec![vec![cell("a"), cell("b")], vec![cell("1"), cell("2")]],
+            alignments: vec![Alignment::Center, Alignment::Right],
+
+            attributes: Attributes::default(),
+        })],
+    }
+}
+
+#[test]
+fn test_tabular() {
+    let result = render_latex(&simple_table(), Config::default());
+    assert_eq!(
+        result,
+        "\\begin{tabular}{cr}\n\\hline\na & b \\\\\n\\hline\n1 & 2 \\\\\n\\hline\n\\end{tabular}"
+    );
+}
+
+#[test]
+fn test_longtable() {
+    let result = render_latex(
+        &simple_table(),
+        Config::default().with_table_style(TableStyle::Longtable),
+    );
+    assert_eq!(
+        result,
+        "\\begin{longtable}{cr}\n\\hline\na & b \\\\\n\\hline\n\\endhead\n1 & 2 \\\\\n\\hline\n\\end{longtable}"
+    );
+}
+
+#[test]
+fn test_multicolumn_and_multirow() {
+    let mut wide = cell("wide");
+    wide.colspan = Some(2);
+    let mut tall = cell("tall");
+    tall.rowspan = Some(2);
+
+    let doc = Document {
+        blocks: vec![Block::Table(Table {
+            rows: vec![
+                vec![cell("h1"), cell("h2"), cell("h3")],
+                vec![wide, removed(), cell("x")],
+                vec![tall, cell("y"), cell("z")],
+                vec![removed(), cell("v"), cell("w")],
+            ],
+            alignments: vec![Alignment::Left, Alignment::Center, Alignment::None],
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert!(result.contains("\\multicolumn{2}{l}{wide} & x \\\\"));
+    assert!(result.contains("\\multirow{2}{*}{tall} & y & z \\\\"));
+    assert!(result.contains("\n & v & w \\\\"));
+}
+
+#[test]
+fn test_multirow_spanning_columns() {
+    let mut block = cell("block");
+    block.rowspan = Some(2);
+    block.colspan = Some(2);
+
+    let doc = Document {
+        blocks: vec![Block::Table(Table {
+            rows: vec![
+                vec![cell("h1"), cell("h2"), cell("h3")],
+                vec![block, removed(), cell("x")],
+                vec![removed(), removed(), cell("y")],
+            ],
+            alignments: vec![Alignment::None; 3],
+
+            attributes: Attributes::default(),
+        })],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert!(result.contains("\\multicolumn{2}{l}{\\multirow{2}{*}{block}} & x \\\\"));
+    assert!(result.contains("\\multicolumn{2}{l}{} & y \\\\"));
+}
@@ -0,0 +1,140 @@
+//! Utility functions for LaTeX rendering
+//!
+//! This module provides helper functions for LaTeX generation including
+//! character escaping and environment generation.
+
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+/// Escape LaTeX special characters in text
+///
+/// # LaTeX Special Characters
+///
+/// The following characters are escaped:
+/// - `\` → `\textbackslash{}`
+/// - `{` `}` `$` `&` `#` `%` `_` → prefixed with `\`
+/// - `^` → `\textasciicircum{}`
+/// - `~` → `\textasciitilde{}`
+///
+/// # Examples
+///
+/// ```rust
+/// # use markdown_ppp::latex_printer::util::escape_latex;
+/// assert_eq!(escape_latex("50% of $10"), r"50\% of \$10");
+/// assert_eq!(escape_latex(r"a\b"), r"a\textbackslash{}b");
+/// ```
+pub fn escape_latex(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => result.push_str(r"\textbackslash{}"),
+            '{' | '}' | '$' | '&' | '#' | '%' | '_' => {
+                result.push('\\');
+                result.push(c);
+            }
+            '^' => result.push_str(r"\textasciicircum{}"),
+            '~' => result.push_str(r"\textasciitilde{}"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Escape a URL for use in `\href` and `\url`
+///
+/// Only the characters that break the argument parsing of `hyperref` are
+/// escaped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use markdown_ppp::latex_printer::util::escape_url;
+/// assert_eq!(escape_url("https://example.com/#a%20b"), r"https://example.com/\#a\%20b");
+/// ```
+pub fn escape_url(url: &str) -> String {
+    let mut result = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            '\\' | '#' | '%' | '{' | '}' => {
+                result.push('\\');
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Make an identifier safe to use in `\label`
+///
+/// Label names are expanded by LaTeX, so every character except ASCII letters,
+/// digits, `-`, `_`, `:` and `.` is replaced with `-`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use markdown_ppp::latex_printer::util::label_id;
+/// assert_eq!(label_id("fig:results_2.1"), "fig:results_2.1");
+/// assert_eq!(label_id("a#b{c}~é"), "a-b-c---");
+/// ```
+pub fn label_id(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Create a LaTeX command with a single argument.
+/// e.g. `\name{content}`
+pub fn command<'a>(
+    arena: &'a Arena<'a>,
+    name: &str,
+    content: DocBuilder<'a, Arena<'a>, ()>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    arena
+        .text(format!("\\{name}{{"))
+        .append(content)
+        .append(arena.text("}"))
+}
+
+/// Create a LaTeX environment with content on separate lines.
+/// e.g. `\begin{name}args ... \end{name}`
+pub fn environment<'a>(
+    arena: &'a Arena<'a>,
+    name: &str,
+    args: &str,
+    content: DocBuilder<'a, Arena<'a>, ()>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    arena
+        .text(format!("\\begin{{{name}}}{args}"))
+        .append(arena.hardline())
+        .append(content)
+        .append(arena.hardline())
+        .append(arena.text(format!("\\end{{{name}}}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_latex() {
+        assert_eq!(escape_latex("hello"), "hello");
+        assert_eq!(escape_latex("a_b & c#d"), r"a\_b \& c\#d");
+        assert_eq!(escape_latex("{x}"), r"\{x\}");
+        assert_eq!(
+            escape_latex("x^2~y"),
+            r"x\textasciicircum{}2\textasciitilde{}y"
+        );
+    }
+
+    #[test]
+    fn test_escape_url() {
+        assert_eq!(escape_url("https://example.com"), "https://example.com");
+        assert_eq!(escape_url("a_b~c"), "a_b~c");
+    }
+}
@@ -10,14 +10,15 @@
 /// The AST module provides a generic AST structure. See [`ast::generic`] for more details.
 pub mod ast;
 
-/// Specialized AST types for element identification.
+/// Specialized AST types for element identification and source spans.
 ///
 /// This module provides pre-defined specialized versions of the generic AST
-/// for element identification scenarios.
+/// for element identification and source location scenarios.
 ///
 /// # Available modules
 ///
 /// - `element_id` - Element ID support and related functionality
+/// - `span` - Source positions of elements
 /// - `type_aliases` - Convenient type aliases for specialized AST types
 /// - `utilities` - Helper functions and utilities
 #[cfg(feature = "ast-specialized")]
@@ -37,12 +38,29 @@ pub mod printer;
 
 /// HTML renderer for converting Markdown AST to HTML.
 ///
+/// Render AST to HTML using [`render_html`](html_printer::render_html).
+#[cfg(feature = "html-printer")]
+pub mod html_printer;
+
+/// LaTeX renderer for converting Markdown AST to LaTeX.
+///
+/// Render AST to LaTeX using [`render_latex`](latex_printer::render_latex).
+#[cfg(feature = "latex-printer")]
+pub mod latex_printer;
+
 /// Typst renderer for converting Markdown AST to Typst.
 ///
 /// Render AST to Typst using [`render_typst`](typst_printer::render_typst).
 #[cfg(feature = "typst-printer")]
 pub mod typst_printer;
 
+#[cfg(any(
+    feature = "html-printer",
+    feature = "latex-printer",
+    feature = "typst-printer"
+))]
+mod render_util;
+
 /// AST transformation utilities for manipulating parsed Markdown.
 #[cfg(feature = "ast-transform")]
 pub mod ast_transform;
@@ -0,0 +1,91 @@
+use crate::ast::Attributes;
+use nom::{
+    branch::alt,
+    bytes::complete::{take_until, take_while1},
+    character::complete::{char, multispace0, multispace1},
+    combinator::map,
+    multi::separated_list0,
+    sequence::{delimited, preceded, separated_pair},
+    IResult, Parser,
+};
+
+enum Attribute<'a> {
+    Id(&'a str),
+    Class(&'a str),
+    Pair(&'a str, &'a str),
+}
+
+fn identifier(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')).parse(input)
+}
+
+fn name(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | ':')).parse(input)
+}
+
+fn value(input: &str) -> IResult<&str, &str> {
+    alt((
+        delimited(char('"'), take_until("\""), char('"')),
+        delimited(char('\''), take_until("'"), char('\'')),
+        take_while1(|c: char| !c.is_whitespace() && !matches!(c, '{' | '}' | '"' | '\'')),
+    ))
+    .parse(input)
+}
+
+fn attribute(input: &str) -> IResult<&str, Attribute<'_>> {
+    alt((
+        map(preceded(char('#'), identifier), Attribute::Id),
+        map(preceded(char('.'), name), Attribute::Class),
+        map(separated_pair(name, char('='), value), |(key, value)| {
+            Attribute::Pair(key, value)
+        }),
+    ))
+    .parse(input)
+}
+
+/// Parse an attribute list `{#id .class key=value key="quoted value"}`
+///
+/// The last id wins; classes and pairs are kept in the order they are written.
+pub(crate) fn attributes(input: &str) -> IResult<&str, Attributes> {
+    map(
+        delimited(
+            char('{'),
+            delimited(
+                multispace0,
+                separated_list0(multispace1, attribute),
+                multispace0,
+            ),
+            char('}'),
+        ),
+        |list| {
+            let mut attributes = Attributes::default();
+            for attribute in list {
+                match attribute {
+                    Attribute::Id(id) => attributes.id = Some(id.to_owned()),
+                    Attribute::Class(class) => attributes.classes.push(class.to_owned()),
+                    Attribute::Pair(key, value) => {
+                        attributes.pairs.push((key.to_owned(), value.to_owned()))
+                    }
+                }
+            }
+            attributes
+        },
+    )
+    .parse(input)
+}
+
+/// Split a trailing attribute list off `text`, returning the text before it
+/// and the attributes
+///
+/// An empty list (`{}`) is left in the text.
+pub(crate) fn trailing_attributes(text: &str) -> Option<(&str, Attributes)> {
+    let text = text.trim_end();
+    if !text.ends_with('}') {
+        return None;
+    }
+    let start = text.rfind('{')?;
+    match attributes(&text[start..]) {
+        Ok(("", attributes)) if !attributes.is_empty() => Some((&text[..start], attributes)),
+        _ => None,
+    }
+}
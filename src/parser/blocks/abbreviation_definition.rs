@@ -0,0 +1,37 @@
+use crate::ast::Block;
+use crate::parser::util::{line_terminated, not_eof_or_eol0};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, space0},
+    combinator::verify,
+    multi::many_m_n,
+    sequence::{delimited, preceded},
+    IResult, Parser,
+};
+
+/// PHP Markdown Extra abbreviation definition: `*[HTML]: HyperText Markup Language`
+///
+/// The definition takes a single line; the expansion may be empty.
+pub(crate) fn abbreviation_definition(input: &str) -> IResult<&str, Block> {
+    let (input, abbreviation) = preceded(
+        many_m_n(0, 3, char(' ')),
+        delimited(
+            tag("*["),
+            verify(
+                take_while1(|c: char| !matches!(c, ']' | '\n' | '\r')),
+                |abbreviation: &str| !abbreviation.trim().is_empty(),
+            ),
+            tag("]:"),
+        ),
+    )
+    .parse(input)?;
+    let (input, expansion) = line_terminated(preceded(space0, not_eof_or_eol0)).parse(input)?;
+
+    Ok((
+        input,
+        Block::AbbreviationDefinition {
+            abbreviation: abbreviation.trim().to_owned(),
+            expansion: expansion.trim_end().to_owned(),
+        },
+    ))
+}
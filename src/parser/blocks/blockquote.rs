@@ -1,4 +1,5 @@
 use crate::ast::Block;
+use crate::parser::source_map::SourceText;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -20,11 +21,11 @@ pub(crate) fn blockquote<'a>(
 
         let (input, lines) =
             many1(preceded(prefix, line_terminated(not_eof_or_eol0))).parse(input)?;
-        let inner = lines.join("\n");
+        let inner = SourceText::join(&state, &lines, "\n");
 
-        let nested_state = Rc::new(state.nested());
+        let nested_state = Rc::new(state.nested_for(&inner));
         let (_, inner) = many1(crate::parser::blocks::block(nested_state))
-            .parse(&inner)
+            .parse(inner.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         let inner = inner.into_iter().flatten().collect();
@@ -1,4 +1,5 @@
-use crate::ast::{CodeBlock, CodeBlockKind};
+use crate::ast::{Attributes, CodeBlock, CodeBlockKind};
+use crate::parser::attributes::trailing_attributes;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -39,6 +40,7 @@ pub(crate) fn code_block_indented<'a>(
         let code_block = CodeBlock {
             kind: CodeBlockKind::Indented,
             literal,
+            attributes: Attributes::default(),
         };
 
         Ok((input, code_block))
@@ -85,12 +87,24 @@ pub(crate) fn code_block_fenced<'a>(
         .parse(input)?;
         let (input, _) = ending_fence().parse(input)?;
 
+        // An attribute list ends the info string: ```rust {#main .numbered}
+        let (info, attributes) = match info.filter(|_| state.config.allow_attributes) {
+            Some(info) => match trailing_attributes(info) {
+                Some((info, attributes)) => {
+                    (Some(info.trim()).filter(|s| !s.is_empty()), attributes)
+                }
+                None => (Some(info), Attributes::default()),
+            },
+            None => (info, Attributes::default()),
+        };
+
         let literal = lines.join("\n");
         let code_block = CodeBlock {
             kind: CodeBlockKind::Fenced {
                 info: info.map(|v| v.to_owned()),
             },
             literal,
+            attributes,
         };
 
         Ok((input, code_block))
@@ -68,7 +68,16 @@ pub(crate) fn container<'a>(
         let (remainder, _) = space0(remainder)?;
 
         let (remainder, params) = if remainder.starts_with('{') {
-            parse_container_params(remainder)?
+            parse_container_params(remainder).inspect_err(|err| {
+                state.record_failure(err, "container block", |e| {
+                    match e.input.strip_prefix('"') {
+                        Some(rest) if rest.starts_with('"') => "empty quoted value",
+                        Some(_) => "quoted value without a closing `\"`",
+                        None => "expected a value after `=`",
+                    }
+                    .to_string()
+                })
+            })?
         } else {
             (remainder, vec![])
         };
@@ -86,12 +95,15 @@ pub(crate) fn container<'a>(
         nested_state.containers.push(kind_trimmed.to_string());
         let nested_state_rc = Rc::new(nested_state);
 
+        let content_start = input;
         let (input, (chars, _)) =
             many_till(anychar, preceded(many_m_n(0, 3, char(' ')), tag(":::"))).parse(input)?;
 
-        let inner_content: String = chars.into_iter().collect();
+        // The content is the text before the closing fence
+        let inner_len = chars.iter().map(|c| c.len_utf8()).sum();
+        let inner_content = &content_start[..inner_len];
         let (_, blocks) = many0(crate::parser::blocks::block(nested_state_rc))
-            .parse(&inner_content)
+            .parse(inner_content)
             .map_err(|err| err.map_input(|_| input))?;
 
         let container = Container {
@@ -0,0 +1,139 @@
+use crate::ast::{Block, DefinitionList, DefinitionListItem};
+use crate::parser::source_map::{SourceText, DEFINITION_LIST_ITEM};
+use crate::parser::util::*;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    character::complete::{char, space0},
+    combinator::{map, opt, recognize, verify},
+    multi::{many0, many1, many_m_n},
+    sequence::preceded,
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// Parse the marker of a definition: up to 3 spaces, `:` and 1 to 4 spaces.
+///
+/// Returns the length of the marker, which is the indentation of the
+/// continuation lines of the definition.
+fn definition_marker(input: &str) -> IResult<&str, usize> {
+    let (remaining, _) = (
+        many_m_n(0, 3, char(' ')),
+        char(':'),
+        many_m_n(1, 4, char(' ')),
+    )
+        .parse(input)?;
+
+    Ok((remaining, input.len() - remaining.len()))
+}
+
+fn blank_lines(input: &str) -> IResult<&str, &str> {
+    recognize(many1(line_terminated(space0))).parse(input)
+}
+
+fn definition_rest_line(prefix_length: usize) -> impl FnMut(&str) -> IResult<&str, Vec<&str>> {
+    move |input: &str| {
+        // Stop parsing lines on EOF
+        if input.is_empty() {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Eof,
+            )));
+        }
+
+        line_terminated(alt((
+            // Continuation lines are indented by the length of the marker
+            preceded(
+                many_m_n(prefix_length, prefix_length, char(' ')),
+                map(not_eof_or_eol1, |v| vec![v]),
+            ),
+            // If this is empty line, followed by an indented line
+            map(
+                (
+                    blank_lines,
+                    preceded(
+                        many_m_n(prefix_length, prefix_length, char(' ')),
+                        not_eof_or_eol1,
+                    ),
+                ),
+                |(newlines, content)| vec![newlines, content],
+            ),
+        )))
+        .parse(input)
+    }
+}
+
+/// Parse a definition: a marker followed by blocks
+fn definition(state: Rc<MarkdownParserState>) -> impl FnMut(&str) -> IResult<&str, Vec<Block>> {
+    move |input: &str| {
+        let (input, prefix_length) = definition_marker(input)?;
+        let (input, first_line) = line_terminated(not_eof_or_eol1).parse(input)?;
+        let (input, rest_lines) = many0(definition_rest_line(prefix_length)).parse(input)?;
+
+        let mut definition_content = SourceText::new(&state);
+        definition_content.push_slice(first_line);
+        for line in rest_lines {
+            definition_content.push_str("\n");
+            for subline in line {
+                definition_content.push_slice(subline)
+            }
+        }
+
+        let nested_state = Rc::new(state.nested_for(&definition_content));
+        let (_, blocks) = many0(crate::parser::blocks::block(nested_state))
+            .parse(definition_content.as_str())
+            .map_err(|err| err.map_input(|_| input))?;
+
+        Ok((input, blocks.into_iter().flatten().collect()))
+    }
+}
+
+/// Parse a term on a single line, followed by one or more definitions
+fn definition_list_item(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&str) -> IResult<&str, DefinitionListItem> {
+    move |input: &str| {
+        let item_start = input;
+        let (input, term) = line_terminated(preceded(
+            many_m_n(0, 3, char(' ')),
+            verify(not_eof_or_eol1, |line: &str| {
+                !line.trim().is_empty() && definition_marker(line).is_err()
+            }),
+        ))
+        .parse(input)?;
+        let (input, definitions) =
+            many1(preceded(opt(blank_lines), definition(state.clone()))).parse(input)?;
+
+        let (_, term) =
+            crate::parser::inline::inline_many0(state.clone()).parse(term.trim_end())?;
+
+        let consumed = &item_start[..item_start.len() - input.len()];
+        let item_end = &item_start[consumed.trim_end_matches(['\n', '\r']).len()..];
+        state.record_span(DEFINITION_LIST_ITEM, item_start, item_end);
+
+        Ok((input, DefinitionListItem { term, definitions }))
+    }
+}
+
+/// Parse definition lists in format:
+///      Term
+///      : Definition
+pub(crate) fn definition_list(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&str) -> IResult<&str, DefinitionList> {
+    move |input: &str| {
+        let (input, (first, rest)) = (
+            definition_list_item(state.clone()),
+            many0(preceded(
+                opt(blank_lines),
+                definition_list_item(state.clone()),
+            )),
+        )
+            .parse(input)?;
+
+        let mut items = vec![first];
+        items.extend(rest);
+
+        Ok((input, DefinitionList { items }))
+    }
+}
@@ -0,0 +1,142 @@
+use super::record_spans;
+use crate::ast::{Block, CaptionSyntax, Inline};
+use crate::parser::config::ElementBehavior;
+use crate::parser::util::*;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, space0, space1},
+    combinator::{opt, value},
+    multi::many_m_n,
+    sequence::{pair, preceded, terminated},
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// A standalone image, or a table with a `Table: caption` or `: caption` line
+pub(crate) fn figure<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Block> {
+    move |input: &'a str| {
+        alt((table_figure(state.clone()), image_figure(state.clone()))).parse(input)
+    }
+}
+
+/// A table with a caption line before or after it, as in Pandoc
+///
+/// A blank line may separate the caption from the table.
+fn table_figure<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Block> {
+    move |input: &'a str| {
+        if matches!(state.config.block_table_behavior, ElementBehavior::Ignore) {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Fail,
+            )));
+        }
+        let table = || crate::parser::blocks::table::table(state.clone());
+        let (rest, table_start, table_end, mut table, (caption_syntax, caption)) =
+            match terminated(caption_line, opt(blank_line)).parse(input) {
+                Ok((table_start, caption)) => {
+                    let (rest, table) = table().parse(table_start)?;
+                    (rest, table_start, rest, table, caption)
+                }
+                Err(_) => {
+                    let (table_end, table) = table().parse(input)?;
+                    let (rest, caption) =
+                        preceded(opt(blank_line), caption_line).parse(table_end)?;
+                    (rest, input, table_end, table, caption)
+                }
+            };
+        let (_, caption) =
+            crate::parser::inline::inline_many1(state.clone()).parse(caption.trim_end())?;
+
+        let label = table.attributes.id.take();
+        let content = vec![Block::Table(table)];
+        if state.tracks_spans() {
+            record_spans(&state, &content, table_start, table_end);
+        }
+
+        Ok((
+            rest,
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+            },
+        ))
+    }
+}
+
+/// `Table: caption` or `: caption`
+fn caption_line(input: &str) -> IResult<&str, (CaptionSyntax, &str)> {
+    line_terminated(preceded(
+        many_m_n(0, 3, char(' ')),
+        pair(
+            alt((
+                value(CaptionSyntax::Table, terminated(tag("Table:"), space0)),
+                value(CaptionSyntax::Colon, terminated(char(':'), space1)),
+            )),
+            not_eof_or_eol1,
+        ),
+    ))
+    .parse(input)
+}
+
+fn blank_line(input: &str) -> IResult<&str, &str> {
+    line_terminated(space0).parse(input)
+}
+
+/// A paragraph holding nothing but an image, captioned by its title or alt text
+fn image_figure<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Block> {
+    move |input: &'a str| {
+        // Avoid parsing every paragraph twice
+        preceded(many_m_n(0, 3, char(' ')), tag("![")).parse(input)?;
+
+        let (rest, block) =
+            crate::parser::blocks::heading::heading_v2_or_paragraph(state.clone()).parse(input)?;
+        let Block::Paragraph(mut inlines) = block else {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Verify,
+            )));
+        };
+        let [Inline::Image(image)] = inlines.as_mut_slice() else {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Verify,
+            )));
+        };
+        let caption = match &image.title {
+            Some(title) if !title.is_empty() => title.clone(),
+            _ if !image.alt.is_empty() => image.alt.clone(),
+            _ => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                )))
+            }
+        };
+
+        let label = image.attributes.id.take();
+        let content = vec![Block::Paragraph(inlines)];
+        if state.tracks_spans() {
+            record_spans(&state, &content, input, rest);
+        }
+
+        Ok((
+            rest,
+            Block::Figure {
+                content,
+                caption: vec![Inline::Text(caption)],
+                label,
+                caption_syntax: CaptionSyntax::Image,
+            },
+        ))
+    }
+}
@@ -1,4 +1,5 @@
 use crate::ast::FootnoteDefinition;
+use crate::parser::source_map::SourceText;
 use crate::parser::util::{line_terminated, not_eof_or_eol1};
 use crate::parser::MarkdownParserState;
 use nom::character::complete::{char, none_of};
@@ -27,19 +28,18 @@ pub(crate) fn footnote_definition<'a>(
         ))
         .parse(input)?;
 
-        let total_size = first_line.len() + rest_lines.len();
-        let mut footnote_content = String::with_capacity(total_size);
+        let mut footnote_content = SourceText::new(&state);
         if !first_line.is_empty() {
-            footnote_content.push_str(first_line)
+            footnote_content.push_slice(first_line)
         }
         for line in rest_lines {
-            footnote_content.push('\n');
-            footnote_content.push_str(line)
+            footnote_content.push_str("\n");
+            footnote_content.push_slice(line)
         }
 
-        let nested_state = Rc::new(state.nested());
+        let nested_state = Rc::new(state.nested_for(&footnote_content));
         let (_, blocks) = many0(crate::parser::blocks::block(nested_state))
-            .parse(&footnote_content)
+            .parse(footnote_content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         let blocks = blocks.into_iter().flatten().collect();
@@ -0,0 +1,75 @@
+use crate::ast::{Block, FrontMatterFormat};
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::util::*;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, space0},
+    combinator::{not, recognize, value},
+    multi::many0,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+/// Front matter block: a `---` or `+++` fence on the first line of the
+/// document, the raw metadata, and the same fence on a line of its own.
+///
+/// As in Pandoc, the opening fence cannot be followed by a blank line, so
+/// that a thematic break at the start of a document stays one.
+pub(crate) fn front_matter(input: &str) -> IResult<&str, Block> {
+    let (input, format) = opening_fence(input)?;
+    let (rest, (body, _)) = (
+        recognize(many0(preceded(
+            not(fence(format)),
+            line_terminated(not_eof_or_eol0),
+        ))),
+        fence(format),
+    )
+        .parse(input)?;
+
+    let raw = body.strip_suffix('\n').unwrap_or(body);
+    let raw = raw.strip_suffix('\r').unwrap_or(raw);
+    Ok((
+        rest,
+        Block::FrontMatter {
+            format,
+            raw: raw.to_owned(),
+        },
+    ))
+}
+
+fn opening_fence(input: &str) -> IResult<&str, FrontMatterFormat> {
+    terminated(
+        alt((
+            fence(FrontMatterFormat::Yaml),
+            fence(FrontMatterFormat::Toml),
+        )),
+        not((space0, line_ending)),
+    )
+    .parse(input)
+}
+
+fn fence<'a>(
+    format: FrontMatterFormat,
+) -> impl Parser<&'a str, Output = FrontMatterFormat, Error = nom::error::Error<&'a str>> {
+    value(
+        format,
+        line_terminated(terminated(tag(format.fence()), space0)),
+    )
+}
+
+/// Length of the front matter that `config` parses at the start of `input`
+pub(crate) fn front_matter_len(config: &MarkdownParserConfig, input: &str) -> Option<usize> {
+    if matches!(config.block_front_matter_behavior, ElementBehavior::Ignore) {
+        return None;
+    }
+    let (rest, _) = front_matter(input).ok()?;
+    Some(input.len() - rest.len())
+}
+
+/// Whether `input` opens a front matter block that is not closed yet
+pub(crate) fn front_matter_pending(config: &MarkdownParserConfig, input: &str) -> bool {
+    !matches!(config.block_front_matter_behavior, ElementBehavior::Ignore)
+        && opening_fence(input).is_ok()
+        && front_matter_len(config, input).is_none()
+}
@@ -1,4 +1,5 @@
 use crate::ast::{Block, GitHubAlert, GitHubAlertType};
+use crate::parser::source_map::SourceText;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -76,16 +77,16 @@ pub(crate) fn github_alert<'a>(
         // Remove the first line (alert marker) and join the rest
         // Use slice instead of remove(0) to avoid panic on empty vec (although many1 guarantees at least one)
         let inner = if lines.len() > 1 {
-            lines[1..].join("\n")
+            SourceText::join(&state, &lines[1..], "\n")
         } else {
-            String::new()
+            SourceText::new(&state)
         };
 
         // Parse the inner content as blocks
-        let nested_state = Rc::new(state.nested());
+        let nested_state = Rc::new(state.nested_for(&inner));
         let (_, blocks) = if !inner.is_empty() {
             many1(crate::parser::blocks::block(nested_state))
-                .parse(&inner)
+                .parse(inner.as_str())
                 .map_err(|err| err.map_input(|_| input))?
         } else {
             ("", vec![])
@@ -1,4 +1,5 @@
-use crate::ast::{Block, Heading, HeadingKind, SetextHeading};
+use crate::ast::{Attributes, Block, Heading, HeadingKind, Inline, SetextHeading};
+use crate::parser::attributes::trailing_attributes;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -30,10 +31,11 @@ pub(crate) fn heading_v1<'a>(
         )
             .parse(input)?;
 
-        let (_, content) = crate::parser::inline::inline_many0(state.clone()).parse(content)?;
+        let (_, mut content) = crate::parser::inline::inline_many0(state.clone()).parse(content)?;
 
         let heading = Heading {
             kind: HeadingKind::Atx(prefix.len() as u8),
+            attributes: heading_attributes(&state, &mut content),
             content,
         };
 
@@ -55,8 +57,10 @@ pub(crate) fn heading_v2_or_paragraph<'a>(
             .parse(input)?;
 
         if let Some(level) = level {
+            let mut content = content;
             let heading = Heading {
                 kind: HeadingKind::Setext(level),
+                attributes: heading_attributes(&state, &mut content),
                 content,
             };
             return Ok((input, Block::Heading(heading)));
@@ -66,6 +70,26 @@ pub(crate) fn heading_v2_or_paragraph<'a>(
     }
 }
 
+/// Remove a trailing attribute list such as `{#id}` from the heading content,
+/// returning the attributes
+fn heading_attributes(state: &MarkdownParserState, content: &mut Vec<Inline>) -> Attributes {
+    if !state.config.allow_heading_ids && !state.config.allow_attributes {
+        return Attributes::default();
+    }
+    let Some(Inline::Text(text)) = content.last_mut() else {
+        return Attributes::default();
+    };
+    let Some((before, attributes)) = trailing_attributes(text) else {
+        return Attributes::default();
+    };
+
+    text.truncate(before.trim_end().len());
+    if text.is_empty() {
+        content.pop();
+    }
+    attributes
+}
+
 pub(crate) fn heading_v2_level<'a>(
     _state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, SetextHeading> {
@@ -1,16 +1,68 @@
 use nom::{
     bytes::complete::{tag, take_until},
-    combinator::map,
+    combinator::verify,
     sequence::delimited,
     IResult, Parser,
 };
+use std::rc::Rc;
 
-use crate::ast::Block;
+use crate::ast::{Block, CodeBlockKind, DisplayMathDelimiter};
+use crate::parser::util::has_blank_line;
+use crate::parser::MarkdownParserState;
 
+/// `$$...$$`
 pub(crate) fn latex_block(input: &str) -> IResult<&str, Block> {
-    map(
-        delimited(tag("$$"), take_until("$$"), tag("$$")),
-        |s: &str| Block::LatexBlock(s.trim().to_string()),
+    display_math(DisplayMathDelimiter::DoubleDollar).parse(input)
+}
+
+/// `\[...\]`
+pub(crate) fn latex_block_brackets(input: &str) -> IResult<&str, Block> {
+    display_math(DisplayMathDelimiter::Brackets).parse(input)
+}
+
+/// A fenced code block with the `math` info string
+pub(crate) fn latex_fence<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Block> {
+    move |input: &'a str| {
+        let (rest, code_block) =
+            crate::parser::blocks::code_block::code_block_fenced(state.clone()).parse(input)?;
+        match code_block.kind {
+            CodeBlockKind::Fenced { info: Some(info) }
+                if info == "math" && code_block.attributes.is_empty() =>
+            {
+                Ok((
+                    rest,
+                    Block::LatexBlock {
+                        delimiter: DisplayMathDelimiter::Fence,
+                        content: code_block.literal,
+                    },
+                ))
+            }
+            _ => Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Verify,
+            ))),
+        }
+    }
+}
+
+/// The formula may span several lines but not a blank one, so that an
+/// unterminated delimiter stays in its own paragraph instead of swallowing
+/// the rest of the document.
+fn display_math<'a>(
+    delimiter: DisplayMathDelimiter,
+) -> impl Parser<&'a str, Output = Block, Error = nom::error::Error<&'a str>> {
+    verify(
+        delimited(
+            tag(delimiter.open()),
+            take_until(delimiter.close()),
+            tag(delimiter.close()),
+        ),
+        |content: &str| !has_blank_line(content),
     )
-    .parse(input)
+    .map(move |content: &str| Block::LatexBlock {
+        delimiter,
+        content: content.trim().to_string(),
+    })
 }
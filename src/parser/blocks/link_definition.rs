@@ -1,6 +1,6 @@
 use super::eof_or_eol;
 use crate::ast::LinkDefinition;
-use crate::parser::link_util::{link_destination, link_label, link_title};
+use crate::parser::link_util::{link_destination, link_text, link_title};
 use crate::parser::MarkdownParserState;
 use nom::character::complete::{char, line_ending, space0, space1};
 use nom::{
@@ -31,7 +31,7 @@ pub(crate) fn link_definition<'a>(
         );
 
         let (input, label) =
-            preceded(many_m_n(0, 3, char(' ')), link_label(state.clone())).parse(input)?;
+            preceded(many_m_n(0, 3, char(' ')), link_text(state.clone())).parse(input)?;
         let (input, _) = char(':').parse(input)?;
         let (input, _) = one_line_whitespace0.parse(input)?;
         let (input, destination) = link_destination.parse(input)?;
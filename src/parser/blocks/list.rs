@@ -1,4 +1,5 @@
 use crate::ast::{ListBulletKind, ListItem, ListKind, ListOrderedKindOptions, TaskState};
+use crate::parser::source_map::{SourceText, LIST_ITEM};
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::combinator::verify;
@@ -96,22 +97,18 @@ fn list_marker_followed_by_newline(
 
 pub(crate) fn list_marker_with_span_size(
     input: &str,
-) -> IResult<&str, (ListKind, usize, Option<TaskState>, String)> {
+) -> IResult<&str, (ListKind, usize, Option<TaskState>, &str)> {
     alt((
         map(
             list_marker_followed_by_newline,
-            |(list_kind, prefix_length, task_state)| {
-                (list_kind, prefix_length, task_state, String::new())
-            },
+            |(list_kind, prefix_length, task_state)| (list_kind, prefix_length, task_state, ""),
         ),
         (map(
             (
                 list_marker_followed_by_spaces,
                 line_terminated(not_eof_or_eol0),
             ),
-            |((list_kind, prefix_length, task_state), s)| {
-                (list_kind, prefix_length, task_state, s.to_string())
-            },
+            |((list_kind, prefix_length, task_state), s)| (list_kind, prefix_length, task_state, s),
         )),
     ))
     .parse(input)
@@ -197,31 +194,35 @@ pub(crate) fn list_item(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&str) -> IResult<&str, (ListKind, ListItem)> {
     move |input: &str| {
+        let item_start = input;
         let (input, (list_kind, item_prefix_length, task_state, first_line)) =
             list_marker_with_span_size(input)?;
 
         let (input, rest_lines) =
             list_item_lines(state.clone(), list_kind.clone(), item_prefix_length).parse(input)?;
 
-        let total_size = first_line.len() + rest_lines.len();
-        let mut item_content = String::with_capacity(total_size);
+        let mut item_content = SourceText::new(&state);
         if !first_line.is_empty() {
-            item_content.push_str(&first_line)
+            item_content.push_slice(first_line)
         }
         for line in rest_lines {
-            item_content.push('\n');
+            item_content.push_str("\n");
             for subline in line {
-                item_content.push_str(subline)
+                item_content.push_slice(subline)
             }
         }
 
-        let nested_state = Rc::new(state.nested());
+        let nested_state = Rc::new(state.nested_for(&item_content));
         let (_, blocks) = many0(crate::parser::blocks::block(nested_state))
-            .parse(&item_content)
+            .parse(item_content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         let blocks = blocks.into_iter().flatten().collect();
 
+        let consumed = &item_start[..item_start.len() - input.len()];
+        let item_end = &item_start[consumed.trim_end_matches(['\n', '\r']).len()..];
+        state.record_span(LIST_ITEM, item_start, item_end);
+
         let item = ListItem {
             task: task_state,
             blocks,
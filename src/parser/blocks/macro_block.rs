@@ -1,17 +1,24 @@
 use nom::{
     bytes::complete::{tag, take_until},
     character::complete::{line_ending, space0},
-    combinator::{map, recognize},
+    combinator::{map, recognize, verify},
     sequence::{delimited, preceded, terminated},
     IResult, Parser,
 };
 
 use crate::ast::Block;
+use crate::parser::util::has_blank_line;
 
 pub(crate) fn macro_block(input: &str) -> IResult<&str, Block> {
     map(
         recognize(terminated(
-            preceded(space0, delimited(tag("{{"), take_until("}}"), tag("}}"))),
+            preceded(
+                space0,
+                verify(
+                    delimited(tag("{{"), take_until("}}"), tag("}}")),
+                    |content: &str| !has_blank_line(content),
+                ),
+            ),
             line_ending,
         )),
         |s: &str| {
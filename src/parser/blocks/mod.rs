@@ -1,7 +1,11 @@
+mod abbreviation_definition;
 mod blockquote;
 mod code_block;
 mod container;
+mod definition_list;
+mod figure;
 mod footnote_definition;
+pub(crate) mod front_matter;
 mod github_alert;
 mod heading;
 mod html_block;
@@ -17,6 +21,8 @@ mod thematic_break;
 mod tests;
 
 use crate::ast::Block;
+use crate::parser::config::{BuiltinBlockParser, ParserPriority};
+use crate::parser::source_map::block_kind;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::branch::alt;
@@ -28,114 +34,300 @@ pub(crate) fn block<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Block>> {
     move |input: &'a str| {
-        preceded(
+        let (rest, blocks) = preceded(
             many_empty_lines0,
             alt((
-                conditional_block(
-                    state.config.block_code_block_behavior.clone(),
-                    map(
-                        crate::parser::blocks::code_block::code_block(state.clone()),
-                        Block::CodeBlock,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::LatexFence,
+                    conditional_block(
+                        state.config.block_latex_fence_behavior.clone(),
+                        crate::parser::blocks::latex::latex_fence(state.clone()),
                     ),
                 ),
-                conditional_block(
-                    state.config.block_heading_v1_behavior.clone(),
-                    map(
-                        crate::parser::blocks::heading::heading_v1(state.clone()),
-                        Block::Heading,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::CodeBlock,
+                    conditional_block(
+                        state.config.block_code_block_behavior.clone(),
+                        map(
+                            crate::parser::blocks::code_block::code_block(state.clone()),
+                            Block::CodeBlock,
+                        ),
+                    ),
+                ),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::HeadingV1,
+                    conditional_block(
+                        state.config.block_heading_v1_behavior.clone(),
+                        map(
+                            crate::parser::blocks::heading::heading_v1(state.clone()),
+                            Block::Heading,
+                        ),
+                    ),
+                ),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::Container,
+                    conditional_block(
+                        state.config.block_container_behavior.clone(),
+                        crate::parser::blocks::container::container(state.clone()),
                     ),
                 ),
-                conditional_block(
-                    state.config.block_container_behavior.clone(),
-                    crate::parser::blocks::container::container(state.clone()),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::MacroBlock,
+                    conditional_block(
+                        state.config.block_macro_block_behavior.clone(),
+                        crate::parser::blocks::macro_block::macro_block,
+                    ),
                 ),
-                map(crate::parser::blocks::macro_block::macro_block, |b| vec![b]),
-                conditional_block(
-                    state.config.block_thematic_break_behavior.clone(),
-                    map(
-                        crate::parser::blocks::thematic_break::thematic_break(state.clone()),
-                        |()| Block::ThematicBreak,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::ThematicBreak,
+                    conditional_block(
+                        state.config.block_thematic_break_behavior.clone(),
+                        map(
+                            crate::parser::blocks::thematic_break::thematic_break(state.clone()),
+                            |()| Block::ThematicBreak,
+                        ),
                     ),
                 ),
                 // NOTE: It's important that the latex parser comes before the paragraph parser
-                map(crate::parser::blocks::latex::latex_block, |b| vec![b]),
-                conditional_block(
-                    state.config.block_heading_v2_behavior.clone(),
-                    crate::parser::blocks::heading::heading_v2_or_paragraph(state.clone()),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::LatexBlock,
+                    alt((
+                        conditional_block(
+                            state.config.block_latex_block_behavior.clone(),
+                            crate::parser::blocks::latex::latex_block,
+                        ),
+                        conditional_block(
+                            state.config.block_latex_brackets_behavior.clone(),
+                            crate::parser::blocks::latex::latex_block_brackets,
+                        ),
+                    )),
+                ),
+                // Must come before setext headings, which also parse paragraphs
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::DefinitionList,
+                    conditional_block(
+                        state.config.block_definition_list_behavior.clone(),
+                        map(
+                            crate::parser::blocks::definition_list::definition_list(state.clone()),
+                            Block::DefinitionList,
+                        ),
+                    ),
+                ),
+                // Must come before setext headings and tables, which produce the content
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::Figure,
+                    conditional_block(
+                        state.config.block_figure_behavior.clone(),
+                        crate::parser::blocks::figure::figure(state.clone()),
+                    ),
+                ),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::HeadingV2,
+                    conditional_block(
+                        state.config.block_heading_v2_behavior.clone(),
+                        crate::parser::blocks::heading::heading_v2_or_paragraph(state.clone()),
+                    ),
                 ),
                 // GitHub alerts should be checked before regular blockquotes
-                conditional_block_vec(
-                    state.config.block_github_alert_behavior.clone(),
-                    crate::parser::blocks::github_alert::github_alert(state.clone()),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::GitHubAlert,
+                    conditional_block_vec(
+                        state.config.block_github_alert_behavior.clone(),
+                        crate::parser::blocks::github_alert::github_alert(state.clone()),
+                    ),
                 ),
-                conditional_block(
-                    state.config.block_blockquote_behavior.clone(),
-                    map(
-                        crate::parser::blocks::blockquote::blockquote(state.clone()),
-                        Block::BlockQuote,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::Blockquote,
+                    conditional_block(
+                        state.config.block_blockquote_behavior.clone(),
+                        map(
+                            crate::parser::blocks::blockquote::blockquote(state.clone()),
+                            Block::BlockQuote,
+                        ),
                     ),
                 ),
-                conditional_block(
-                    state.config.block_list_behavior.clone(),
-                    map(
-                        crate::parser::blocks::list::list(state.clone()),
-                        Block::List,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::List,
+                    conditional_block(
+                        state.config.block_list_behavior.clone(),
+                        map(
+                            crate::parser::blocks::list::list(state.clone()),
+                            Block::List,
+                        ),
                     ),
                 ),
-                conditional_block(
-                    state.config.block_html_block_behavior.clone(),
-                    map(
-                        crate::parser::blocks::html_block::html_block(state.clone()),
-                        |s| Block::HtmlBlock(s.to_owned()),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::HtmlBlock,
+                    conditional_block(
+                        state.config.block_html_block_behavior.clone(),
+                        map(
+                            crate::parser::blocks::html_block::html_block(state.clone()),
+                            |s| Block::HtmlBlock(s.to_owned()),
+                        ),
                     ),
                 ),
                 // Alway try before link definition
-                conditional_block(
-                    state.config.block_footnote_definition_behavior.clone(),
-                    map(
-                        crate::parser::blocks::footnote_definition::footnote_definition(
-                            state.clone(),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::FootnoteDefinition,
+                    conditional_block(
+                        state.config.block_footnote_definition_behavior.clone(),
+                        map(
+                            crate::parser::blocks::footnote_definition::footnote_definition(
+                                state.clone(),
+                            ),
+                            Block::FootnoteDefinition,
+                        ),
+                    ),
+                ),
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::LinkDefinition,
+                    conditional_block(
+                        state.config.block_link_definition_behavior.clone(),
+                        map(
+                            crate::parser::blocks::link_definition::link_definition(state.clone()),
+                            Block::Definition,
                         ),
-                        Block::FootnoteDefinition,
                     ),
                 ),
-                conditional_block(
-                    state.config.block_link_definition_behavior.clone(),
-                    map(
-                        crate::parser::blocks::link_definition::link_definition(state.clone()),
-                        Block::Definition,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::AbbreviationDefinition,
+                    conditional_block(
+                        state.config.block_abbreviation_definition_behavior.clone(),
+                        crate::parser::blocks::abbreviation_definition::abbreviation_definition,
                     ),
                 ),
-                custom_parser(state.clone()),
-                conditional_block(
-                    state.config.block_table_behavior.clone(),
-                    map(
-                        crate::parser::blocks::table::table(state.clone()),
-                        Block::Table,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::Table,
+                    conditional_block(
+                        state.config.block_table_behavior.clone(),
+                        map(
+                            crate::parser::blocks::table::table(state.clone()),
+                            Block::Table,
+                        ),
                     ),
                 ),
-                conditional_block(
-                    state.config.block_paragraph_behavior.clone(),
-                    map(
-                        crate::parser::blocks::paragraph::paragraph(state.clone(), false),
-                        Block::Paragraph,
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinBlockParser::Paragraph,
+                    conditional_block(
+                        state.config.block_paragraph_behavior.clone(),
+                        map(
+                            crate::parser::blocks::paragraph::paragraph(state.clone(), false),
+                            Block::Paragraph,
+                        ),
                     ),
                 ),
             )),
         )
+        .parse(input)?;
+
+        if state.tracks_spans() {
+            // The span starts after the skipped empty lines
+            let (start, _) = many_empty_lines0(input)?;
+            record_spans(&state, &blocks, start, rest);
+        }
+
+        Ok((rest, blocks))
+    }
+}
+
+/// Front matter at the start of the document
+///
+/// Front matter is only recognized on the first line of the document, so
+/// this parser is run once before the top-level [`block`]s.
+pub(crate) fn front_matter_block<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Block>> {
+    move |input: &'a str| {
+        let (rest, blocks) = conditional_block(
+            state.config.block_front_matter_behavior.clone(),
+            crate::parser::blocks::front_matter::front_matter,
+        )
+        .parse(input)?;
+
+        if state.tracks_spans() {
+            record_spans(&state, &blocks, input, rest);
+        }
+
+        Ok((rest, blocks))
+    }
+}
+
+/// Record the text from `start` up to `rest` as the span of `blocks`
+///
+/// The span does not include the line ending of the last line.
+fn record_spans(state: &MarkdownParserState, blocks: &[Block], start: &str, rest: &str) {
+    let consumed = &start[..start.len() - rest.len()];
+    let end = &start[consumed.trim_end_matches(['\n', '\r']).len()..];
+    for block in blocks {
+        state.record_span(block_kind(block), start, end);
+    }
+}
+
+/// Try the custom parsers registered around `builtin`, and `inner` in between
+fn with_custom_parsers<'a, P>(
+    state: Rc<MarkdownParserState>,
+    builtin: BuiltinBlockParser,
+    mut inner: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Block>>
+where
+    P: Parser<&'a str, Output = Vec<Block>, Error = nom::error::Error<&'a str>>,
+{
+    move |input: &'a str| {
+        if state.config.custom_block_parsers.is_empty() {
+            return inner.parse(input);
+        }
+        alt((
+            custom_parser(state.clone(), Some(ParserPriority::Before(builtin))),
+            |i| inner.parse(i),
+            custom_parser(state.clone(), Some(ParserPriority::After(builtin))),
+        ))
         .parse(input)
     }
 }
 
+/// The custom block parsers registered at `priority`, or all of them if it is `None`,
+/// tried in registration order
 pub(crate) fn custom_parser(
     state: Rc<MarkdownParserState>,
+    priority: Option<ParserPriority<BuiltinBlockParser>>,
 ) -> impl FnMut(&str) -> IResult<&str, Vec<Block>> {
     move |input: &str| {
-        if let Some(custom_parser) = state.config.custom_block_parser.as_ref() {
-            let mut p = (**custom_parser).borrow_mut();
-            (p.as_mut())(input)
-        } else {
-            fail().parse(input)
+        let parsers = state.config.custom_block_parsers.iter();
+        for custom in parsers.filter(|p| priority.is_none_or(|priority| p.priority == priority)) {
+            // A parser that is already running higher up the stack does not match
+            let Ok(mut p) = custom.parser.try_borrow_mut() else {
+                continue;
+            };
+            match (p.as_mut())(input) {
+                Err(nom::Err::Error(_)) => continue,
+                result => {
+                    return result.inspect_err(|err| {
+                        state.record_failure(err, "custom block", |e| {
+                            format!("parser `{}` failed: {}", custom.name, e.code.description())
+                        })
+                    })
+                }
+            }
         }
+        fail().parse(input)
     }
 }
@@ -1,4 +1,5 @@
 use crate::ast::Inline;
+use crate::parser::source_map::SourceText;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -9,7 +10,6 @@ use nom::{
     sequence::preceded,
     IResult, Parser,
 };
-use std::borrow::Cow;
 use std::rc::Rc;
 
 pub(crate) fn paragraph<'a>(
@@ -38,12 +38,13 @@ pub(crate) fn paragraph<'a>(
         let (input, rest_lines) = line_terminated(paragraph_parser).parse(input)?;
         lines.extend(rest_lines);
 
-        let content = lines.join("\n");
+        let joined = SourceText::join(&state, &lines, "\n");
 
         let transformed_input =
             if let Some(inline_macro_replacer) = &state.config.inline_macro_replacer {
                 let mut replacer = inline_macro_replacer.borrow_mut();
-                let mut result = String::new();
+                let content = joined.as_str();
+                let mut result = SourceText::new(&state.for_text(&joined));
                 let mut last_pos = 0;
 
                 while let Some(start_pos) = content[last_pos..].find("{{") {
@@ -75,7 +76,7 @@ pub(crate) fn paragraph<'a>(
                     }
 
                     if let Some(absolute_end) = end_pos {
-                        result.push_str(&content[last_pos..absolute_start]);
+                        result.push_slice(&content[last_pos..absolute_start]);
                         let macro_content = &content[absolute_start + 2..absolute_end];
                         let replacement = (replacer)(macro_content.trim());
                         result.push_str(&replacement);
@@ -86,14 +87,14 @@ pub(crate) fn paragraph<'a>(
                     }
                 }
 
-                result.push_str(&content[last_pos..]);
-                Cow::Owned(result)
+                result.push_slice(&content[last_pos..]);
+                result
             } else {
-                Cow::Borrowed(content.as_str())
+                joined
             };
 
-        let (_, content) = crate::parser::inline::inline_many1(state.clone())
-            .parse(transformed_input.as_ref())
+        let (_, content) = crate::parser::inline::inline_many1(state.for_text(&transformed_input))
+            .parse(transformed_input.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         Ok((input, content))
@@ -155,6 +156,13 @@ pub(crate) fn is_paragraph_line_start<'a>(
                     crate::parser::blocks::link_definition::link_definition(state.clone()),
                 ),
             ),
+            conditional_block_unit(
+                state.config.block_abbreviation_definition_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::blocks::abbreviation_definition::abbreviation_definition,
+                ),
+            ),
             conditional_block_unit(
                 state.config.block_footnote_definition_behavior.clone(),
                 value(
@@ -168,7 +176,7 @@ pub(crate) fn is_paragraph_line_start<'a>(
             ),
             value(
                 vec![()],
-                crate::parser::blocks::custom_parser(state.clone()),
+                crate::parser::blocks::custom_parser(state.clone(), None),
             ),
             value(vec![()], line_terminated(space0)),
         ))))
@@ -1,5 +1,7 @@
 use super::{eof_or_eol, line_terminated};
-use crate::ast::{Alignment, Inline, Table, TableCell, TableRow};
+use crate::ast::{Alignment, Attributes, Inline, Table, TableCell, TableRow};
+use crate::parser::attributes::attributes;
+use crate::parser::source_map::SourceText;
 use crate::parser::MarkdownParserState;
 use nom::multi::many_m_n;
 use nom::{
@@ -30,6 +32,17 @@ pub(crate) fn table<'a>(
 
         let (input, rows) = parse_table_data_rows(state.clone(), col_count).parse(input)?;
 
+        // An attribute list on the line following the table: {#prices .wide}
+        let (input, attributes) = if state.config.allow_attributes {
+            map(
+                opt(line_terminated(delimited(space0, attributes, space0))),
+                Option::unwrap_or_default,
+            )
+            .parse(input)?
+        } else {
+            (input, Attributes::default())
+        };
+
         let mut all_rows = std::iter::once(header).chain(rows).collect::<Vec<_>>();
         process_spans(&mut all_rows);
 
@@ -38,6 +51,7 @@ pub(crate) fn table<'a>(
             Table {
                 rows: all_rows,
                 alignments,
+                attributes,
             },
         ))
     }
@@ -207,6 +221,7 @@ fn cell_content<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, TableCell> {
     move |input: &'a str| {
+        let cell_start = input;
         let (input, chars) = many1(preceded(
             not(alt((value((), eof_or_eol), value((), char('|'))))),
             alt((value('|', tag("\\|")), anychar)),
@@ -214,9 +229,13 @@ fn cell_content<'a>(
         .parse(input)?;
 
         let content = chars.iter().collect::<String>();
-        let trimmed_content = content.trim();
-        let (_, content) = crate::parser::inline::inline_many0(state.clone())
-            .parse(trimmed_content)
+        let trimmed_content = SourceText::aligned(
+            &state,
+            &cell_start[..cell_start.len() - input.len()],
+            content.trim().to_string(),
+        );
+        let (_, content) = crate::parser::inline::inline_many0(state.for_text(&trimmed_content))
+            .parse(trimmed_content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         Ok((
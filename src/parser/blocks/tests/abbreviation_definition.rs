@@ -0,0 +1,66 @@
+use crate::ast::*;
+use crate::parser::config::*;
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn parse(input: &str) -> Vec<Block> {
+    let config = MarkdownParserConfig::default()
+        .with_block_abbreviation_definition_behavior(ElementBehavior::Parse);
+    parse_markdown(MarkdownParserState::with_config(config), input)
+        .unwrap()
+        .blocks
+}
+
+fn definition(abbreviation: &str, expansion: &str) -> Block {
+    Block::AbbreviationDefinition {
+        abbreviation: abbreviation.to_owned(),
+        expansion: expansion.to_owned(),
+    }
+}
+
+#[test]
+fn abbreviation_definition_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "*[HTML]: HyperText").unwrap();
+    assert!(matches!(doc.blocks.as_slice(), [Block::Paragraph(_)]));
+}
+
+#[test]
+fn abbreviation_definitions() {
+    assert_eq!(
+        parse("*[HTML]: HyperText Markup Language\n  *[W3C]:World Wide Web Consortium  \n*[TBD]:"),
+        vec![
+            definition("HTML", "HyperText Markup Language"),
+            definition("W3C", "World Wide Web Consortium"),
+            definition("TBD", ""),
+        ]
+    );
+}
+
+#[test]
+fn abbreviation_definition_interrupts_paragraph() {
+    assert_eq!(
+        parse("Some HTML.\n*[HTML]: HyperText Markup Language"),
+        vec![
+            Block::Paragraph(vec![Inline::Text("Some HTML.".to_owned())]),
+            definition("HTML", "HyperText Markup Language"),
+        ]
+    );
+}
+
+#[test]
+fn abbreviation_definition_needs_abbreviation_and_colon() {
+    assert_eq!(
+        parse("*[ ]: empty\n\n*[HTML] no colon"),
+        vec![
+            Block::Paragraph(vec![Inline::Text("*[ ]: empty".to_owned())]),
+            Block::Paragraph(vec![
+                Inline::Text("*".to_owned()),
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("HTML".to_owned())],
+                    text: vec![Inline::Text("HTML".to_owned())],
+                    image: false,
+                }),
+                Inline::Text(" no colon".to_owned()),
+            ]),
+        ]
+    );
+}
@@ -1,5 +1,5 @@
 use crate::ast::*;
-use crate::parser::{parse_markdown, MarkdownParserState};
+use crate::parser::{parse_markdown, MarkdownParserConfig, MarkdownParserState};
 
 #[test]
 fn code_block_indented1() {
@@ -9,7 +9,8 @@ fn code_block_indented1() {
         Document {
             blocks: vec![Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Indented,
-                literal: " a".to_owned()
+                literal: " a".to_owned(),
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -23,7 +24,8 @@ fn code_block_indented2() {
         Document {
             blocks: vec![Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Indented,
-                literal: " a\nb".to_owned()
+                literal: " a\nb".to_owned(),
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -37,7 +39,8 @@ fn code_block_fenced1() {
         Document {
             blocks: vec![Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Fenced { info: None },
-                literal: "a".to_owned()
+                literal: "a".to_owned(),
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -51,7 +54,8 @@ fn code_block_fenced2() {
         Document {
             blocks: vec![Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Fenced { info: None },
-                literal: "a".to_owned()
+                literal: "a".to_owned(),
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -65,7 +69,8 @@ fn code_block_fenced3() {
         Document {
             blocks: vec![Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Fenced { info: None },
-                literal: "  a\n    b".to_owned()
+                literal: "  a\n    b".to_owned(),
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -81,8 +86,55 @@ fn code_block_fenced4() {
                 kind: CodeBlockKind::Fenced {
                     info: Some("rust".to_owned())
                 },
-                literal: "a".to_owned()
+                literal: "a".to_owned(),
+                attributes: Attributes::default(),
             })]
         }
     );
 }
+
+#[test]
+fn code_block_attributes() {
+    let config = MarkdownParserConfig::default().with_allow_attributes();
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config.clone()),
+        "``` rust {#main .numberLines startFrom=10}\nfn main() {}\n```\n\n```{.text}\nplain\n```",
+    )
+    .unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::CodeBlock(CodeBlock {
+                kind: CodeBlockKind::Fenced {
+                    info: Some("rust".to_owned())
+                },
+                literal: "fn main() {}".to_owned(),
+                attributes: Attributes {
+                    id: Some("main".to_owned()),
+                    classes: vec!["numberLines".to_owned()],
+                    pairs: vec![("startFrom".to_owned(), "10".to_owned())],
+                },
+            }),
+            Block::CodeBlock(CodeBlock {
+                kind: CodeBlockKind::Fenced { info: None },
+                literal: "plain".to_owned(),
+                attributes: Attributes {
+                    classes: vec!["text".to_owned()],
+                    ..Default::default()
+                },
+            }),
+        ]
+    );
+
+    let doc = parse_markdown(MarkdownParserState::default(), "```rust {.x}\n```").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::CodeBlock(CodeBlock {
+            kind: CodeBlockKind::Fenced {
+                info: Some("rust {.x}".to_owned())
+            },
+            literal: "".to_owned(),
+            attributes: Attributes::default(),
+        })]
+    );
+}
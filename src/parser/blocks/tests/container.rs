@@ -1,4 +1,4 @@
-use crate::ast::{Block, Container, Heading, HeadingKind, Inline};
+use crate::ast::{Attributes, Block, Container, Heading, HeadingKind, Inline};
 use crate::parser::{parse_markdown, MarkdownParserState};
 
 #[test]
@@ -84,6 +84,7 @@ some content
             blocks: vec![
                 Block::Heading(Heading {
                     kind: HeadingKind::Atx(1),
+                    attributes: Attributes::default(),
                     content: vec![Inline::Text("H1".to_string())]
                 }),
                 Block::Paragraph(vec![Inline::Text("some content".to_string())])
@@ -1,4 +1,7 @@
 use crate::ast::*;
+use crate::parser::config::{
+    BuiltinBlockParser, CustomBlockParserFn, MarkdownParserConfig, ParserPriority,
+};
 use crate::parser::{parse_markdown, MarkdownParserState};
 use nom::combinator::value;
 use std::cell::RefCell;
@@ -20,9 +23,97 @@ fn custom_parser1() {
                 Block::ThematicBreak,
                 Block::Heading(Heading {
                     kind: HeadingKind::Setext(SetextHeading::Level1),
+                    attributes: Attributes::default(),
                     content: vec![Inline::Text("text".to_owned())]
                 })
             ]
         }
     );
 }
+
+fn tag_parser(tag: &'static str, block: Block) -> CustomBlockParserFn {
+    use nom::Parser;
+    Rc::new(RefCell::new(Box::new(move |input: &str| {
+        value(vec![block.clone()], nom::bytes::complete::tag(tag)).parse(input)
+    })))
+}
+
+fn html(text: &str) -> Block {
+    Block::HtmlBlock(text.to_owned())
+}
+
+#[test]
+fn custom_parser_priority() {
+    let config = MarkdownParserConfig::default().with_named_custom_block_parser(
+        "before",
+        ParserPriority::Before(BuiltinBlockParser::HeadingV1),
+        tag_parser("# title", html("before")),
+    );
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "# title").unwrap();
+    assert_eq!(doc.blocks, vec![html("before")]);
+
+    let config = MarkdownParserConfig::default().with_named_custom_block_parser(
+        "after",
+        ParserPriority::After(BuiltinBlockParser::HeadingV1),
+        tag_parser("#", html("after")),
+    );
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "# title\n\n#").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
+                content: vec![Inline::Text("title".to_owned())]
+            }),
+            html("after"),
+        ]
+    );
+}
+
+#[test]
+fn custom_parser_interrupts_paragraph() {
+    let config = MarkdownParserConfig::default().with_named_custom_block_parser(
+        "toc",
+        ParserPriority::Before(BuiltinBlockParser::Paragraph),
+        tag_parser("[TOC]", html("toc")),
+    );
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "text\n[TOC]").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::Paragraph(vec![Inline::Text("text".to_owned())]),
+            html("toc"),
+        ]
+    );
+}
+
+#[test]
+fn custom_parser_registry() {
+    let priority = ParserPriority::Before(BuiltinBlockParser::CodeBlock);
+    let config = MarkdownParserConfig::default()
+        .with_named_custom_block_parser("a", priority, tag_parser("@", html("a")))
+        .with_named_custom_block_parser("b", priority, tag_parser("@", html("b")))
+        .with_custom_block_parser(tag_parser("@", html("custom")));
+    assert_eq!(
+        config.custom_block_parser_names().collect::<Vec<_>>(),
+        vec!["a", "b", "custom"]
+    );
+
+    // Parsers at the same priority are tried in registration order
+    let doc = parse_markdown(MarkdownParserState::with_config(config.clone()), "@").unwrap();
+    assert_eq!(doc.blocks, vec![html("a")]);
+
+    // Registering under an existing name replaces the parser
+    let config = config.with_named_custom_block_parser("a", priority, tag_parser("@", html("a2")));
+    assert_eq!(
+        config.custom_block_parser_names().collect::<Vec<_>>(),
+        vec!["b", "custom", "a"]
+    );
+    let doc = parse_markdown(MarkdownParserState::with_config(config.clone()), "@").unwrap();
+    assert_eq!(doc.blocks, vec![html("b")]);
+
+    let config = config.without_custom_block_parser("b");
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "@").unwrap();
+    assert_eq!(doc.blocks, vec![html("a2")]);
+}
@@ -0,0 +1,99 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn parse(input: &str) -> Document {
+    let config =
+        MarkdownParserConfig::default().with_block_definition_list_behavior(ElementBehavior::Parse);
+    parse_markdown(MarkdownParserState::with_config(config), input).unwrap()
+}
+
+fn text(s: &str) -> Vec<Block> {
+    vec![Block::Paragraph(vec![Inline::Text(s.to_owned())])]
+}
+
+#[test]
+fn definition_list_single_item() {
+    assert_eq!(
+        parse("Term\n: Definition"),
+        Document {
+            blocks: vec![Block::DefinitionList(DefinitionList {
+                items: vec![DefinitionListItem {
+                    term: vec![Inline::Text("Term".to_owned())],
+                    definitions: vec![text("Definition")],
+                }],
+            })],
+        }
+    );
+}
+
+#[test]
+fn definition_list_multiple_items_and_definitions() {
+    assert_eq!(
+        parse("Apple\n: A fruit\n: A company\n\n*Banana*\n\n:   Yellow\n"),
+        Document {
+            blocks: vec![Block::DefinitionList(DefinitionList {
+                items: vec![
+                    DefinitionListItem {
+                        term: vec![Inline::Text("Apple".to_owned())],
+                        definitions: vec![text("A fruit"), text("A company")],
+                    },
+                    DefinitionListItem {
+                        term: vec![Inline::Emphasis(vec![Inline::Text("Banana".to_owned())])],
+                        definitions: vec![text("Yellow")],
+                    },
+                ],
+            })],
+        }
+    );
+}
+
+#[test]
+fn definition_list_continuation_blocks() {
+    assert_eq!(
+        parse("Term\n: First line\n  second line\n\n  - item\n\nAfter"),
+        Document {
+            blocks: vec![
+                Block::DefinitionList(DefinitionList {
+                    items: vec![DefinitionListItem {
+                        term: vec![Inline::Text("Term".to_owned())],
+                        definitions: vec![vec![
+                            Block::Paragraph(vec![Inline::Text(
+                                "First line\nsecond line".to_owned()
+                            )]),
+                            Block::List(List {
+                                kind: ListKind::Bullet(ListBulletKind::Dash),
+                                items: vec![ListItem {
+                                    task: None,
+                                    blocks: text("item"),
+                                }],
+                            }),
+                        ]],
+                    }],
+                }),
+                Block::Paragraph(vec![Inline::Text("After".to_owned())]),
+            ],
+        }
+    );
+}
+
+#[test]
+fn definition_list_requires_definition() {
+    assert_eq!(
+        parse("Term\n\nParagraph"),
+        Document {
+            blocks: vec![text("Term"), text("Paragraph")].concat(),
+        }
+    );
+}
+
+#[test]
+fn definition_list_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "Term\n: Definition").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: text("Term\n: Definition"),
+        }
+    );
+}
@@ -0,0 +1,93 @@
+use crate::ast::*;
+use crate::parser::{parse_markdown, parse_markdown_with_recovery, MarkdownParserState};
+use nom::Parser;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn error_position_of_bad_container_params() {
+    let err =
+        parse_markdown(MarkdownParserState::new(), "Intro\n\n:::note{kind=}\n:::\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 14));
+    assert_eq!(err.offset, 20);
+    assert_eq!(err.construct, "container block");
+    assert_eq!(
+        err.to_string(),
+        "3:14: container block: expected a value after `=`"
+    );
+}
+
+#[test]
+fn error_inside_nested_block() {
+    let err = parse_markdown(MarkdownParserState::new(), "> quote\n>\n> :::a{x=}\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 10));
+    assert_eq!(err.construct, "container block");
+}
+
+#[test]
+fn error_from_custom_parser() {
+    let config = crate::parser::config::MarkdownParserConfig::default().with_custom_block_parser(
+        Rc::new(RefCell::new(Box::new(|input: &str| {
+            let (rest, _) = nom::bytes::complete::tag("@@").parse(input)?;
+            nom::combinator::cut(nom::bytes::complete::tag("end"))
+                .map(|_| vec![Block::ThematicBreak])
+                .parse(rest)
+        }))),
+    );
+    let err =
+        parse_markdown(MarkdownParserState::with_config(config), "text\n\n@@oops\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 3));
+    assert_eq!(err.construct, "custom block");
+    assert_eq!(err.message, "parser `custom` failed: Tag");
+}
+
+#[test]
+fn error_message_of_bad_quoted_container_param() {
+    let message = |input: &str| {
+        parse_markdown(MarkdownParserState::new(), input)
+            .unwrap_err()
+            .message
+    };
+    assert_eq!(
+        message(":::note{kind=\"open}\n:::\n"),
+        "quoted value without a closing `\"`"
+    );
+    assert_eq!(message(":::note{kind=\"\"}\n:::\n"), "empty quoted value");
+}
+
+#[test]
+fn recovery_keeps_parsing() {
+    let (doc, errors) = parse_markdown_with_recovery(
+        MarkdownParserState::new(),
+        "# Title\n\n:::a{x=}\n\n*text*\n\n:::b{y=}\n",
+    );
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.line, e.construct.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(3, "container block"), (7, "container block")]
+    );
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
+                content: vec![Inline::Text("Title".to_owned())]
+            }),
+            Block::Paragraph(vec![Inline::Text(":::a{x=}".to_owned())]),
+            Block::Paragraph(vec![Inline::Emphasis(vec![Inline::Text(
+                "text".to_owned()
+            )])]),
+            Block::Paragraph(vec![Inline::Text(":::b{y=}".to_owned())]),
+        ]
+    );
+}
+
+#[test]
+fn recovery_without_errors() {
+    let (doc, errors) = parse_markdown_with_recovery(MarkdownParserState::new(), "a\n\nb\n");
+    assert!(errors.is_empty());
+    assert_eq!(doc.blocks.len(), 2);
+}
@@ -0,0 +1,165 @@
+use crate::ast::*;
+use crate::parser::config::*;
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+const TABLE: &str = "| Fruit | Price |\n| ----- | ----- |\n| Apple | 1 |\n";
+
+fn parse(input: &str) -> Vec<Block> {
+    let config = MarkdownParserConfig::default()
+        .with_allow_attributes()
+        .with_block_figure_behavior(ElementBehavior::Parse);
+    parse_markdown(MarkdownParserState::with_config(config), input)
+        .unwrap()
+        .blocks
+}
+
+fn image(alt: &str, title: Option<&str>) -> Inline {
+    Inline::Image(Image {
+        destination: "cat.png".to_owned(),
+        title: title.map(str::to_owned),
+        alt: alt.to_owned(),
+        attributes: Attributes::default(),
+    })
+}
+
+fn table() -> Block {
+    let doc = parse_markdown(MarkdownParserState::default(), TABLE).unwrap();
+    doc.blocks[0].clone()
+}
+
+#[test]
+fn figure_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "![A cat](cat.png)").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![image("A cat", None)])]
+    );
+}
+
+#[test]
+fn image_figure_captioned_by_alt_text() {
+    assert_eq!(
+        parse("![A cat](cat.png){#fig-cat}"),
+        vec![Block::Figure {
+            content: vec![Block::Paragraph(vec![image("A cat", None)])],
+            caption: vec![Inline::Text("A cat".to_owned())],
+            label: Some("fig-cat".to_owned()),
+            caption_syntax: CaptionSyntax::Image,
+        }]
+    );
+}
+
+#[test]
+fn image_figure_captioned_by_title() {
+    assert_eq!(
+        parse("![cat](cat.png \"A sleeping cat\")"),
+        vec![Block::Figure {
+            content: vec![Block::Paragraph(vec![image("cat", Some("A sleeping cat"))])],
+            caption: vec![Inline::Text("A sleeping cat".to_owned())],
+            label: None,
+            caption_syntax: CaptionSyntax::Image,
+        }]
+    );
+}
+
+#[test]
+fn image_not_alone_is_not_figure() {
+    assert_eq!(
+        parse("![A cat](cat.png) sleeps\n\n![](cat.png)"),
+        vec![
+            Block::Paragraph(vec![
+                image("A cat", None),
+                Inline::Text(" sleeps".to_owned()),
+            ]),
+            Block::Paragraph(vec![image("", None)]),
+        ]
+    );
+}
+
+#[test]
+fn table_figure() {
+    assert_eq!(
+        parse(&format!("{TABLE}Table: Fruit *prices*\n\nAfter")),
+        vec![
+            Block::Figure {
+                content: vec![table()],
+                caption: vec![
+                    Inline::Text("Fruit ".to_owned()),
+                    Inline::Emphasis(vec![Inline::Text("prices".to_owned())]),
+                ],
+                label: None,
+                caption_syntax: CaptionSyntax::Table,
+            },
+            Block::Paragraph(vec![Inline::Text("After".to_owned())]),
+        ]
+    );
+}
+
+#[test]
+fn table_figure_with_colon_and_label() {
+    assert_eq!(
+        parse(&format!("{TABLE}{{#tbl-prices}}\n: Fruit prices")),
+        vec![Block::Figure {
+            content: vec![table()],
+            caption: vec![Inline::Text("Fruit prices".to_owned())],
+            label: Some("tbl-prices".to_owned()),
+            caption_syntax: CaptionSyntax::Colon,
+        }]
+    );
+}
+
+#[test]
+fn table_without_caption_is_not_figure() {
+    assert_eq!(parse(TABLE), vec![table()]);
+}
+
+#[test]
+fn table_figure_with_blank_line_before_caption() {
+    assert_eq!(
+        parse(&format!("{TABLE}\n: Fruit prices\n\nAfter")),
+        vec![
+            Block::Figure {
+                content: vec![table()],
+                caption: vec![Inline::Text("Fruit prices".to_owned())],
+                label: None,
+                caption_syntax: CaptionSyntax::Colon,
+            },
+            Block::Paragraph(vec![Inline::Text("After".to_owned())]),
+        ]
+    );
+}
+
+#[test]
+fn table_figure_with_caption_before_table() {
+    let figure = Block::Figure {
+        content: vec![table()],
+        caption: vec![Inline::Text("Fruit prices".to_owned())],
+        label: None,
+        caption_syntax: CaptionSyntax::Table,
+    };
+    assert_eq!(
+        parse(&format!("Table: Fruit prices\n{TABLE}\nAfter")),
+        vec![
+            figure.clone(),
+            Block::Paragraph(vec![Inline::Text("After".to_owned())]),
+        ]
+    );
+    assert_eq!(
+        parse(&format!("Before\n\nTable: Fruit prices\n\n{TABLE}")),
+        vec![
+            Block::Paragraph(vec![Inline::Text("Before".to_owned())]),
+            figure,
+        ]
+    );
+}
+
+#[test]
+fn caption_without_table_is_paragraph() {
+    assert_eq!(
+        parse("Table: Fruit prices\n\nAfter"),
+        vec![
+            Block::Paragraph(vec![Inline::Text("Table: Fruit prices".to_owned())]),
+            Block::Paragraph(vec![Inline::Text("After".to_owned())]),
+        ]
+    );
+}
@@ -0,0 +1,143 @@
+use crate::ast::*;
+use crate::parser::config::*;
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn state() -> MarkdownParserState {
+    let config =
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse);
+    MarkdownParserState::with_config(config)
+}
+
+#[test]
+fn front_matter_yaml() {
+    let doc = parse_markdown(state(), "---\ntitle: Hello\n\ntags: [a, b]\n---\n# Hello\n").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::FrontMatter {
+                    format: FrontMatterFormat::Yaml,
+                    raw: "title: Hello\n\ntags: [a, b]".to_owned(),
+                },
+                Block::Heading(Heading {
+                    kind: HeadingKind::Atx(1),
+                    attributes: Attributes::default(),
+                    content: vec![Inline::Text("Hello".to_owned())]
+                }),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_toml() {
+    let doc = parse_markdown(state(), "+++\ntitle = \"Hello\"\n+++  \r\n\ntext").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::FrontMatter {
+                    format: FrontMatterFormat::Toml,
+                    raw: "title = \"Hello\"".to_owned(),
+                },
+                Block::Paragraph(vec![Inline::Text("text".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_empty() {
+    let doc = parse_markdown(state(), "---\n---").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::FrontMatter {
+                format: FrontMatterFormat::Yaml,
+                raw: String::new(),
+            }]
+        }
+    );
+}
+
+#[test]
+fn front_matter_only_at_document_start() {
+    let doc = parse_markdown(state(), "\n---\na\n---\n").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::ThematicBreak,
+                Block::Heading(Heading {
+                    kind: HeadingKind::Setext(SetextHeading::Level2),
+                    attributes: Attributes::default(),
+                    content: vec![Inline::Text("a".to_owned())]
+                }),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_unclosed() {
+    let doc = parse_markdown(state(), "---\n+++\n").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::ThematicBreak,
+                Block::Paragraph(vec![Inline::Text("+++".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "---\na\n---\n").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::ThematicBreak,
+                Block::Heading(Heading {
+                    kind: HeadingKind::Setext(SetextHeading::Level2),
+                    attributes: Attributes::default(),
+                    content: vec![Inline::Text("a".to_owned())]
+                }),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_not_followed_by_blank_line() {
+    let doc = parse_markdown(state(), "---\n\nHello\n\n---\n\nWorld\n").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::ThematicBreak,
+                Block::Paragraph(vec![Inline::Text("Hello".to_owned())]),
+                Block::ThematicBreak,
+                Block::Paragraph(vec![Inline::Text("World".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_skip() {
+    let config =
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Skip);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "---\na\n---\nb").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::Empty,
+                Block::Paragraph(vec![Inline::Text("b".to_owned())]),
+            ]
+        }
+    );
+}
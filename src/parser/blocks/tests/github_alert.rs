@@ -305,6 +305,7 @@ fn github_alert_invalid_custom_starts_with_number() {
                 Inline::LinkReference(crate::ast::LinkReference {
                     label: vec![Inline::Text("!123INVALID".to_string())],
                     text: vec![Inline::Text("!123INVALID".to_string())],
+                    image: false,
                 }),
                 Inline::Text("\nShould not be parsed as alert".to_string())
             ])])],
@@ -329,6 +330,7 @@ fn github_alert_invalid_custom_with_special_chars() {
                 Inline::LinkReference(crate::ast::LinkReference {
                     label: vec![Inline::Text("!CUSTOM-ALERT".to_string())],
                     text: vec![Inline::Text("!CUSTOM-ALERT".to_string())],
+                    image: false,
                 }),
                 Inline::Text("\nShould not be parsed as alert".to_string())
             ])])],
@@ -9,6 +9,7 @@ fn heading_v1() {
         Document {
             blocks: vec![Block::Heading(Heading {
                 kind: HeadingKind::Atx(2),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("a".to_owned())]
             })]
         }
@@ -29,6 +30,7 @@ fn heading_v1() {
         Document {
             blocks: vec![Block::Heading(Heading {
                 kind: HeadingKind::Atx(2),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("a".to_owned())]
             })]
         }
@@ -43,6 +45,7 @@ fn heading_v2() {
         Document {
             blocks: vec![Block::Heading(Heading {
                 kind: HeadingKind::Setext(SetextHeading::Level1),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("a".to_owned())]
             })]
         }
@@ -54,8 +57,91 @@ fn heading_v2() {
         Document {
             blocks: vec![Block::Heading(Heading {
                 kind: HeadingKind::Setext(SetextHeading::Level2),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("a".to_owned())]
             })]
         }
     );
 }
+
+#[test]
+fn heading_id() {
+    let config = MarkdownParserConfig::default().with_allow_heading_ids();
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "# Install *now* {#install}  \n\nTitle {#sec:title.1}\n---\n\n# {#only}\n\n# Text {#not an id}",
+    )
+    .unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes {
+                    id: Some("install".to_owned()),
+                    ..Default::default()
+                },
+                content: vec![
+                    Inline::Text("Install ".to_owned()),
+                    Inline::Emphasis(vec![Inline::Text("now".to_owned())]),
+                ]
+            }),
+            Block::Heading(Heading {
+                kind: HeadingKind::Setext(SetextHeading::Level2),
+                attributes: Attributes {
+                    id: Some("sec:title.1".to_owned()),
+                    ..Default::default()
+                },
+                content: vec![Inline::Text("Title".to_owned())]
+            }),
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes {
+                    id: Some("only".to_owned()),
+                    ..Default::default()
+                },
+                content: vec![]
+            }),
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
+                content: vec![Inline::Text("Text {#not an id}".to_owned())]
+            }),
+        ]
+    );
+
+    let doc = parse_markdown(MarkdownParserState::default(), "# Install {#install}").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Heading(Heading {
+            kind: HeadingKind::Atx(1),
+            attributes: Attributes::default(),
+            content: vec![Inline::Text("Install {#install}".to_owned())]
+        })]
+    );
+}
+
+#[test]
+fn heading_attributes() {
+    let config = MarkdownParserConfig::default().with_allow_attributes();
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "# Install {#install .unnumbered lang=en title=\"Set up\"}",
+    )
+    .unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Heading(Heading {
+            kind: HeadingKind::Atx(1),
+            attributes: Attributes {
+                id: Some("install".to_owned()),
+                classes: vec!["unnumbered".to_owned()],
+                pairs: vec![
+                    ("lang".to_owned(), "en".to_owned()),
+                    ("title".to_owned(), "Set up".to_owned()),
+                ],
+            },
+            content: vec![Inline::Text("Install".to_owned())]
+        })]
+    );
+}
@@ -0,0 +1,203 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, IncrementalDocument, MarkdownParserState, TextEdit};
+
+fn assert_same_as_full_parse(input: &str, edit: TextEdit) {
+    assert_same_as_full_parse_with(MarkdownParserConfig::default(), input, edit);
+}
+
+fn assert_same_as_full_parse_with(config: MarkdownParserConfig, input: &str, edit: TextEdit) {
+    let state = || MarkdownParserState::with_config(config.clone());
+    let mut doc = IncrementalDocument::parse(state(), input).unwrap();
+    let mut edited = input.to_string();
+    edited.replace_range(edit.range.clone(), &edit.replacement);
+
+    match parse_markdown(state(), &edited) {
+        Ok(expected) => {
+            doc.apply_edit(&edit).unwrap();
+            assert_eq!(doc.source(), edited);
+            assert_eq!(doc.document(), &expected, "{edit:?}");
+        }
+        Err(_) => assert!(doc.apply_edit(&edit).is_err(), "{edit:?}"),
+    }
+}
+
+#[test]
+fn incremental_edit_inside_block() {
+    let mut doc =
+        IncrementalDocument::parse(MarkdownParserState::new(), "# Title\n\nOne\n\nTwo\n").unwrap();
+    doc.apply_edit(&TextEdit::new(9..12, "**One**")).unwrap();
+    assert_eq!(
+        doc.document(),
+        &Document {
+            blocks: vec![
+                Block::Heading(Heading {
+                    kind: HeadingKind::Atx(1),
+                    attributes: Attributes::default(),
+                    content: vec![Inline::Text("Title".to_owned())]
+                }),
+                Block::Paragraph(vec![Inline::Strong(vec![Inline::Text("One".to_owned())])]),
+                Block::Paragraph(vec![Inline::Text("Two".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn incremental_edit_merges_blocks() {
+    let mut doc = IncrementalDocument::parse(MarkdownParserState::new(), "One\n\nTwo\n").unwrap();
+    doc.apply_edit(&TextEdit::new(3..5, "\n")).unwrap();
+    assert_eq!(
+        doc.into_document(),
+        Document {
+            blocks: vec![Block::Paragraph(vec![Inline::Text("One\nTwo".to_owned())])]
+        }
+    );
+}
+
+#[test]
+fn incremental_edit_extends_previous_block() {
+    // Indenting the paragraph moves it into the list item before it.
+    assert_same_as_full_parse("- item\n\npara\n\nlast\n", TextEdit::new(8..8, "  "));
+}
+
+#[test]
+fn incremental_sequence_of_edits() {
+    let mut doc = IncrementalDocument::parse(MarkdownParserState::new(), "").unwrap();
+    for (offset, text) in [
+        (0, "> quote\n"),
+        (8, "\n- a\n"),
+        (13, "- b\n"),
+        (0, "# H\n"),
+    ] {
+        doc.apply_edit(&TextEdit::new(offset..offset, text))
+            .unwrap();
+        let expected = parse_markdown(MarkdownParserState::new(), doc.source()).unwrap();
+        assert_eq!(doc.document(), &expected);
+    }
+    assert_eq!(doc.source(), "# H\n> quote\n\n- a\n- b\n");
+}
+
+#[test]
+fn incremental_error_leaves_document_unchanged() {
+    let mut doc = IncrementalDocument::parse(MarkdownParserState::new(), "a\n\nb\n").unwrap();
+    let before = doc.document().clone();
+    let err = doc
+        .apply_edit(&TextEdit::new(3..4, ":::x{y=}"))
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (3, 8));
+    assert_eq!(doc.source(), "a\n\nb\n");
+    assert_eq!(doc.document(), &before);
+}
+
+#[test]
+fn incremental_matches_full_parse_at_every_offset() {
+    let input = "# T\n\nsome *text*\nmore\n\n- a\n- b\n\n  c\n\n> q\n\n```\nx\n\n```\n\n| a |\n|---|\n| 1 |\n\nend\n";
+    for offset in 0..=input.len() {
+        for text in ["\n", "- ", "  ", "```", "|"] {
+            assert_same_as_full_parse(input, TextEdit::new(offset..offset, text));
+        }
+        if offset < input.len() {
+            assert_same_as_full_parse(input, TextEdit::new(offset..offset + 1, ""));
+        }
+    }
+}
+
+#[test]
+fn incremental_front_matter_at_every_offset() {
+    let config =
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse);
+    for input in [
+        "---\ntitle: a\n\nb: c\n---\n\n# T\n\ntext\n",
+        "---\n\na\n\n- b\n\nc\n\nd\n",
+    ] {
+        for offset in 0..=input.len() {
+            for text in ["---\n", "\n", "-"] {
+                assert_same_as_full_parse_with(
+                    config.clone(),
+                    input,
+                    TextEdit::new(offset..offset, text),
+                );
+            }
+            if offset < input.len() {
+                assert_same_as_full_parse_with(
+                    config.clone(),
+                    input,
+                    TextEdit::new(offset..offset + 1, ""),
+                );
+            }
+        }
+    }
+}
+
+/// Fragments that open or close multi-line blocks, or join and split them
+pub(super) const FRAGMENTS: &[&str] = &[
+    "```\n",
+    "~~~\n",
+    "$$\n",
+    ":::\n",
+    ":::note\n",
+    "<!--\n",
+    "-->\n",
+    "{{\n",
+    "}}\n",
+    "\\[\n",
+    "\\]\n",
+    "Price is $$ cheap\n",
+    "em*\n",
+    "# H\n",
+    "[x]: /u\n",
+    "\n",
+    "a\n\n",
+    "b\n\n",
+    "c\n",
+    "- ",
+    "> ",
+    "    ",
+    "---\n",
+];
+
+#[test]
+fn incremental_matches_full_parse_after_random_edits() {
+    // xorshift, so that failures are reproducible
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = |bound: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % bound as u64) as usize
+    };
+    for _ in 0..500 {
+        let mut input = String::new();
+        for _ in 0..next(12) {
+            input.push_str(FRAGMENTS[next(FRAGMENTS.len())]);
+        }
+        let mut doc =
+            IncrementalDocument::parse(MarkdownParserState::new(), input.clone()).unwrap();
+        for _ in 0..4 {
+            let start = next(input.len() + 1);
+            let end = (start + next(4)).min(input.len());
+            let edit = TextEdit::new(start..end, FRAGMENTS[next(FRAGMENTS.len())]);
+            input.replace_range(edit.range.clone(), &edit.replacement);
+            let expected = parse_markdown(MarkdownParserState::new(), &input).unwrap();
+            doc.apply_edit(&edit).unwrap();
+            assert_eq!(doc.document(), &expected, "{input:?} after {edit:?}");
+        }
+    }
+}
+
+#[test]
+fn incremental_comment_closed_after_earlier_closer() {
+    // The comment opened on the second line is closed by the edit, although
+    // the text before it already has as many closers as openers
+    assert_same_as_full_parse(
+        "-->\n<!--\nem*\n# H\n[x]: /u\nem*\n:::note\n:::\n",
+        TextEdit::new(35..36, "-->"),
+    );
+}
+
+#[test]
+fn incremental_display_math_ends_at_blank_line() {
+    assert_same_as_full_parse("$$\na\n\nb\n\nc\n", TextEdit::new(9..9, "$$\n"));
+    assert_same_as_full_parse("$$\na\nb\n\nc\n", TextEdit::new(7..7, "$$\n"));
+}
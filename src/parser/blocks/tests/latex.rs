@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
 use crate::parser::{parse_markdown, MarkdownParserState};
 
 #[test]
@@ -11,9 +12,10 @@ fn block_latex() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::LatexBlock(
-                "\\sum_{i=0}^n i = \\frac{n(n+1)}{2}".to_string()
-            )],
+            blocks: vec![Block::LatexBlock {
+                delimiter: DisplayMathDelimiter::DoubleDollar,
+                content: "\\sum_{i=0}^n i = \\frac{n(n+1)}{2}".to_string()
+            }],
         }
     );
 }
@@ -26,11 +28,115 @@ fn block_latex_with_text() {
         Document {
             blocks: vec![
                 Block::Paragraph(vec![Inline::Text("The formula is:".to_string())]),
-                Block::LatexBlock(
-                    "\\int_0^\\infty e^{-x^2} dx = \\frac{\\sqrt{\\pi}}{2}".to_string()
-                ),
+                Block::LatexBlock {
+                    delimiter: DisplayMathDelimiter::DoubleDollar,
+                    content: "\\int_0^\\infty e^{-x^2} dx = \\frac{\\sqrt{\\pi}}{2}".to_string()
+                },
                 Block::Paragraph(vec![Inline::Text("End of formula.".to_string())]),
             ],
         }
     );
 }
+
+#[test]
+fn block_latex_behavior() {
+    let config =
+        MarkdownParserConfig::default().with_block_latex_block_behavior(ElementBehavior::Skip);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "$$x^2$$\n\ntext").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::Empty,
+            Block::Paragraph(vec![Inline::Text("text".to_string())]),
+        ]
+    );
+
+    let config =
+        MarkdownParserConfig::default().with_block_latex_block_behavior(ElementBehavior::Ignore);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "$$x^2$$").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::Dollar,
+                content: String::new()
+            },
+            Inline::Text("x^2".to_string()),
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::Dollar,
+                content: String::new()
+            },
+        ])]
+    );
+}
+
+#[test]
+fn block_latex_unterminated() {
+    let doc = parse_markdown(
+        MarkdownParserState::default(),
+        "$$\nx^2\n\n# Heading\n\nSee $$y$$",
+    )
+    .unwrap();
+    assert_eq!(doc.blocks.len(), 3);
+    assert_eq!(
+        doc.blocks[1],
+        Block::Heading(Heading {
+            kind: HeadingKind::Atx(1),
+            content: vec![Inline::Text("Heading".to_string())],
+            attributes: Attributes::default(),
+        })
+    );
+}
+
+#[test]
+fn block_latex_multiline() {
+    let doc = parse_markdown(MarkdownParserState::default(), "$$\na +\nb\n$$").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::LatexBlock {
+            delimiter: DisplayMathDelimiter::DoubleDollar,
+            content: "a +\nb".to_string()
+        }]
+    );
+}
+
+#[test]
+fn block_latex_brackets() {
+    let config =
+        MarkdownParserConfig::default().with_block_latex_brackets_behavior(ElementBehavior::Parse);
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "\\[\n\\frac{a}{b}\n\\]\n\ntext",
+    )
+    .unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![
+            Block::LatexBlock {
+                delimiter: DisplayMathDelimiter::Brackets,
+                content: "\\frac{a}{b}".to_string()
+            },
+            Block::Paragraph(vec![Inline::Text("text".to_string())]),
+        ]
+    );
+}
+
+#[test]
+fn block_latex_fence() {
+    let input = "```math\na^2\n+ b^2\n```\n\n```rust\nfn main() {}\n```";
+    let config =
+        MarkdownParserConfig::default().with_block_latex_fence_behavior(ElementBehavior::Parse);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), input).unwrap();
+    assert_eq!(
+        doc.blocks[0],
+        Block::LatexBlock {
+            delimiter: DisplayMathDelimiter::Fence,
+            content: "a^2\n+ b^2".to_string()
+        }
+    );
+    assert!(matches!(doc.blocks[1], Block::CodeBlock(_)));
+
+    // Disabled by default: a code block with the `math` info string
+    let doc = parse_markdown(MarkdownParserState::default(), input).unwrap();
+    assert!(matches!(doc.blocks[0], Block::CodeBlock(_)));
+}
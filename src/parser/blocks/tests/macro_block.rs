@@ -36,3 +36,40 @@ fn test_fail_if_not_on_own_line() {
     let doc = parse_markdown(state, text).unwrap();
     assert!(!matches!(doc.blocks[0], Block::MacroBlock(_)));
 }
+
+#[test]
+fn test_macro_block_behavior() {
+    use crate::ast::Inline;
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use std::{cell::RefCell, rc::Rc};
+
+    let config = MarkdownParserConfig::default().with_block_macro_block_behavior(
+        ElementBehavior::Map(Rc::new(RefCell::new(Box::new(|block| match block {
+            Block::MacroBlock(name) => Block::HtmlBlock(format!("<!-- {name} -->")),
+            other => other,
+        })))),
+    );
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "{{ toc }}\n").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::HtmlBlock("<!-- toc -->".to_string())]
+    );
+
+    let config =
+        MarkdownParserConfig::default().with_block_macro_block_behavior(ElementBehavior::Ignore);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "{{ toc }}\n").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(
+            "{{ toc }}".to_string()
+        )])]
+    );
+}
+
+#[test]
+fn test_macro_block_does_not_span_blank_line() {
+    let text = "{{ open\n\nclose }}\n";
+    let state = MarkdownParserState::default();
+    let doc = parse_markdown(state, text).unwrap();
+    assert!(!doc.blocks.iter().any(|b| matches!(b, Block::MacroBlock(_))));
+}
@@ -1,15 +1,24 @@
+mod abbreviation_definition;
 mod blockquote;
 mod code_block;
 mod container;
 mod custom_parser;
+mod definition_list;
+mod error;
+mod figure;
 mod footnote_definition;
+mod front_matter;
 mod github_alert;
 mod heading;
 mod html_block;
+mod incremental;
 mod latex;
 mod link_definition;
 mod list;
 mod macro_block;
 mod paragraph;
+#[cfg(feature = "ast-specialized")]
+mod spans;
+mod stream;
 mod table;
 mod thematic_break;
@@ -0,0 +1,260 @@
+use crate::ast::generic::{Block, Inline};
+use crate::ast_specialized::{Position, Span};
+use crate::parser::{parse_markdown_with_spans, MarkdownParserState};
+
+fn text_of<'a>(input: &'a str, span: &Span) -> &'a str {
+    &input[span.range()]
+}
+
+#[test]
+fn spans_heading_and_inlines() {
+    let input = "# Hi *there*\n\nSome `code`";
+    let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+    assert_eq!(text_of(input, &doc.user_data), input);
+
+    let Block::Heading(heading) = &doc.blocks[0] else {
+        panic!("expected heading");
+    };
+    assert_eq!(text_of(input, &heading.user_data), "# Hi *there*");
+    let Inline::Emphasis { content, user_data } = &heading.content[1] else {
+        panic!("expected emphasis");
+    };
+    assert_eq!(text_of(input, user_data), "*there*");
+    let Inline::Text { user_data, .. } = &content[0] else {
+        panic!("expected text");
+    };
+    assert_eq!(text_of(input, user_data), "there");
+
+    let Block::Paragraph { content, user_data } = &doc.blocks[1] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(
+        *user_data,
+        Span::new(Position::new(14, 3, 1), Position::new(25, 3, 12))
+    );
+    let Inline::Code { user_data, .. } = &content[1] else {
+        panic!("expected code");
+    };
+    assert_eq!(text_of(input, user_data), "`code`");
+}
+
+#[test]
+fn spans_blockquote() {
+    let input = "> a **b**\n>> c";
+    let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+
+    let Block::BlockQuote { blocks, user_data } = &doc.blocks[0] else {
+        panic!("expected blockquote");
+    };
+    assert_eq!(text_of(input, user_data), input);
+    let Block::Paragraph { content, .. } = &blocks[0] else {
+        panic!("expected paragraph");
+    };
+    let Inline::Strong { user_data, .. } = &content[1] else {
+        panic!("expected strong");
+    };
+    assert_eq!(text_of(input, user_data), "**b**");
+
+    let Block::BlockQuote { blocks, user_data } = &blocks[1] else {
+        panic!("expected nested blockquote");
+    };
+    assert_eq!(text_of(input, user_data), "> c");
+    let Block::Paragraph { user_data, .. } = &blocks[0] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(text_of(input, user_data), "c");
+    assert_eq!(user_data.start, Position::new(13, 2, 4));
+}
+
+#[test]
+fn spans_list_items() {
+    let input = "- one\n- two\n  [x](u)\n\n  more";
+    let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+
+    let Block::List(list) = &doc.blocks[0] else {
+        panic!("expected list");
+    };
+    assert_eq!(text_of(input, &list.user_data), input);
+    assert_eq!(text_of(input, &list.items[0].user_data), "- one");
+    assert_eq!(
+        text_of(input, &list.items[1].user_data),
+        "- two\n  [x](u)\n\n  more"
+    );
+
+    let Block::Paragraph { content, .. } = &list.items[1].blocks[0] else {
+        panic!("expected paragraph");
+    };
+    let Inline::Link(link) = &content[1] else {
+        panic!("expected link");
+    };
+    assert_eq!(text_of(input, &link.user_data), "[x](u)");
+    assert_eq!(link.user_data.start, Position::new(14, 3, 3));
+
+    let Block::Paragraph { user_data, .. } = &list.items[1].blocks[1] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(text_of(input, user_data), "more");
+}
+
+#[test]
+fn spans_table_cells() {
+    let input = "| a | b\\|c |\n|---|---|\n| 1 | `2` |";
+    let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+
+    let Block::Table(table) = &doc.blocks[0] else {
+        panic!("expected table");
+    };
+    assert_eq!(text_of(input, &table.user_data), input);
+    let Inline::Text { user_data, .. } = &table.rows[0][1].content[0] else {
+        panic!("expected text");
+    };
+    assert_eq!(text_of(input, user_data), "b\\|c");
+    let Inline::Code { user_data, .. } = &table.rows[1][1].content[0] else {
+        panic!("expected code");
+    };
+    assert_eq!(text_of(input, user_data), "`2`");
+    assert_eq!(user_data.start, Position::new(29, 3, 7));
+}
+
+#[test]
+fn spans_columns_count_characters() {
+    let input = "ÄÖÜ *x*";
+    let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+
+    let Block::Paragraph { content, .. } = &doc.blocks[0] else {
+        panic!("expected paragraph");
+    };
+    let Inline::Emphasis { user_data, .. } = &content[1] else {
+        panic!("expected emphasis");
+    };
+    assert_eq!(
+        *user_data,
+        Span::new(Position::new(7, 1, 5), Position::new(10, 1, 8))
+    );
+}
+
+#[test]
+fn spans_footnote_and_container() {
+    let input = "[^1]: note *a*\n   next\n\n:::warning\nbody\n:::\n";
+    let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+
+    let Block::FootnoteDefinition(footnote) = &doc.blocks[0] else {
+        panic!("expected footnote definition");
+    };
+    assert_eq!(
+        text_of(input, &footnote.user_data),
+        "[^1]: note *a*\n   next"
+    );
+    let Block::Paragraph { content, .. } = &footnote.blocks[0] else {
+        panic!("expected paragraph");
+    };
+    let Inline::Emphasis { user_data, .. } = &content[1] else {
+        panic!("expected emphasis");
+    };
+    assert_eq!(text_of(input, user_data), "*a*");
+
+    let Block::Container(container) = &doc.blocks[1] else {
+        panic!("expected container");
+    };
+    assert_eq!(
+        text_of(input, &container.user_data),
+        ":::warning\nbody\n:::"
+    );
+    let Block::Paragraph { user_data, .. } = &container.blocks[0] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(text_of(input, user_data), "body");
+}
+
+#[test]
+fn spans_front_matter() {
+    let input = "---\ntitle: x\n---\n\ntext";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_front_matter_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+    let Block::FrontMatter { user_data, .. } = &doc.blocks[0] else {
+        panic!("expected front matter");
+    };
+    assert_eq!(text_of(input, user_data), "---\ntitle: x\n---");
+    let Block::Paragraph { user_data, .. } = &doc.blocks[1] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(text_of(input, user_data), "text");
+}
+
+#[test]
+fn spans_definition_list_items() {
+    let input = "*Apple*\n: fruit\n\n: company\n\nPear\n: green";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_definition_list_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+
+    let Block::DefinitionList(list) = &doc.blocks[0] else {
+        panic!("expected definition list");
+    };
+    assert_eq!(text_of(input, &list.user_data), input);
+    assert_eq!(
+        text_of(input, &list.items[0].user_data),
+        "*Apple*\n: fruit\n\n: company"
+    );
+    assert_eq!(text_of(input, &list.items[1].user_data), "Pear\n: green");
+
+    let Inline::Emphasis { user_data, .. } = &list.items[0].term[0] else {
+        panic!("expected emphasis");
+    };
+    assert_eq!(text_of(input, user_data), "*Apple*");
+    let Block::Paragraph { user_data, .. } = &list.items[0].definitions[1][0] else {
+        panic!("expected paragraph");
+    };
+    assert_eq!(text_of(input, user_data), "company");
+}
+
+#[test]
+fn spans_figure_content_and_caption() {
+    let input = "| a |\n| - |\n| b |\nTable: *Results*";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_figure_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+
+    let Block::Figure {
+        content,
+        caption,
+        user_data,
+        ..
+    } = &doc.blocks[0]
+    else {
+        panic!("expected figure");
+    };
+    assert_eq!(text_of(input, user_data), input);
+    let Block::Table(table) = &content[0] else {
+        panic!("expected table");
+    };
+    assert_eq!(text_of(input, &table.user_data), "| a |\n| - |\n| b |");
+    let Inline::Emphasis { user_data, .. } = &caption[0] else {
+        panic!("expected emphasis");
+    };
+    assert_eq!(text_of(input, user_data), "*Results*");
+}
+
+#[test]
+fn spans_figure_caption_before_table() {
+    let input = "Table: *Results*\n\n| a |\n| - |\n| b |\n";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_figure_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+
+    let Block::Figure {
+        content, caption, ..
+    } = &doc.blocks[0]
+    else {
+        panic!("expected figure");
+    };
+    let Block::Table(table) = &content[0] else {
+        panic!("expected table");
+    };
+    assert_eq!(text_of(input, &table.user_data), "| a |\n| - |\n| b |");
+    let Inline::Emphasis { user_data, .. } = &caption[0] else {
+        panic!("expected emphasis");
+    };
+    assert_eq!(text_of(input, user_data), "*Results*");
+}
@@ -0,0 +1,211 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, BlockStream, MarkdownParserState, StreamError, StreamParser};
+use std::io::Read;
+
+const INPUT: &str = "# Title\n\nSome *text*\nmore — text\n\n- a\n- b\n\n  c\n\n> quote\nlazy\n\n```\ncode\n\n```\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\nSetext\n---\n\n[x]: /url\n\n[^n]: note\n    more\n\n    indented\n\nend\n";
+
+/// A reader that returns one byte per read
+struct ByteReader<'a>(&'a [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let Some((first, rest)) = self.0.split_first() else {
+            return Ok(0);
+        };
+        buf[0] = *first;
+        self.0 = rest;
+        Ok(1)
+    }
+}
+
+fn stream_blocks(reader: impl Read) -> Result<Vec<Block>, StreamError> {
+    BlockStream::new(MarkdownParserState::new(), reader).collect()
+}
+
+#[test]
+fn stream_matches_full_parse_at_every_split() {
+    let expected = parse_markdown(MarkdownParserState::new(), INPUT)
+        .unwrap()
+        .blocks;
+    for split in (0..=INPUT.len()).filter(|&i| INPUT.is_char_boundary(i)) {
+        let mut parser = StreamParser::new(MarkdownParserState::new());
+        let mut blocks = parser.feed(&INPUT[..split]).unwrap();
+        blocks.extend(parser.feed(&INPUT[split..]).unwrap());
+        blocks.extend(parser.finish().unwrap());
+        assert_eq!(blocks, expected, "split at {split}");
+    }
+}
+
+#[test]
+fn stream_yields_blocks_before_end_of_input() {
+    let mut parser = StreamParser::new(MarkdownParserState::new());
+    assert_eq!(parser.feed("# One\n\n").unwrap(), vec![]);
+    assert_eq!(parser.feed("Two\n\n").unwrap(), vec![]);
+    assert_eq!(
+        parser.feed("Three\n\n").unwrap(),
+        vec![Block::Heading(Heading {
+            kind: HeadingKind::Atx(1),
+            attributes: Attributes::default(),
+            content: vec![Inline::Text("One".to_owned())]
+        })]
+    );
+}
+
+#[test]
+fn stream_reads_byte_by_byte() {
+    let expected = parse_markdown(MarkdownParserState::new(), INPUT)
+        .unwrap()
+        .blocks;
+    let blocks = stream_blocks(ByteReader(INPUT.as_bytes())).unwrap();
+    assert_eq!(blocks, expected);
+    assert_eq!(stream_blocks("".as_bytes()).unwrap(), vec![]);
+}
+
+#[test]
+fn stream_invalid_utf8() {
+    let err = stream_blocks(&b"text\n\n\xff\n"[..]).unwrap_err();
+    assert!(matches!(err, StreamError::Io(e) if e.kind() == std::io::ErrorKind::InvalidData));
+}
+
+#[test]
+fn stream_error_position_in_whole_input() {
+    let mut parser = StreamParser::new(MarkdownParserState::new());
+    parser.feed("a\n\nb\n\nc\n\n").unwrap();
+    let err = parser.feed(":::x{y=}\n").unwrap_err();
+    assert_eq!((err.offset, err.line, err.column), (16, 7, 8));
+
+    let mut stream = BlockStream::new(MarkdownParserState::new(), &b"a\n\n:::x{y=}\n"[..]);
+    assert!(matches!(stream.next(), Some(Err(StreamError::Parse(_)))));
+    assert!(stream.next().is_none());
+}
+
+#[test]
+fn stream_deferred_resolution() {
+    let input = "Text[^n] and [link].\n\n[^n]: Note.\n\n[LINK]: /url\n";
+    let mut stream = BlockStream::new(MarkdownParserState::new(), input.as_bytes());
+
+    let first = stream.next().unwrap().unwrap();
+    assert!(!stream.definitions().is_resolved(&first));
+    let rest: Vec<_> = stream.by_ref().map(Result::unwrap).collect();
+    assert_eq!(rest.len(), 2);
+
+    let definitions = stream.definitions();
+    assert!(definitions.is_resolved(&first));
+    assert_eq!(
+        definitions
+            .link(&[Inline::Text("link".to_owned())])
+            .map(|d| d.destination.as_str()),
+        Some("/url")
+    );
+    assert!(definitions.footnote("n").is_some());
+}
+
+#[test]
+fn stream_front_matter_at_every_split() {
+    let state = || {
+        let config = MarkdownParserConfig::default()
+            .with_block_front_matter_behavior(ElementBehavior::Parse);
+        MarkdownParserState::with_config(config)
+    };
+    for input in ["---\na\n\nb\n\nc\n\nd\n---\ne\n", "---\na\n\nb\n\nc\n\nd\n"] {
+        let expected = parse_markdown(state(), input).unwrap().blocks;
+        for split in 0..=input.len() {
+            let mut parser = StreamParser::new(state());
+            let mut blocks = parser.feed(&input[..split]).unwrap();
+            blocks.extend(parser.feed(&input[split..]).unwrap());
+            blocks.extend(parser.finish().unwrap());
+            assert_eq!(blocks, expected, "split at {split}");
+        }
+    }
+}
+
+#[test]
+fn stream_fences_with_blank_lines_line_by_line() {
+    for input in [
+        "```\na\n\nb\n\nc\n\nd\n```\n",
+        "$$\na\n\nb\n\nc\n$$\n\ne\n",
+        ":::note\na\n\nb\n\nc\n:::\n",
+        "```\na\n\nb\n\nc\n\nd\n",
+    ] {
+        let expected = parse_markdown(MarkdownParserState::new(), input)
+            .unwrap()
+            .blocks;
+        let mut parser = StreamParser::new(MarkdownParserState::new());
+        let mut blocks = Vec::new();
+        for line in input.split_inclusive('\n') {
+            blocks.extend(parser.feed(line).unwrap());
+        }
+        blocks.extend(parser.finish().unwrap());
+        assert_eq!(blocks, expected, "{input:?}");
+    }
+}
+
+#[test]
+fn stream_yields_blocks_after_closed_fence() {
+    let mut parser = StreamParser::new(MarkdownParserState::new());
+    assert_eq!(parser.feed("```\na\n\nb\n\nc\n").unwrap(), vec![]);
+    assert_eq!(parser.feed("```\n\nd\n\ne\n\n").unwrap().len(), 1);
+}
+
+#[test]
+fn stream_yields_blocks_after_opener_in_prose() {
+    for opener in [
+        "Price is $$ cheap",
+        "Use \\[ here",
+        "Braces {{ here",
+        "A <!-- here",
+    ] {
+        let input = format!("{opener}\n\nSecond\n\nThird\n\nFourth\n");
+        let expected = parse_markdown(MarkdownParserState::new(), &input)
+            .unwrap()
+            .blocks;
+        let mut parser = StreamParser::new(MarkdownParserState::new());
+        let mut blocks = Vec::new();
+        for line in input.split_inclusive('\n') {
+            blocks.extend(parser.feed(line).unwrap());
+        }
+        assert_eq!(blocks[..], expected[..2], "{opener:?}");
+        blocks.extend(parser.finish().unwrap());
+        assert_eq!(blocks, expected, "{opener:?}");
+    }
+}
+
+#[test]
+fn stream_closing_line_split_across_feeds() {
+    let mut parser = StreamParser::new(MarkdownParserState::new());
+    assert_eq!(parser.feed("```\na\n\nb\n").unwrap(), vec![]);
+    assert_eq!(parser.feed("c\n``").unwrap(), vec![]);
+    assert_eq!(parser.feed("`\n\nd\n\ne\n\n").unwrap().len(), 1);
+}
+
+#[test]
+fn stream_matches_full_parse_for_random_input() {
+    use super::incremental::FRAGMENTS;
+    // xorshift, so that failures are reproducible
+    let mut seed = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = |bound: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % bound as u64) as usize
+    };
+    for _ in 0..500 {
+        let input: String = (0..next(16))
+            .map(|_| FRAGMENTS[next(FRAGMENTS.len())])
+            .collect();
+        let expected = parse_markdown(MarkdownParserState::new(), &input)
+            .unwrap()
+            .blocks;
+        let mut parser = StreamParser::new(MarkdownParserState::new());
+        let mut blocks = Vec::new();
+        let mut rest = input.as_str();
+        while !rest.is_empty() {
+            let (chunk, tail) = rest.split_at(next(rest.len()) + 1);
+            blocks.extend(parser.feed(chunk).unwrap());
+            rest = tail;
+        }
+        blocks.extend(parser.finish().unwrap());
+        assert_eq!(blocks, expected, "{input:?}");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::ast::*;
-use crate::parser::{parse_markdown, MarkdownParserState};
+use crate::parser::{parse_markdown, MarkdownParserConfig, MarkdownParserState};
 
 #[test]
 fn table1() {
@@ -44,7 +44,8 @@ fn table1() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -84,7 +85,8 @@ fn table_with_complex_spans() {
                         TableCell { content: vec![Inline::Text("F".to_owned())], colspan: None, rowspan: None, removed_by_extended_table: false },
                     ],
                 ],
-                alignments: vec![Alignment::Center, Alignment::Center, Alignment::Center]
+                alignments: vec![Alignment::Center, Alignment::Center, Alignment::Center],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -133,7 +135,8 @@ fn table2() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::Left, Alignment::Right]
+                alignments: vec![Alignment::Left, Alignment::Right],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -182,7 +185,8 @@ fn table3() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -246,7 +250,8 @@ fn table4() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -311,7 +316,8 @@ fn table5() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -376,7 +382,8 @@ fn table6() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -426,7 +433,8 @@ fn table7() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
@@ -511,8 +519,35 @@ fn table_with_merged_cells() {
                         }
                     ]
                 ],
-                alignments: vec![Alignment::None, Alignment::None, Alignment::None]
+                alignments: vec![Alignment::None, Alignment::None, Alignment::None],
+                attributes: Attributes::default(),
             })]
         }
     );
 }
+
+#[test]
+fn table_attributes() {
+    let config = MarkdownParserConfig::default().with_allow_attributes();
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "| a |\n| - |\n| b |\n{#results .wide}\n\nafter",
+    )
+    .unwrap();
+    let Block::Table(table) = &doc.blocks[0] else {
+        panic!("expected a table, got {:?}", doc.blocks[0]);
+    };
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(
+        table.attributes,
+        Attributes {
+            id: Some("results".to_owned()),
+            classes: vec!["wide".to_owned()],
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        doc.blocks[1],
+        Block::Paragraph(vec![Inline::Text("after".to_owned())])
+    );
+}
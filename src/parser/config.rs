@@ -10,16 +10,19 @@ type ElementMapFn<ELT> = Rc<RefCell<Box<dyn FnMut(ELT) -> ELT>>>;
 type ElementFlatMapFn<ELT> = Rc<RefCell<Box<dyn FnMut(ELT) -> Vec<ELT>>>>;
 
 /// Function type for custom block parsers.
-type CustomBlockParserFn =
+pub type CustomBlockParserFn =
     Rc<RefCell<Box<dyn for<'a> FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Block>>>>>;
 
 /// Function type for custom inline parsers.
-type CustomInlineParserFn =
+pub type CustomInlineParserFn =
     Rc<RefCell<Box<dyn for<'a> FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Inline>>>>>;
 
 /// Function type for replacing inline macros.
 pub type InlineMacroReplacerFn = Rc<RefCell<Box<dyn FnMut(&str) -> String>>>;
 
+/// Function type for resolving GitHub references to link destinations.
+pub type GitHubReferenceResolver = Rc<dyn Fn(&crate::ast::GitHubReference) -> Option<String>>;
+
 /// Behavior of the parser when encountering certain elements.
 #[derive(Clone)]
 pub enum ElementBehavior<ELT> {
@@ -40,15 +43,177 @@ pub enum ElementBehavior<ELT> {
     FlatMap(ElementFlatMapFn<ELT>),
 }
 
+/// A built-in block parser, used to order custom block parsers.
+///
+/// Built-in block parsers are tried in the order of the variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinBlockParser {
+    /// Fenced math blocks (e.g., ```` ```math ````).
+    LatexFence,
+    /// Fenced and indented code blocks.
+    CodeBlock,
+    /// ATX headings (e.g., `# Heading`).
+    HeadingV1,
+    /// Container blocks (e.g., `:::note`).
+    Container,
+    /// Macro blocks (e.g., `{{ macro }}`).
+    MacroBlock,
+    /// Thematic breaks (e.g., `---`).
+    ThematicBreak,
+    /// LaTeX blocks (e.g., `$$x^2$$`, `\[x^2\]`).
+    LatexBlock,
+    /// Definition lists (e.g., `Term\n: Definition`).
+    DefinitionList,
+    /// Figures (a standalone image, or a table with a `Table: caption` line).
+    Figure,
+    /// Setext headings (e.g., `Heading\n===`).
+    HeadingV2,
+    /// GitHub alerts (e.g., `> [!NOTE]`).
+    GitHubAlert,
+    /// Blockquotes.
+    Blockquote,
+    /// Lists.
+    List,
+    /// HTML blocks.
+    HtmlBlock,
+    /// Footnote definitions.
+    FootnoteDefinition,
+    /// Link definitions.
+    LinkDefinition,
+    /// Abbreviation definitions (e.g., `*[HTML]: HyperText Markup Language`).
+    AbbreviationDefinition,
+    /// Tables.
+    Table,
+    /// Paragraphs.
+    Paragraph,
+}
+
+/// A built-in inline parser, used to order custom inline parsers.
+///
+/// Built-in inline parsers are tried in the order of the variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinInlineParser {
+    /// Autolinks (e.g., `<https://example.com>`).
+    Autolink,
+    /// GFM extended autolinks (e.g., `https://example.com`, `www.example.com`).
+    ExtendedAutolink,
+    /// Wiki links (e.g., `[[Page|label]]`).
+    WikiLink,
+    /// Inline links.
+    Link,
+    /// Pandoc citations (e.g., `[see @doe99, p. 33]`, `@doe99`).
+    Citation,
+    /// Bracketed spans (e.g., `[text]{.class}`).
+    Span,
+    /// Footnote references (e.g., `[^1]`).
+    FootnoteReference,
+    /// Reference links.
+    ReferenceLink,
+    /// Hard line breaks.
+    HardNewline,
+    /// Images.
+    Image,
+    /// Code spans.
+    CodeSpan,
+    /// Environment variable names (e.g., `PKG_CONFIG_PATH`).
+    EnvironmentVariable,
+    /// Inline LaTeX (e.g., `$x^2$`, `\(x^2\)`).
+    Latex,
+    /// GitHub references (e.g., `@alice`, `#123`, `owner/repo#45`).
+    GitHubReference,
+    /// Emoji shortcodes (e.g., `:rocket:`).
+    Emoji,
+    /// Emphasis and strong emphasis.
+    Emphasis,
+    /// Strikethrough.
+    Strikethrough,
+    /// Superscript (e.g., `^2^`).
+    Superscript,
+    /// Subscript (e.g., `~2~`).
+    Subscript,
+    /// Highlighted text (e.g., `==text==`).
+    Highlight,
+    /// Inserted text (e.g., `++text++`).
+    Insert,
+    /// Text.
+    Text,
+}
+
+/// Position of a custom parser relative to a built-in parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParserPriority<P> {
+    /// The custom parser is tried right before the built-in parser.
+    Before(P),
+
+    /// The custom parser is tried right after the built-in parser.
+    After(P),
+}
+
+/// A custom parser registered under a name.
+#[derive(Clone)]
+pub(crate) struct NamedParser<P, F> {
+    /// The name the parser was registered with.
+    pub(crate) name: String,
+
+    /// Where the parser is tried.
+    pub(crate) priority: ParserPriority<P>,
+
+    /// The parser function.
+    pub(crate) parser: F,
+}
+
+/// Custom parsers, in registration order.
+type NamedParsers<P, F> = Vec<NamedParser<P, F>>;
+
+/// Name of the parser set with [`MarkdownParserConfig::with_custom_block_parser`] and
+/// [`MarkdownParserConfig::with_custom_inline_parser`].
+const DEFAULT_CUSTOM_PARSER: &str = "custom";
+
+/// Register `parser` under `name`, replacing the parser of the same name.
+fn register<P, F>(
+    mut parsers: NamedParsers<P, F>,
+    name: String,
+    priority: ParserPriority<P>,
+    parser: F,
+) -> NamedParsers<P, F> {
+    parsers.retain(|p| p.name != name);
+    parsers.push(NamedParser {
+        name,
+        priority,
+        parser,
+    });
+    parsers
+}
+
 /// A configuration for the Markdown parser.
 #[derive(Clone)]
 pub struct MarkdownParserConfig {
     /// If true, the parser will allow headings without a space after the hash marks.
     pub(crate) allow_no_space_in_headings: bool,
 
+    /// If true, a trailing `{#id}` in a heading sets the identifier of the heading.
+    pub(crate) allow_heading_ids: bool,
+
+    /// If true, attribute lists (`{#id .class key=value}`) are parsed on headings,
+    /// fenced code blocks, links and tables.
+    pub(crate) allow_attributes: bool,
+
+    /// If true, inline `$...$` math follows Pandoc's `tex_math_dollars` rules.
+    pub(crate) strict_dollar_math: bool,
+
     /// A map of HTML entities to their corresponding `Entity` structs.
     pub(crate) html_entities_map: HashMap<String, &'static entities::Entity>,
 
+    /// If true, emoji shortcodes become `Inline::Text` holding the emoji instead of
+    /// `Inline::Emoji` nodes.
+    pub(crate) emoji_as_text: bool,
+
+    /// A map of emoji shortcodes (without the colons) to emoji.
+    pub(crate) emoji_map: HashMap<String, String>,
+
+    /// Resolver giving GitHub references their link destination.
+    pub(crate) github_reference_resolver: Option<GitHubReferenceResolver>,
+
     /// The behavior of the parser when encountering blockquotes.
     pub(crate) block_blockquote_behavior: ElementBehavior<crate::ast::Block>,
 
@@ -79,6 +244,10 @@ pub struct MarkdownParserConfig {
     /// The behavior of the parser when encountering link definitions.
     pub(crate) block_link_definition_behavior: ElementBehavior<crate::ast::Block>,
 
+    /// The behavior of the parser when encountering abbreviation definitions
+    /// (e.g., `*[HTML]: HyperText Markup Language`).
+    pub(crate) block_abbreviation_definition_behavior: ElementBehavior<crate::ast::Block>,
+
     /// The behavior of the parser when encountering tables.
     pub(crate) block_table_behavior: ElementBehavior<crate::ast::Block>,
 
@@ -88,12 +257,63 @@ pub struct MarkdownParserConfig {
     /// The behavior of the parser when encountering container blocks.
     pub(crate) block_container_behavior: ElementBehavior<crate::ast::Block>,
 
+    /// The behavior of the parser when encountering definition lists
+    /// (e.g., `Term\n: Definition`).
+    pub(crate) block_definition_list_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering figures: a standalone image, or
+    /// a table with a caption line (e.g., `Table: caption`).
+    pub(crate) block_figure_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering front matter at the start of the
+    /// document (e.g., `---\ntitle: x\n---`).
+    pub(crate) block_front_matter_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering LaTeX blocks (e.g., `$$x^2$$`).
+    pub(crate) block_latex_block_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering LaTeX blocks in brackets
+    /// (e.g., `\[x^2\]`).
+    pub(crate) block_latex_brackets_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering fenced math blocks
+    /// (e.g., ```` ```math ````).
+    pub(crate) block_latex_fence_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering macro blocks (e.g., `{{ macro }}`).
+    pub(crate) block_macro_block_behavior: ElementBehavior<crate::ast::Block>,
+
     /// The behavior of the parser when encountering inline autolinks.
     pub(crate) inline_autolink_behavior: ElementBehavior<crate::ast::Inline>,
 
+    /// The behavior of the parser when encountering GFM extended autolinks
+    /// (e.g., `https://example.com`, `www.example.com` or `foo@example.com`).
+    pub(crate) inline_extended_autolink_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering wiki links (e.g., `[[Page|label]]`).
+    pub(crate) inline_wiki_link_behavior: ElementBehavior<crate::ast::Inline>,
+
     /// The behavior of the parser when encountering inline links.
     pub(crate) inline_link_behavior: ElementBehavior<crate::ast::Inline>,
 
+    /// The behavior of the parser when encountering citations (e.g., `[@doe99, p. 33]`).
+    pub(crate) inline_citation_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering bracketed spans (e.g., `[text]{.class}`).
+    pub(crate) inline_span_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering emoji shortcodes (e.g., `:rocket:`).
+    pub(crate) inline_emoji_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering mentions (e.g., `@alice`).
+    pub(crate) inline_mention_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering issue references (e.g., `#123`).
+    pub(crate) inline_issue_reference_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering commit references (e.g., `a5c3785`).
+    pub(crate) inline_commit_reference_behavior: ElementBehavior<crate::ast::Inline>,
+
     /// The behavior of the parser when encountering inline footnote references.
     pub(crate) inline_footnote_reference_behavior: ElementBehavior<crate::ast::Inline>,
 
@@ -115,14 +335,40 @@ pub struct MarkdownParserConfig {
     /// The behavior of the parser when encountering inline strikethrough.
     pub(crate) inline_strikethrough_behavior: ElementBehavior<crate::ast::Inline>,
 
+    /// The behavior of the parser when encountering superscript (e.g., `^2^`).
+    pub(crate) inline_superscript_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering subscript (e.g., `~2~`).
+    pub(crate) inline_subscript_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering highlighted text (e.g., `==text==`).
+    pub(crate) inline_highlight_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering inserted text (e.g., `++text++`).
+    pub(crate) inline_insert_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering inline LaTeX (e.g., `$x^2$`).
+    pub(crate) inline_latex_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering inline LaTeX in parentheses
+    /// (e.g., `\(x^2\)`).
+    pub(crate) inline_latex_parentheses_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering inline code math (e.g., `` $`x^2`$ ``).
+    pub(crate) inline_latex_code_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering environment variable names
+    /// (e.g., `PKG_CONFIG_PATH`), which are kept as text instead of being parsed as emphasis.
+    pub(crate) inline_environment_variable_behavior: ElementBehavior<crate::ast::Inline>,
+
     /// The behavior of the parser when encountering inline text.
     pub(crate) inline_text_behavior: ElementBehavior<crate::ast::Inline>,
 
-    /// A custom parser for blocks. This is a function that takes a string and returns a `Block`.
-    pub(crate) custom_block_parser: Option<CustomBlockParserFn>,
+    /// Custom parsers for blocks, in registration order.
+    pub(crate) custom_block_parsers: NamedParsers<BuiltinBlockParser, CustomBlockParserFn>,
 
-    /// A custom parser for inlines. This is a function that takes a string and returns a `Inline`.
-    pub(crate) custom_inline_parser: Option<CustomInlineParserFn>,
+    /// Custom parsers for inlines, in registration order.
+    pub(crate) custom_inline_parsers: NamedParsers<BuiltinInlineParser, CustomInlineParserFn>,
 
     /// A function that replaces inline macros.
     pub(crate) inline_macro_replacer: Option<InlineMacroReplacerFn>,
@@ -132,7 +378,13 @@ impl Default for MarkdownParserConfig {
     fn default() -> Self {
         Self {
             allow_no_space_in_headings: false,
+            allow_heading_ids: false,
+            allow_attributes: false,
+            strict_dollar_math: false,
             html_entities_map: Self::make_html_entities_map(),
+            emoji_as_text: false,
+            emoji_map: Self::make_emoji_map(),
+            github_reference_resolver: None,
             block_blockquote_behavior: ElementBehavior::Parse,
             block_github_alert_behavior: ElementBehavior::Parse,
             block_heading_v1_behavior: ElementBehavior::Parse,
@@ -143,11 +395,27 @@ impl Default for MarkdownParserConfig {
             block_html_block_behavior: ElementBehavior::Parse,
             block_footnote_definition_behavior: ElementBehavior::Parse,
             block_link_definition_behavior: ElementBehavior::Parse,
+            block_abbreviation_definition_behavior: ElementBehavior::Ignore,
             block_table_behavior: ElementBehavior::Parse,
             block_paragraph_behavior: ElementBehavior::Parse,
             block_container_behavior: ElementBehavior::Parse,
+            block_definition_list_behavior: ElementBehavior::Ignore,
+            block_figure_behavior: ElementBehavior::Ignore,
+            block_front_matter_behavior: ElementBehavior::Ignore,
+            block_latex_block_behavior: ElementBehavior::Parse,
+            block_latex_brackets_behavior: ElementBehavior::Ignore,
+            block_latex_fence_behavior: ElementBehavior::Ignore,
+            block_macro_block_behavior: ElementBehavior::Parse,
             inline_autolink_behavior: ElementBehavior::Parse,
+            inline_extended_autolink_behavior: ElementBehavior::Ignore,
+            inline_wiki_link_behavior: ElementBehavior::Ignore,
             inline_link_behavior: ElementBehavior::Parse,
+            inline_citation_behavior: ElementBehavior::Ignore,
+            inline_span_behavior: ElementBehavior::Ignore,
+            inline_emoji_behavior: ElementBehavior::Ignore,
+            inline_mention_behavior: ElementBehavior::Ignore,
+            inline_issue_reference_behavior: ElementBehavior::Ignore,
+            inline_commit_reference_behavior: ElementBehavior::Ignore,
             inline_footnote_reference_behavior: ElementBehavior::Parse,
             inline_reference_link_behavior: ElementBehavior::Parse,
             inline_hard_newline_behavior: ElementBehavior::Parse,
@@ -155,9 +423,17 @@ impl Default for MarkdownParserConfig {
             inline_code_span_behavior: ElementBehavior::Parse,
             inline_emphasis_behavior: ElementBehavior::Parse,
             inline_strikethrough_behavior: ElementBehavior::Parse,
+            inline_superscript_behavior: ElementBehavior::Ignore,
+            inline_subscript_behavior: ElementBehavior::Ignore,
+            inline_highlight_behavior: ElementBehavior::Ignore,
+            inline_insert_behavior: ElementBehavior::Ignore,
+            inline_latex_behavior: ElementBehavior::Parse,
+            inline_latex_parentheses_behavior: ElementBehavior::Ignore,
+            inline_latex_code_behavior: ElementBehavior::Ignore,
+            inline_environment_variable_behavior: ElementBehavior::Parse,
             inline_text_behavior: ElementBehavior::Parse,
-            custom_block_parser: None,
-            custom_inline_parser: None,
+            custom_block_parsers: Vec::new(),
+            custom_inline_parsers: Vec::new(),
             inline_macro_replacer: None,
         }
     }
@@ -172,6 +448,13 @@ impl MarkdownParserConfig {
         map
     }
 
+    fn make_emoji_map() -> HashMap<String, String> {
+        crate::parser::inline::emoji::GITHUB_EMOJI
+            .iter()
+            .map(|(shortcode, glyph)| (shortcode.to_string(), glyph.to_string()))
+            .collect()
+    }
+
     /// Enable the parser to allow headings without a space after the hash marks.
     pub fn with_allow_no_space_in_headings(self) -> Self {
         Self {
@@ -180,6 +463,39 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Enable the parser to take a trailing `{#id}` in a heading as the identifier of the
+    /// heading, e.g. `# Installation {#install}`.
+    ///
+    /// The braces may hold a full attribute list, as with [`Self::with_allow_attributes`].
+    pub fn with_allow_heading_ids(self) -> Self {
+        Self {
+            allow_heading_ids: true,
+            ..self
+        }
+    }
+
+    /// Enable attribute lists (`{#id .class key=value key="quoted value"}`) at the end of
+    /// headings, after the info string of fenced code blocks, right after links, and on
+    /// the line following a table.
+    ///
+    /// Images always accept an attribute list, e.g. `![alt](/img.png){width=50%}`.
+    pub fn with_allow_attributes(self) -> Self {
+        Self {
+            allow_attributes: true,
+            ..self
+        }
+    }
+
+    /// Enable Pandoc's `tex_math_dollars` rules for inline `$...$` math: the opening `$`
+    /// must not be followed by whitespace, and the closing `$` must not be preceded by
+    /// whitespace or followed by a digit. With these rules, `$5 and $10` is text.
+    pub fn with_strict_dollar_math(self) -> Self {
+        Self {
+            strict_dollar_math: true,
+            ..self
+        }
+    }
+
     /// Set a custom map of HTML entities.
     pub fn with_html_entities_map(
         self,
@@ -191,6 +507,51 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Replace emoji shortcodes with text holding the emoji instead of
+    /// [`Inline::Emoji`](crate::ast::Inline::Emoji) nodes.
+    ///
+    /// The Markdown printer then writes the emoji rather than the shortcode.
+    pub fn with_emoji_as_text(self) -> Self {
+        Self {
+            emoji_as_text: true,
+            ..self
+        }
+    }
+
+    /// Set a custom map of emoji shortcodes (without the colons) to emoji, replacing the
+    /// built-in table of commonly used GitHub shortcodes, e.g. with the full gemoji
+    /// database.
+    pub fn with_emoji_map(self, emoji_map: HashMap<String, String>) -> Self {
+        Self { emoji_map, ..self }
+    }
+
+    /// Add an emoji shortcode to the table, e.g. `with_emoji("shipit", "🐿️")`.
+    pub fn with_emoji(mut self, shortcode: impl Into<String>, glyph: impl Into<String>) -> Self {
+        self.emoji_map.insert(shortcode.into(), glyph.into());
+        self
+    }
+
+    /// Set a resolver giving GitHub references their link destination, e.g.
+    ///
+    /// ```rust
+    /// use markdown_ppp::parser::config::{ElementBehavior, MarkdownParserConfig};
+    /// use std::rc::Rc;
+    ///
+    /// let config = MarkdownParserConfig::default()
+    ///     .with_inline_issue_reference_behavior(ElementBehavior::Parse)
+    ///     .with_github_reference_resolver(Rc::new(|reference| {
+    ///         Some(reference.github_url("owner/repo"))
+    ///     }));
+    /// ```
+    ///
+    /// References the resolver returns `None` for keep no destination.
+    pub fn with_github_reference_resolver(self, resolver: GitHubReferenceResolver) -> Self {
+        Self {
+            github_reference_resolver: Some(resolver),
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering blockquotes.
     pub fn with_block_blockquote_behavior(
         self,
@@ -298,6 +659,22 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering abbreviation definitions
+    /// (e.g., `*[HTML]: HyperText Markup Language`).
+    ///
+    /// Abbreviation definitions are not parsed by default ([`ElementBehavior::Ignore`]).
+    /// Words matching a definition are marked up by the `AbbreviationResolver` of
+    /// `ast_transform`.
+    pub fn with_block_abbreviation_definition_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_abbreviation_definition_behavior: behavior,
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering tables.
     pub fn with_block_table_behavior(self, behavior: ElementBehavior<crate::ast::Block>) -> Self {
         Self {
@@ -328,6 +705,100 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering definition lists
+    /// (e.g., `Term\n: Definition`).
+    ///
+    /// Definition lists are not parsed by default ([`ElementBehavior::Ignore`]); use
+    /// [`ElementBehavior::Parse`] to enable them.
+    pub fn with_block_definition_list_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_definition_list_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering figures.
+    ///
+    /// Figures are not parsed by default ([`ElementBehavior::Ignore`]). Once enabled,
+    /// a paragraph holding nothing but an image with a title or alt text becomes a
+    /// figure captioned by the title, or else the alt text. A table becomes a figure
+    /// when the line before or after it is a `Table: caption` or `: caption` line; a
+    /// blank line may separate the caption from the table. The `{#id}` of the image
+    /// or table is the label of the figure.
+    pub fn with_block_figure_behavior(self, behavior: ElementBehavior<crate::ast::Block>) -> Self {
+        Self {
+            block_figure_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering front matter at the start of the
+    /// document (e.g., `---\ntitle: x\n---`).
+    ///
+    /// Front matter is not parsed by default ([`ElementBehavior::Ignore`]): the fences
+    /// are parsed as ordinary Markdown, as CommonMark does. The opening fence cannot be
+    /// followed by a blank line.
+    pub fn with_block_front_matter_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_front_matter_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering LaTeX blocks (e.g., `$$x^2$$`).
+    pub fn with_block_latex_block_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_latex_block_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering LaTeX blocks in brackets
+    /// (e.g., `\[x^2\]`).
+    pub fn with_block_latex_brackets_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_latex_brackets_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering fenced math blocks
+    /// (e.g., ```` ```math ````).
+    ///
+    /// With [`ElementBehavior::Ignore`], they are parsed as code blocks.
+    pub fn with_block_latex_fence_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_latex_fence_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering macro blocks (e.g., `{{ macro }}`).
+    pub fn with_block_macro_block_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_macro_block_behavior: behavior,
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering inline autolinks.
     pub fn with_inline_autolink_behavior(
         self,
@@ -339,6 +810,36 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering GFM extended autolinks: bare
+    /// `https://example.com`, `www.example.com` and `foo@example.com`.
+    ///
+    /// They are parsed into [`Inline::Autolink`](crate::ast::Inline::Autolink) with
+    /// `bare` set; use [`ElementBehavior::Map`] to turn them into links instead.
+    pub fn with_inline_extended_autolink_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_extended_autolink_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering wiki links (e.g., `[[Page]]`,
+    /// `[[Page#Section|label]]` or the embed `![[Page]]`).
+    ///
+    /// Wiki links are not parsed by default ([`ElementBehavior::Ignore`]); use
+    /// [`ElementBehavior::Parse`] to enable them.
+    pub fn with_inline_wiki_link_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_wiki_link_behavior: behavior,
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering inline links.
     pub fn with_inline_link_behavior(self, behavior: ElementBehavior<crate::ast::Inline>) -> Self {
         Self {
@@ -347,6 +848,93 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering Pandoc citations (e.g.,
+    /// `[see @doe99, p. 33; -@smith04]`, `@doe99` or `@doe99 [p. 33]`).
+    ///
+    /// Citations are not parsed by default ([`ElementBehavior::Ignore`]). In-text
+    /// citations only start a word, so `foo@example.com` stays text, are not
+    /// recognized in link text and labels, and take precedence over mentions when
+    /// both are enabled.
+    pub fn with_inline_citation_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_citation_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering bracketed spans (e.g.,
+    /// `[Ctrl]{.kbd}` or `[text]{#id lang=fr}`).
+    ///
+    /// Spans are not parsed by default ([`ElementBehavior::Ignore`]); use
+    /// [`ElementBehavior::Parse`] to enable them. The attribute list must follow the
+    /// closing bracket directly, so `[text] {.class}` stays a reference link.
+    pub fn with_inline_span_behavior(self, behavior: ElementBehavior<crate::ast::Inline>) -> Self {
+        Self {
+            inline_span_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering emoji shortcodes (e.g., `:rocket:`).
+    ///
+    /// Only shortcodes found in the emoji table are parsed; others stay text. The
+    /// built-in table holds over 300 commonly used GitHub shortcodes, not all of
+    /// GitHub's: add missing ones with [`with_emoji`](Self::with_emoji) or replace
+    /// the table with [`with_emoji_map`](Self::with_emoji_map).
+    pub fn with_inline_emoji_behavior(self, behavior: ElementBehavior<crate::ast::Inline>) -> Self {
+        Self {
+            inline_emoji_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering mentions (e.g., `@alice` or
+    /// `@org/team`).
+    ///
+    /// Mentions are not parsed by default ([`ElementBehavior::Ignore`]). They only
+    /// start a word, so `foo@example.com` stays text.
+    pub fn with_inline_mention_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_mention_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering issue references (e.g., `#123`
+    /// or `owner/repo#45`).
+    ///
+    /// Issue references are not parsed by default ([`ElementBehavior::Ignore`]).
+    pub fn with_inline_issue_reference_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_issue_reference_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering commit references: a SHA of 7 to
+    /// 40 lowercase hex digits (e.g., `a5c3785`) or `owner/repo@a5c3785`.
+    ///
+    /// Commit references are not parsed by default ([`ElementBehavior::Ignore`]). A SHA
+    /// needs both a letter and a digit, so words like `deadbeef` stay text.
+    pub fn with_inline_commit_reference_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_commit_reference_behavior: behavior,
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering inline footnote references.
     pub fn with_inline_footnote_reference_behavior(
         self,
@@ -421,6 +1009,112 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering superscript (e.g., `2^10^`).
+    ///
+    /// Superscript is not parsed by default ([`ElementBehavior::Ignore`]). As in Pandoc, the
+    /// content cannot hold unescaped whitespace, so `x^2 + y^2` stays text.
+    pub fn with_inline_superscript_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_superscript_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering subscript (e.g., `H~2~O`).
+    ///
+    /// Subscript is not parsed by default ([`ElementBehavior::Ignore`]). The content cannot
+    /// hold unescaped whitespace, and `~~` still opens a strikethrough.
+    pub fn with_inline_subscript_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_subscript_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering highlighted text (e.g., `==text==`).
+    ///
+    /// Highlighting is not parsed by default ([`ElementBehavior::Ignore`]). The content
+    /// cannot start or end with whitespace, so `a == b` stays text.
+    pub fn with_inline_highlight_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_highlight_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering inserted text (e.g., `++text++`).
+    ///
+    /// Insertions are not parsed by default ([`ElementBehavior::Ignore`]). The content
+    /// cannot start or end with whitespace, so `C++ and C++` stays text.
+    pub fn with_inline_insert_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_insert_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering inline LaTeX (e.g., `$x^2$`).
+    ///
+    /// With [`ElementBehavior::Ignore`], dollar signs are plain text.
+    pub fn with_inline_latex_behavior(self, behavior: ElementBehavior<crate::ast::Inline>) -> Self {
+        Self {
+            inline_latex_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering inline LaTeX in parentheses
+    /// (e.g., `\(x^2\)`).
+    ///
+    /// With [`ElementBehavior::Ignore`], `\(` stays text.
+    pub fn with_inline_latex_parentheses_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_latex_parentheses_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering inline code math
+    /// (e.g., `` $`x^2`$ ``).
+    pub fn with_inline_latex_code_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_latex_code_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering environment variable names
+    /// (e.g., `PKG_CONFIG_PATH`).
+    ///
+    /// With [`ElementBehavior::Ignore`], underscores in such names may start emphasis.
+    pub fn with_inline_environment_variable_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_environment_variable_behavior: behavior,
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering inline text.
     pub fn with_inline_text_behavior(self, behavior: ElementBehavior<crate::ast::Inline>) -> Self {
         Self {
@@ -430,21 +1124,94 @@ impl MarkdownParserConfig {
     }
 
     /// Set a custom parser for blocks.
+    ///
+    /// The parser is tried after link definitions and before tables. This is a shorthand for
+    /// [`Self::with_named_custom_block_parser`] with the name `"custom"`.
     pub fn with_custom_block_parser(self, parser: CustomBlockParserFn) -> Self {
+        self.with_named_custom_block_parser(
+            DEFAULT_CUSTOM_PARSER,
+            ParserPriority::After(BuiltinBlockParser::LinkDefinition),
+            parser,
+        )
+    }
+
+    /// Set a custom parser for inlines.
+    ///
+    /// The parser is tried right before text. This is a shorthand for
+    /// [`Self::with_named_custom_inline_parser`] with the name `"custom"`.
+    pub fn with_custom_inline_parser(self, parser: CustomInlineParserFn) -> Self {
+        self.with_named_custom_inline_parser(
+            DEFAULT_CUSTOM_PARSER,
+            ParserPriority::After(BuiltinInlineParser::Insert),
+            parser,
+        )
+    }
+
+    /// Register a custom parser for blocks under `name`, tried at `priority`.
+    ///
+    /// A parser registered earlier under the same name is replaced. Parsers registered at the
+    /// same priority are tried in registration order.
+    pub fn with_named_custom_block_parser(
+        self,
+        name: impl Into<String>,
+        priority: ParserPriority<BuiltinBlockParser>,
+        parser: CustomBlockParserFn,
+    ) -> Self {
         Self {
-            custom_block_parser: Some(parser),
+            custom_block_parsers: register(
+                self.custom_block_parsers,
+                name.into(),
+                priority,
+                parser,
+            ),
             ..self
         }
     }
 
-    /// Set a custom parser for inlines.
-    pub fn with_custom_inline_parser(self, parser: CustomInlineParserFn) -> Self {
+    /// Register a custom parser for inlines under `name`, tried at `priority`.
+    ///
+    /// A parser registered earlier under the same name is replaced. Parsers registered at the
+    /// same priority are tried in registration order. Text stops in front of any input a
+    /// custom inline parser accepts.
+    pub fn with_named_custom_inline_parser(
+        self,
+        name: impl Into<String>,
+        priority: ParserPriority<BuiltinInlineParser>,
+        parser: CustomInlineParserFn,
+    ) -> Self {
         Self {
-            custom_inline_parser: Some(parser),
+            custom_inline_parsers: register(
+                self.custom_inline_parsers,
+                name.into(),
+                priority,
+                parser,
+            ),
             ..self
         }
     }
 
+    /// Remove the custom block parser registered under `name`.
+    pub fn without_custom_block_parser(mut self, name: &str) -> Self {
+        self.custom_block_parsers.retain(|p| p.name != name);
+        self
+    }
+
+    /// Remove the custom inline parser registered under `name`.
+    pub fn without_custom_inline_parser(mut self, name: &str) -> Self {
+        self.custom_inline_parsers.retain(|p| p.name != name);
+        self
+    }
+
+    /// Names of the registered custom block parsers, in registration order.
+    pub fn custom_block_parser_names(&self) -> impl Iterator<Item = &str> {
+        self.custom_block_parsers.iter().map(|p| p.name.as_str())
+    }
+
+    /// Names of the registered custom inline parsers, in registration order.
+    pub fn custom_inline_parser_names(&self) -> impl Iterator<Item = &str> {
+        self.custom_inline_parsers.iter().map(|p| p.name.as_str())
+    }
+
     /// Set a function that replaces inline macros.
     pub fn with_inline_macro_replacer(self, replacer: InlineMacroReplacerFn) -> Self {
         Self {
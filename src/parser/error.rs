@@ -0,0 +1,80 @@
+//! Errors reported by the Markdown parser
+
+use std::fmt;
+
+/// An error encountered while parsing Markdown
+///
+/// Errors point at the place in the input where parsing failed and name the
+/// construct that was being parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::parser::{parse_markdown, MarkdownParserState};
+///
+/// let err = parse_markdown(MarkdownParserState::new(), "Intro\n\n:::note{kind=}\n:::\n")
+///     .unwrap_err();
+/// assert_eq!((err.line, err.column), (3, 14));
+/// assert_eq!(err.construct, "container block");
+/// assert_eq!(err.to_string(), "3:14: container block: expected a value after `=`");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseError {
+    /// Byte offset of the error in the input
+    pub offset: usize,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number in characters, starting at 1
+    pub column: usize,
+    /// The construct being parsed
+    ///
+    /// One of `"container block"` for malformed container parameters,
+    /// `"custom block"` or `"custom inline"` for a custom parser that
+    /// returned [`nom::Err::Failure`], or `"block"` when no block parser
+    /// accepts a line. Other malformed Markdown is parsed as text, as
+    /// CommonMark requires, and does not produce errors.
+    pub construct: String,
+    /// Short description of the problem
+    pub message: String,
+}
+
+impl ParseError {
+    /// Create an error at `offset` in `input`, computing its line and column
+    pub fn new(
+        input: &str,
+        offset: usize,
+        construct: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        let before = input.get(..offset).unwrap_or(input);
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            construct: construct.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Move an error found in text that starts `offset` bytes and `lines`
+    /// lines into the input, at the start of a line
+    pub(crate) fn shifted(mut self, offset: usize, lines: usize) -> Self {
+        self.offset += offset;
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}: {}",
+            self.line, self.column, self.construct, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
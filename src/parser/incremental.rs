@@ -0,0 +1,364 @@
+//! Incremental reparsing of edited Markdown
+//!
+//! [`IncrementalDocument`] keeps the source text of a document together with
+//! the byte range consumed by every top-level call of the block parser. When
+//! the text is edited, parsing restarts at the block boundary before the
+//! first block touched by the edit and stops as soon as it reaches a block
+//! boundary of the previous parse past the edit. Blocks outside that window
+//! are reused, so the result is the same [`Document`] a full parse of the
+//! new text produces.
+//!
+//! A fence without its closing line (```` ``` ````, `:::`, `<!--`, ...) is
+//! parsed as text, but a closing line added anywhere after it turns all
+//! blocks in between into a single block. Parsing therefore restarts at the
+//! earliest such fence before the edit. Display math (`$$`, `\[`) and macro
+//! blocks (`{{`) cannot span a blank line, so their openers only matter up to
+//! the next one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::parser::{IncrementalDocument, MarkdownParserState, TextEdit};
+//!
+//! let mut doc = IncrementalDocument::parse(MarkdownParserState::new(), "# Title\n\nHello\n")
+//!     .unwrap();
+//! doc.apply_edit(&TextEdit::new(9..14, "*Bye*")).unwrap();
+//!
+//! assert_eq!(doc.source(), "# Title\n\n*Bye*\n");
+//! assert_eq!(
+//!     doc.document().blocks[1],
+//!     Block::Paragraph(vec![Inline::Emphasis(vec![Inline::Text("Bye".to_string())])])
+//! );
+//! ```
+
+use super::{next_block, source_map::SpanTracker, MarkdownParserState, ParseError};
+use crate::ast::{Block, Document};
+use crate::parser::blocks::front_matter::front_matter_len;
+use crate::parser::config::MarkdownParserConfig;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Number of blocks before the first edited one that are parsed again
+///
+/// A block parser looks past the end of its block to decide where the block
+/// ends, e.g. to check whether the next line continues a paragraph or list.
+/// An edit at the start of a block can therefore change the block before it.
+pub(super) const LOOKBEHIND_BLOCKS: usize = 1;
+
+/// A replacement of a byte range of the source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Byte range of the replaced text in the old source
+    pub range: Range<usize>,
+    /// Text inserted in place of the range
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Create an edit replacing `range` with `replacement`
+    pub fn new(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+}
+
+/// The source text consumed by one call of the top-level block parser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Chunk {
+    /// Byte offset of the first consumed character, including empty lines
+    pub(super) start: usize,
+    /// Byte offset past the last consumed character
+    pub(super) end: usize,
+    /// Number of blocks produced
+    pub(super) blocks: usize,
+    /// The fence without its closing line the text holds, see [`open_fence`]
+    pub(super) open_fence: Option<OpenFence>,
+}
+
+impl Chunk {
+    /// Whether text after `source[..upto]` may still close a fence of the chunk
+    pub(super) fn is_open(&self, source: &str, upto: usize) -> bool {
+        match self.open_fence {
+            None => false,
+            Some(OpenFence::Unbounded) => true,
+            Some(OpenFence::UntilBlankLine) => {
+                let line_start = source[..self.end].rfind('\n').map_or(0, |i| i + 1);
+                !source[line_start..upto]
+                    .split_inclusive('\n')
+                    .any(is_blank_line)
+            }
+        }
+    }
+}
+
+/// A fence in a chunk that text after the chunk may still close
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum OpenFence {
+    /// A code fence, container or HTML comment, closed anywhere after it
+    Unbounded,
+    /// Display math or a macro block, which cannot span a blank line
+    UntilBlankLine,
+}
+
+/// A parsed document that can be updated by text edits
+///
+/// An edit reparses only the top-level blocks around it, from the block
+/// boundary before it (or the earliest unclosed fence before it) to the first
+/// unchanged block boundary after it. The result is always the document a
+/// full parse of the edited text produces.
+pub struct IncrementalDocument {
+    config: Rc<MarkdownParserConfig>,
+    source: String,
+    document: Document,
+    chunks: Vec<Chunk>,
+}
+
+impl IncrementalDocument {
+    /// Parse `input` with the configuration of `state`
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse_markdown`](super::parse_markdown).
+    pub fn parse(state: MarkdownParserState, input: impl Into<String>) -> Result<Self, ParseError> {
+        let source = input.into();
+        let (blocks, chunks) = parse_chunks(&state.config, &source, 0, true, |_| false)?;
+        Ok(Self {
+            config: state.config,
+            source,
+            document: Document { blocks },
+            chunks,
+        })
+    }
+
+    /// The current source text
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The document parsed from the current source text
+    pub fn document(&self) -> &Document {
+        &self.document
+    }
+
+    /// Consume the incremental document, returning the parsed document
+    pub fn into_document(self) -> Document {
+        self.document
+    }
+
+    /// Apply `edit` to the source text and reparse the affected blocks
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edited text cannot be parsed. The document
+    /// and its source are left unchanged in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of `edit` is out of bounds or does not lie on
+    /// `char` boundaries.
+    pub fn apply_edit(&mut self, edit: &TextEdit) -> Result<(), ParseError> {
+        let mut source = self.source.clone();
+        source.replace_range(edit.range.clone(), &edit.replacement);
+        let new_edit_end = edit.range.start + edit.replacement.len();
+        let shift = |offset: usize| offset + new_edit_end - edit.range.end;
+
+        // The first chunk that may change is the one containing the start of
+        // the edit, or ending right at it.
+        let touched = self.chunks.partition_point(|c| c.end < edit.range.start);
+        let mut first = touched.saturating_sub(LOOKBEHIND_BLOCKS);
+        let mut restart = self.chunks.get(first).map_or(0, |c| c.start);
+        // An edit that closes a front matter block changes all blocks
+        // between its fences.
+        if front_matter_len(&self.config, &source).is_some_and(|len| len > restart) {
+            (first, restart) = (0, 0);
+        }
+        // So does an edit that closes a fence opened before it.
+        if let Some(open) = self.chunks[..first]
+            .iter()
+            .position(|c| c.is_open(&source, restart))
+        {
+            (first, restart) = (open, self.chunks[open].start);
+        }
+
+        // Stop at the first old chunk past the edit that starts where a new
+        // chunk ends: parsing from there sees the same text as before.
+        let mut last = self.chunks.len();
+        let (blocks, chunks) = parse_chunks(&self.config, &source, restart, true, |end| {
+            if end < new_edit_end {
+                return false;
+            }
+            let old = end + edit.range.end - new_edit_end;
+            match self.chunks[touched..].binary_search_by_key(&old, |c| c.start) {
+                Ok(i) => {
+                    last = touched + i;
+                    true
+                }
+                Err(_) => false,
+            }
+        })?;
+
+        let first_block = self.chunks[..first].iter().map(|c| c.blocks).sum::<usize>();
+        let replaced_blocks = self.chunks[first..last]
+            .iter()
+            .map(|c| c.blocks)
+            .sum::<usize>();
+        self.document
+            .blocks
+            .splice(first_block..first_block + replaced_blocks, blocks);
+
+        let tail: Vec<_> = self.chunks[last..]
+            .iter()
+            .map(|c| Chunk {
+                start: shift(c.start),
+                end: shift(c.end),
+                ..*c
+            })
+            .collect();
+        self.chunks.splice(first.., chunks.into_iter().chain(tail));
+        self.source = source;
+        Ok(())
+    }
+}
+
+/// Parse top-level blocks of `input` starting at byte offset `start`
+///
+/// Front matter is parsed when `start` is 0 and `input` starts the
+/// document. After every other chunk `stop` is called with the offset past
+/// it. Parsing stops when it returns `true`, or when only whitespace is left.
+pub(super) fn parse_chunks(
+    config: &Rc<MarkdownParserConfig>,
+    input: &str,
+    start: usize,
+    document_start: bool,
+    mut stop: impl FnMut(usize) -> bool,
+) -> Result<(Vec<Block>, Vec<Chunk>), ParseError> {
+    let tracker = SpanTracker::new(input, false);
+    let state = MarkdownParserState {
+        config: config.clone(),
+        is_nested_block_context: false,
+        containers: Vec::new(),
+        span_tracker: Some(tracker.clone()),
+        is_link_text: false,
+    };
+    let state = Rc::new(state);
+    let mut block = crate::parser::blocks::block(state.clone());
+
+    let mut blocks = Vec::new();
+    let mut chunks = Vec::new();
+    let mut rest = &input[start..];
+    if start == 0 && document_start {
+        if let Ok((remaining, parsed)) = crate::parser::blocks::front_matter_block(state)(input) {
+            chunks.push(Chunk {
+                start: 0,
+                end: input.len() - remaining.len(),
+                blocks: parsed.len(),
+                open_fence: None,
+            });
+            blocks.extend(parsed);
+            rest = remaining;
+        }
+    }
+    while let Some(result) = next_block(&mut block, &tracker, input, rest) {
+        let (remaining, parsed) = result?;
+        let chunk = Chunk {
+            start: input.len() - rest.len(),
+            end: input.len() - remaining.len(),
+            blocks: parsed.len(),
+            open_fence: open_fence(&rest[..rest.len() - remaining.len()]),
+        };
+        blocks.extend(parsed);
+        chunks.push(chunk);
+        rest = remaining;
+        if stop(chunk.end) {
+            break;
+        }
+    }
+    Ok((blocks, chunks))
+}
+
+/// The fence without its closing line that `text` holds
+///
+/// The block parsers look for the closing line up to the end of the input,
+/// and parse the opening line as text when there is none. The check is
+/// conservative: fences are looked for at the start of every line, also
+/// inside containers and list items, and a fence is open when no closing line
+/// follows its opening line.
+pub(super) fn open_fence(text: &str) -> Option<OpenFence> {
+    // The opening fence of the block that is open after each line
+    let mut open: Option<&str> = None;
+    for line in text.lines() {
+        let content = line_content(line);
+        open = match open {
+            None => opening_fence(content),
+            Some("<!--") => (!line.contains("-->")).then_some("<!--"),
+            // A `:::` ends the container, or fails it when the line opens
+            // another one
+            Some(":::") => {
+                (!line.contains(":::") || opening_fence(content) == Some(":::")).then_some(":::")
+            }
+            Some(fence) => {
+                let closes = code_fence(content)
+                    .is_some_and(|f| f.starts_with(fence) && f.len() == content.len());
+                (!closes).then_some(fence)
+            }
+        };
+    }
+    if open.is_some() {
+        return Some(OpenFence::Unbounded);
+    }
+
+    // Display math and macro blocks end at the first blank line
+    let mut tail_start = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        offset += line.len();
+        if is_blank_line(line) {
+            tail_start = offset;
+        }
+    }
+    let tail = &text[tail_start..];
+    let unpaired = |open: &str, close: &str| {
+        tail.rfind(open)
+            .is_some_and(|i| !tail[i + open.len()..].contains(close))
+    };
+    (tail.matches("$$").count() % 2 == 1 || unpaired("\\[", "\\]") || unpaired("{{", "}}"))
+        .then_some(OpenFence::UntilBlankLine)
+}
+
+/// Whether `line` may close an unbounded fence opened before it
+pub(super) fn may_close_fence(line: &str) -> bool {
+    code_fence(line_content(line)).is_some() || line.contains(":::") || line.contains("-->")
+}
+
+/// The fence opened by a line starting with `content`, if any
+fn opening_fence(content: &str) -> Option<&str> {
+    if let Some(fence) = code_fence(content) {
+        return Some(fence);
+    }
+    if content.starts_with(":::") && content[3..].starts_with(|c: char| !c.is_whitespace()) {
+        return Some(":::");
+    }
+    (content.starts_with("<!--") && !content.contains("-->")).then_some("<!--")
+}
+
+/// The run of three or more backticks or tildes starting `content`
+fn code_fence(content: &str) -> Option<&str> {
+    let fence_char = content.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = content.len() - content.trim_start_matches(fence_char).len();
+    (len >= 3).then_some(&content[..len])
+}
+
+/// The text of `line` after indentation, block quote and list item markers
+fn line_content(line: &str) -> &str {
+    line.trim_start_matches(|c: char| {
+        c.is_whitespace() || c.is_ascii_digit() || matches!(c, '>' | '-' | '+' | '*' | '.' | ')')
+    })
+}
+
+/// Whether `line`, including its line ending, is a blank line
+fn is_blank_line(line: &str) -> bool {
+    line.ends_with('\n') && line.trim().is_empty()
+}
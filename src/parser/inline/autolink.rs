@@ -1,6 +1,6 @@
 use nom::{
     branch::alt,
-    bytes::complete::{take_while, take_while1},
+    bytes::complete::{tag, take_while, take_while1},
     character::complete::{char, satisfy},
     combinator::{map, recognize},
     sequence::{delimited, pair, terminated},
@@ -57,3 +57,118 @@ fn is_scheme_char(c: char) -> bool {
 fn is_scheme_start(c: char) -> bool {
     c.is_ascii_alphabetic()
 }
+
+/// GFM extended autolink: a bare `http://`, `https://` or `ftp://` URL, a
+/// `www.` link or an email address
+pub(crate) fn extended_autolink(input: &str) -> IResult<&str, String> {
+    alt((extended_url, extended_www, extended_email)).parse(input)
+}
+
+/// An extended autolink that may follow `prev`: URLs and `www.` links only
+/// after whitespace, `*`, `_`, `~` or `(`, and email addresses anywhere but
+/// inside another address.
+pub(crate) fn extended_autolink_after<'a>(
+    prev: char,
+) -> impl FnMut(&'a str) -> IResult<&'a str, String> {
+    move |input: &'a str| {
+        if prev.is_whitespace() || matches!(prev, '*' | '_' | '~' | '(') {
+            extended_autolink(input)
+        } else if !is_email_local_char(prev) {
+            extended_email(input)
+        } else {
+            Err(error(input))
+        }
+    }
+}
+
+fn extended_url(input: &str) -> IResult<&str, String> {
+    let (rest, _) = alt((tag("https://"), tag("http://"), tag("ftp://"))).parse(input)?;
+    extended_link(input, input.len() - rest.len())
+}
+
+fn extended_www(input: &str) -> IResult<&str, String> {
+    let _ = tag("www.").parse(input)?;
+    extended_link(input, 0)
+}
+
+/// The link runs up to whitespace or `<`, without trailing punctuation, and
+/// must have a valid domain after `prefix_len` bytes.
+fn extended_link(input: &str, prefix_len: usize) -> IResult<&str, String> {
+    let candidate_len = input
+        .find(|c: char| c.is_whitespace() || c == '<')
+        .unwrap_or(input.len());
+    let link = trim_link_end(&input[..candidate_len]);
+
+    let host = &link[prefix_len.min(link.len())..];
+    let domain = &host[..host.find(|c| !is_domain_char(c)).unwrap_or(host.len())];
+    let segments: Vec<&str> = domain.split('.').collect();
+    if segments.len() < 2
+        || segments.iter().any(|s| s.is_empty())
+        || segments.iter().rev().take(2).any(|s| s.contains('_'))
+    {
+        return Err(error(input));
+    }
+
+    Ok((&input[link.len()..], link.to_string()))
+}
+
+/// Strip trailing punctuation, unbalanced closing parentheses and a trailing
+/// entity reference such as `&amp;`
+fn trim_link_end(mut link: &str) -> &str {
+    while let Some(last) = link.chars().last() {
+        if matches!(last, '?' | '!' | '.' | ',' | ':' | '*' | '_' | '~')
+            || (last == ')' && link.matches(')').count() > link.matches('(').count())
+        {
+            link = &link[..link.len() - 1];
+        } else if last == ';' {
+            match link.rfind('&') {
+                Some(amp)
+                    if amp + 2 < link.len()
+                        && link[amp + 1..link.len() - 1]
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric()) =>
+                {
+                    link = &link[..amp]
+                }
+                _ => break,
+            }
+        } else {
+            break;
+        }
+    }
+    link
+}
+
+fn extended_email(input: &str) -> IResult<&str, String> {
+    let local_len = input
+        .find(|c| !is_email_local_char(c))
+        .unwrap_or(input.len());
+    if local_len == 0 || !input[local_len..].starts_with('@') {
+        return Err(error(input));
+    }
+
+    let host = &input[local_len + 1..];
+    let domain =
+        host[..host.find(|c| !is_domain_char(c)).unwrap_or(host.len())].trim_end_matches('.');
+    if !domain.contains('.')
+        || domain.split('.').any(|s| s.is_empty())
+        || domain.ends_with(['-', '_'])
+    {
+        return Err(error(input));
+    }
+
+    let len = local_len + 1 + domain.len();
+    Ok((&input[len..], input[..len].to_string()))
+}
+
+fn is_domain_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+fn is_email_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+')
+}
+
+fn error(input: &str) -> nom::Err<nom::error::Error<&str>> {
+    nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Verify))
+}
@@ -0,0 +1,144 @@
+use crate::ast::{Citation, CitationItem, Inline};
+use crate::parser::config::ElementBehavior;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    bytes::complete::{take_till, take_till1, take_while1},
+    character::complete::{char, one_of},
+    combinator::{map, not, opt, peek, recognize},
+    multi::{many0, separated_list1},
+    sequence::{delimited, pair, preceded, terminated},
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// `[see @doe99, p. 33; -@smith04]`, or `@doe99` and `@doe99 [p. 33]` in the text
+///
+/// As in Pandoc, in-text citations are not recognized in the text of links.
+pub(crate) fn citation<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        if state.is_link_text {
+            return bracketed_citation(input);
+        }
+        alt((bracketed_citation, in_text_citation)).parse(input)
+    }
+}
+
+/// `[see @doe99, p. 33; -@smith04]`
+///
+/// A following `(` or `[` makes the brackets a link instead, as in Pandoc.
+pub(crate) fn bracketed_citation(input: &str) -> IResult<&str, Inline> {
+    map(
+        terminated(
+            delimited(
+                char('['),
+                separated_list1(char(';'), citation_item),
+                char(']'),
+            ),
+            not(peek(one_of("(["))),
+        ),
+        |items| {
+            Inline::Citation(Citation {
+                items,
+                in_text: false,
+            })
+        },
+    )
+    .parse(input)
+}
+
+/// Whether an in-text citation starts `input`
+///
+/// Like GitHub mentions, in-text citations only start a word: `prev` is the
+/// character before `input`, if known, so that e-mail addresses stay text.
+pub(crate) fn starts_in_text_citation(
+    state: &MarkdownParserState,
+    prev: Option<char>,
+    input: &str,
+) -> bool {
+    let behavior = &state.config.inline_citation_behavior;
+    if matches!(behavior, ElementBehavior::Ignore)
+        || state.is_link_text
+        || prev.is_some_and(|c| c.is_alphanumeric() || c == '_')
+    {
+        return false;
+    }
+    in_text_citation(input).is_ok()
+}
+
+/// `@doe99`, optionally followed by a locator in brackets (`@doe99 [p. 33]`)
+fn in_text_citation(input: &str) -> IResult<&str, Inline> {
+    map(
+        pair(
+            preceded(char('@'), citation_key),
+            opt(terminated(
+                preceded(
+                    char(' '),
+                    delimited(
+                        char('['),
+                        take_till1(|c| matches!(c, '[' | ']' | '@' | ';')),
+                        char(']'),
+                    ),
+                ),
+                not(peek(one_of("(["))),
+            )),
+        ),
+        |(key, locator)| {
+            Inline::Citation(Citation {
+                items: vec![CitationItem {
+                    key: key.to_owned(),
+                    prefix: None,
+                    locator: locator.and_then(normalize),
+                    suppress_author: false,
+                }],
+                in_text: true,
+            })
+        },
+    )
+    .parse(input)
+}
+
+/// `see -@doe99, p. 33` within brackets
+fn citation_item(input: &str) -> IResult<&str, CitationItem> {
+    let (rest, prefix) = take_till(|c| matches!(c, '@' | ';' | '[' | ']')).parse(input)?;
+    let (prefix, suppress_author) = match prefix.strip_suffix('-') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+    // The key starts a word
+    if prefix.ends_with(|c: char| !c.is_whitespace()) {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    let (rest, key) = preceded(char('@'), citation_key).parse(rest)?;
+    let (rest, suffix) = take_till(|c| matches!(c, ';' | '[' | ']')).parse(rest)?;
+    let locator = suffix.trim_start();
+    let locator = locator.strip_prefix(',').unwrap_or(locator);
+
+    Ok((
+        rest,
+        CitationItem {
+            key: key.to_owned(),
+            prefix: normalize(prefix),
+            locator: normalize(locator),
+            suppress_author,
+        },
+    ))
+}
+
+/// Words of letters, digits and `_`, joined by single punctuation characters
+/// (`doe:1999.a`), as in Pandoc
+fn citation_key(input: &str) -> IResult<&str, &str> {
+    let word = || take_while1(|c: char| c.is_alphanumeric() || c == '_');
+    recognize(pair(word(), many0(pair(one_of(":.#$%&-+?<>~/"), word())))).parse(input)
+}
+
+/// Collapse whitespace, so that text wrapped over several lines prints on one
+fn normalize(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
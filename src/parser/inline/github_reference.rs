@@ -0,0 +1,160 @@
+use crate::ast::{GitHubReference, GitHubReferenceKind, Inline};
+use crate::parser::config::ElementBehavior;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    bytes::complete::{take_while1, take_while_m_n},
+    character::complete::{char, digit1, satisfy},
+    combinator::{map, map_res, not, opt, recognize, verify},
+    sequence::{pair, preceded, terminated},
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// `@user` or `@org/team`
+pub(crate) fn mention<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    reference(state, mention_kind)
+}
+
+/// `#123` or `owner/repo#45`
+pub(crate) fn issue_reference<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    reference(state, issue_kind)
+}
+
+/// A commit SHA (`a5c3785`) or `owner/repo@a5c3785`
+pub(crate) fn commit_reference<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    reference(state, commit_kind)
+}
+
+/// Whether a reference enabled in the configuration starts `input`
+///
+/// References only start a word: `prev` is the character before `input`, if known,
+/// so that e-mail addresses, URLs and hex words stay text.
+pub(crate) fn starts_github_reference(
+    state: &MarkdownParserState,
+    prev: Option<char>,
+    input: &str,
+) -> bool {
+    let enabled = |behavior: &ElementBehavior<Inline>| !matches!(behavior, ElementBehavior::Ignore);
+    if prev.is_some_and(|c| {
+        c.is_alphanumeric() || matches!(c, '_' | '-' | '/' | '@' | '.' | '#' | '&')
+    }) {
+        return false;
+    }
+    (enabled(&state.config.inline_mention_behavior) && mention_kind(input).is_ok())
+        || (enabled(&state.config.inline_issue_reference_behavior) && issue_kind(input).is_ok())
+        || (enabled(&state.config.inline_commit_reference_behavior) && commit_kind(input).is_ok())
+}
+
+fn reference<'a>(
+    state: Rc<MarkdownParserState>,
+    kind: fn(&'a str) -> IResult<&'a str, GitHubReferenceKind>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        let (rest, kind) = kind(input)?;
+        let mut reference = GitHubReference {
+            kind,
+            destination: None,
+        };
+        if let Some(resolver) = &state.config.github_reference_resolver {
+            reference.destination = resolver(&reference);
+        }
+        Ok((rest, Inline::GitHubReference(reference)))
+    }
+}
+
+fn mention_kind(input: &str) -> IResult<&str, GitHubReferenceKind> {
+    map(
+        terminated(
+            preceded(
+                char('@'),
+                recognize(pair(
+                    verify(
+                        take_while1(|c: char| c.is_ascii_alphanumeric() || c == '-'),
+                        |name: &str| {
+                            name.len() <= 39 && !name.starts_with('-') && !name.ends_with('-')
+                        },
+                    ),
+                    opt(pair(
+                        char('/'),
+                        take_while1(|c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-')),
+                    )),
+                )),
+            ),
+            word_end,
+        ),
+        |name: &str| GitHubReferenceKind::Mention(name.to_owned()),
+    )
+    .parse(input)
+}
+
+fn issue_kind(input: &str) -> IResult<&str, GitHubReferenceKind> {
+    map(
+        terminated(
+            pair(
+                opt(repository),
+                preceded(
+                    char('#'),
+                    map_res(
+                        verify(digit1, |digits: &str| !digits.starts_with('0')),
+                        str::parse::<u64>,
+                    ),
+                ),
+            ),
+            word_end,
+        ),
+        |(repository, number)| GitHubReferenceKind::Issue {
+            repository: repository.map(str::to_owned),
+            number,
+        },
+    )
+    .parse(input)
+}
+
+fn commit_kind(input: &str) -> IResult<&str, GitHubReferenceKind> {
+    map(
+        terminated(
+            alt((
+                pair(map(terminated(repository, char('@')), Some), sha),
+                map(sha, |sha| (None, sha)),
+            )),
+            word_end,
+        ),
+        |(repository, sha)| GitHubReferenceKind::Commit {
+            repository: repository.map(str::to_owned),
+            sha: sha.to_owned(),
+        },
+    )
+    .parse(input)
+}
+
+/// `owner/repo`
+fn repository(input: &str) -> IResult<&str, &str> {
+    recognize((
+        take_while1(|c: char| c.is_ascii_alphanumeric() || c == '-'),
+        char('/'),
+        take_while1(|c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')),
+    ))
+    .parse(input)
+}
+
+/// 7 to 40 lowercase hex digits, with at least one letter and one digit
+fn sha(input: &str) -> IResult<&str, &str> {
+    verify(
+        take_while_m_n(7, 40, |c: char| matches!(c, '0'..='9' | 'a'..='f')),
+        |sha: &str| {
+            sha.chars().any(|c| c.is_ascii_digit()) && sha.chars().any(|c| c.is_ascii_lowercase())
+        },
+    )
+    .parse(input)
+}
+
+fn word_end(input: &str) -> IResult<&str, ()> {
+    not(satisfy(|c: char| c.is_alphanumeric() || c == '_')).parse(input)
+}
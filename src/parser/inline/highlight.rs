@@ -0,0 +1,62 @@
+use crate::ast::Inline;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, char, satisfy},
+    combinator::{not, peek, recognize, verify},
+    multi::many1,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// Highlighted text: `==text==`
+pub(crate) fn highlight<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        let (input, content) = paired(state.clone(), "==").parse(input)?;
+        Ok((input, Inline::Highlight(content)))
+    }
+}
+
+/// Inserted text: `++text++`
+pub(crate) fn insert<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        let (input, content) = paired(state.clone(), "++").parse(input)?;
+        Ok((input, Inline::Insert(content)))
+    }
+}
+
+/// Content between doubled `delimiter`s, neither starting nor ending with whitespace,
+/// so that `a == b` and `C++ or C++` stay text
+fn paired<'a>(
+    state: Rc<MarkdownParserState>,
+    delimiter: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
+    move |input: &'a str| {
+        let c = delimiter.chars().next().unwrap_or_default();
+        let (input, _) = terminated(
+            tag(delimiter),
+            peek(satisfy(move |next| next != c && !next.is_whitespace())),
+        )
+        .parse(input)?;
+        let closing_tag = terminated(tag(delimiter), not(char(c)));
+        let (input, content) = verify(
+            recognize(many1(preceded(
+                peek(not(closing_tag)),
+                alt((recognize(preceded(char('\\'), anychar)), recognize(anychar))),
+            ))),
+            |content: &str| !content.ends_with(char::is_whitespace),
+        )
+        .parse(input)?;
+        let (input, _) = tag(delimiter).parse(input)?;
+
+        let (_, inline) = crate::parser::inline::inline_many1(state.clone()).parse(content)?;
+
+        Ok((input, inline))
+    }
+}
@@ -1,54 +1,18 @@
 use crate::parser::link_util::link_title;
 use crate::parser::MarkdownParserState;
 use crate::{
-    ast::{Image, ImageAttributes, Inline},
-    parser::link_util::link_destination,
+    ast::{Image, Inline},
+    parser::{attributes::attributes, link_util::link_destination},
 };
 use nom::{
-    branch::alt,
-    bytes::complete::{take_until, take_while, take_while1},
-    character::complete::{alpha1, char, multispace0, multispace1},
+    bytes::complete::take_while,
+    character::complete::{char, multispace0},
     combinator::{map, opt},
-    multi::separated_list0,
-    sequence::{delimited, preceded, separated_pair},
+    sequence::{delimited, preceded},
     IResult, Parser,
 };
 use std::rc::Rc;
 
-fn key_value_parser<'a>(input: &'a str) -> IResult<&'a str, (&'a str, &'a str)> {
-    separated_pair(
-        preceded(multispace0, alpha1),
-        delimited(multispace0, char('='), multispace0),
-        alt((
-            delimited(char('"'), take_until("\""), char('"')),
-            take_while1(|c: char| !c.is_whitespace() && c != '}'),
-        )),
-    )
-    .parse(input)
-}
-
-fn attributes_parser<'a>(input: &'a str) -> IResult<&'a str, ImageAttributes> {
-    map(
-        delimited(
-            preceded(multispace0, char('{')),
-            preceded(multispace0, separated_list0(multispace1, key_value_parser)),
-            preceded(multispace0, char('}')),
-        ),
-        |key_values| {
-            let mut attrs = ImageAttributes::default();
-            for (key, value) in key_values {
-                match key {
-                    "width" => attrs.width = Some(value.to_string()),
-                    "height" => attrs.height = Some(value.to_string()),
-                    _ => {}
-                }
-            }
-            attrs
-        },
-    )
-    .parse(input)
-}
-
 // ![alt text](/url "title")
 pub(crate) fn image<'a>(
     _state: Rc<MarkdownParserState>,
@@ -70,7 +34,11 @@ pub(crate) fn image<'a>(
         )
         .parse(input)?;
 
-        let (input, attr) = opt(attributes_parser).parse(input)?;
+        let (input, attributes) = map(
+            opt(preceded(multispace0, attributes)),
+            Option::unwrap_or_default,
+        )
+        .parse(input)?;
 
         Ok((
             input,
@@ -78,7 +46,7 @@ pub(crate) fn image<'a>(
                 destination,
                 title,
                 alt: alt.to_owned(),
-                attr,
+                attributes,
             }),
         ))
     }
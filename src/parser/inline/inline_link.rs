@@ -1,9 +1,10 @@
-use crate::ast::Link;
-use crate::parser::link_util::{link_destination, link_label, link_title};
+use crate::ast::{Attributes, Link};
+use crate::parser::attributes::attributes;
+use crate::parser::link_util::{link_destination, link_text, link_title};
 use crate::parser::MarkdownParserState;
 use nom::{
     character::complete::{char, multispace0},
-    combinator::opt,
+    combinator::{map, opt},
     sequence::{delimited, preceded},
     IResult, Parser,
 };
@@ -14,7 +15,7 @@ pub(crate) fn inline_link<'a>(
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Link> {
     move |input: &'a str| {
         let (input, (children, (destination, title))) = (
-            link_label(state.clone()),
+            link_text(state.clone()),
             delimited(
                 char('('),
                 (
@@ -26,10 +27,17 @@ pub(crate) fn inline_link<'a>(
         )
             .parse(input)?;
 
+        let (input, attributes) = if state.config.allow_attributes {
+            map(opt(attributes), Option::unwrap_or_default).parse(input)?
+        } else {
+            (input, Attributes::default())
+        };
+
         let link = Link {
             destination,
             title,
             children,
+            attributes,
         };
 
         Ok((input, link))
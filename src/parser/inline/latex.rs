@@ -1,14 +1,87 @@
 use nom::{
-    bytes::complete::take_while, character::complete::char, combinator::map, sequence::delimited,
+    bytes::complete::{tag, take_until1, take_while, take_while1},
+    character::complete::char,
+    combinator::map,
+    sequence::delimited,
     IResult, Parser,
 };
+use std::rc::Rc;
 
-use crate::ast::Inline;
+use crate::ast::{Inline, InlineMathDelimiter};
+use crate::parser::MarkdownParserState;
 
-pub(crate) fn latex(input: &str) -> IResult<&str, Vec<Inline>> {
+pub(crate) fn latex<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        if state.config.strict_dollar_math {
+            strict_latex(input)
+        } else {
+            map(
+                delimited(char('$'), take_while(|c| c != '$'), char('$')),
+                |s: &str| Inline::Latex {
+                    delimiter: InlineMathDelimiter::Dollar,
+                    content: s.to_string(),
+                },
+            )
+            .parse(input)
+        }
+    }
+}
+
+/// `$...$` following Pandoc's `tex_math_dollars` rules
+///
+/// The opening `$` must be followed by a non-whitespace character, and the
+/// closing `$` must be preceded by a non-whitespace character and must not
+/// be followed by a digit, so that `$5 and $10` stays text. An escaped `\$`
+/// does not close the formula.
+fn strict_latex(input: &str) -> IResult<&str, Inline> {
+    let error = || nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Char));
+
+    let body = input.strip_prefix('$').ok_or_else(error)?;
+    if body.starts_with(|c: char| c.is_whitespace() || c == '$') {
+        return Err(error());
+    }
+
+    for (i, _) in body.match_indices('$') {
+        let content = &body[..i];
+        let rest = &body[i + 1..];
+        if content.ends_with(|c: char| c.is_whitespace() || c == '\\')
+            || rest.starts_with(|c: char| c.is_ascii_digit())
+        {
+            continue;
+        }
+        return Ok((
+            rest,
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::Dollar,
+                content: content.to_string(),
+            },
+        ));
+    }
+    Err(error())
+}
+
+/// `\(...\)`
+pub(crate) fn latex_parentheses(input: &str) -> IResult<&str, Inline> {
+    map(
+        delimited(tag(r"\("), take_until1(r"\)"), tag(r"\)")),
+        |s: &str| Inline::Latex {
+            delimiter: InlineMathDelimiter::Parentheses,
+            content: s.to_string(),
+        },
+    )
+    .parse(input)
+}
+
+/// `` $`...`$ `` as used by GitLab and GitHub
+pub(crate) fn latex_code(input: &str) -> IResult<&str, Inline> {
     map(
-        delimited(char('$'), take_while(|c| c != '$'), char('$')),
-        |s: &str| vec![Inline::Latex(s.to_string())],
+        delimited(tag("$`"), take_while1(|c| c != '`'), tag("`$")),
+        |s: &str| Inline::Latex {
+            delimiter: InlineMathDelimiter::DollarBacktick,
+            content: s.to_string(),
+        },
     )
     .parse(input)
 }
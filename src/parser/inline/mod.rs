@@ -1,25 +1,34 @@
-mod autolink;
+pub(crate) mod autolink;
+mod citation;
 mod code_span;
+pub(crate) mod emoji;
 mod emphasis;
 mod environment_variable;
 mod footnote_reference;
+pub(crate) mod github_reference;
 mod hard_newline;
+mod highlight;
 mod html_entity;
 mod image;
 mod inline_link;
 mod latex;
 mod reference_link;
+mod script;
+mod span;
 mod strikethrough;
 mod text;
+mod wiki_link;
 
 #[cfg(test)]
 mod tests;
 
 use crate::ast::Inline;
+use crate::parser::config::{BuiltinInlineParser, ParserPriority};
+use crate::parser::source_map::inline_kind;
 use crate::parser::MarkdownParserState;
 use nom::{
     branch::alt,
-    combinator::{fail, map},
+    combinator::{consumed, fail, map},
     multi::{many0, many1},
     IResult, Parser,
 };
@@ -28,7 +37,7 @@ use std::rc::Rc;
 use super::util::conditional_inline;
 
 /// Merges consecutive Text elements into a single Text element
-fn merge_consecutive_text_elements(inlines: Vec<Inline>) -> Vec<Inline> {
+pub(crate) fn merge_consecutive_text_elements(inlines: Vec<Inline>) -> Vec<Inline> {
     let mut result = Vec::new();
     let mut current_text = String::new();
     let mut has_text = false;
@@ -60,14 +69,51 @@ fn merge_consecutive_text_elements(inlines: Vec<Inline>) -> Vec<Inline> {
     result
 }
 
+/// Flattens parsed inlines together with the text each was parsed from,
+/// merging consecutive Text elements
+fn collect_inlines(
+    state: &MarkdownParserState,
+    list_of_lists: Vec<(&str, Vec<Inline>)>,
+) -> Vec<Inline> {
+    if state.tracks_spans() {
+        record_inline_spans(state, &list_of_lists);
+    }
+    let r: Vec<_> = list_of_lists
+        .into_iter()
+        .flat_map(|(_, inlines)| inlines)
+        .collect();
+    merge_consecutive_text_elements(r)
+}
+
+/// Records the positions of parsed inlines, as they will be after merging
+/// consecutive Text elements
+fn record_inline_spans<'a>(state: &MarkdownParserState, list_of_lists: &[(&'a str, Vec<Inline>)]) {
+    let mut text: Option<(&'a str, &'a str)> = None;
+    for (consumed, inlines) in list_of_lists {
+        let end = &consumed[consumed.len()..];
+        for inline in inlines {
+            if let Inline::Text(_) = inline {
+                let start = text.map_or(*consumed, |(start, _)| start);
+                text = Some((start, end));
+            } else {
+                if let Some((start, text_end)) = text.take() {
+                    state.record_span("text", start, text_end);
+                }
+                state.record_span(inline_kind(inline), consumed, end);
+            }
+        }
+    }
+    if let Some((start, end)) = text {
+        state.record_span("text", start, end);
+    }
+}
+
 pub(crate) fn inline_many0<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
-        let (input, list_of_lists) = many0(inline(state.clone())).parse(input)?;
-        let r: Vec<_> = list_of_lists.into_iter().flatten().collect();
-        let merged = merge_consecutive_text_elements(r);
-        Ok((input, merged))
+        let (input, list_of_lists) = many0(consumed(inline(state.clone()))).parse(input)?;
+        Ok((input, collect_inlines(&state, list_of_lists)))
     }
 }
 
@@ -75,10 +121,8 @@ pub(crate) fn inline_many1<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
-        let (input, list_of_lists) = many1(inline(state.clone())).parse(input)?;
-        let r: Vec<_> = list_of_lists.into_iter().flatten().collect();
-        let merged = merge_consecutive_text_elements(r);
-        Ok((input, merged))
+        let (input, list_of_lists) = many1(consumed(inline(state.clone()))).parse(input)?;
+        Ok((input, collect_inlines(&state, list_of_lists)))
     }
 }
 
@@ -87,68 +131,272 @@ pub(crate) fn inline<'a>(
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
         alt((
-            conditional_inline(
-                state.config.inline_autolink_behavior.clone(),
-                map(crate::parser::inline::autolink::autolink, Inline::Autolink),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Autolink,
+                conditional_inline(
+                    state.config.inline_autolink_behavior.clone(),
+                    map(crate::parser::inline::autolink::autolink, |url| {
+                        Inline::Autolink { url, bare: false }
+                    }),
+                ),
             ),
-            conditional_inline(
-                state.config.inline_link_behavior.clone(),
-                map(
-                    crate::parser::inline::inline_link::inline_link(state.clone()),
-                    Inline::Link,
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::ExtendedAutolink,
+                conditional_inline(
+                    state.config.inline_extended_autolink_behavior.clone(),
+                    map(crate::parser::inline::autolink::extended_autolink, |url| {
+                        Inline::Autolink { url, bare: true }
+                    }),
                 ),
             ),
-            conditional_inline(
-                state.config.inline_footnote_reference_behavior.clone(),
-                crate::parser::inline::footnote_reference::footnote_reference,
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::WikiLink,
+                conditional_inline(
+                    state.config.inline_wiki_link_behavior.clone(),
+                    crate::parser::inline::wiki_link::wiki_link,
+                ),
             ),
-            conditional_inline(
-                state.config.inline_reference_link_behavior.clone(),
-                crate::parser::inline::reference_link::reference_link(state.clone()),
+            alt((
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinInlineParser::Link,
+                    conditional_inline(
+                        state.config.inline_link_behavior.clone(),
+                        map(
+                            crate::parser::inline::inline_link::inline_link(state.clone()),
+                            Inline::Link,
+                        ),
+                    ),
+                ),
+                // NOTE: Citations must come before spans and reference links, and before
+                // GitHub references, which accept `@name` too
+                with_custom_parsers(
+                    state.clone(),
+                    BuiltinInlineParser::Citation,
+                    conditional_inline(
+                        state.config.inline_citation_behavior.clone(),
+                        crate::parser::inline::citation::citation(state.clone()),
+                    ),
+                ),
+            )),
+            // NOTE: Spans must come before reference links, which accept any `[text]`
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Span,
+                conditional_inline(
+                    state.config.inline_span_behavior.clone(),
+                    crate::parser::inline::span::span(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::FootnoteReference,
+                conditional_inline(
+                    state.config.inline_footnote_reference_behavior.clone(),
+                    crate::parser::inline::footnote_reference::footnote_reference,
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::ReferenceLink,
+                conditional_inline(
+                    state.config.inline_reference_link_behavior.clone(),
+                    crate::parser::inline::reference_link::reference_link(state.clone()),
+                ),
             ),
-            conditional_inline(
-                state.config.inline_hard_newline_behavior.clone(),
-                crate::parser::inline::hard_newline::hard_newline,
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::HardNewline,
+                conditional_inline(
+                    state.config.inline_hard_newline_behavior.clone(),
+                    crate::parser::inline::hard_newline::hard_newline,
+                ),
             ),
-            conditional_inline(
-                state.config.inline_image_behavior.clone(),
-                crate::parser::inline::image::image(state.clone()),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Image,
+                alt((
+                    conditional_inline(
+                        state.config.inline_image_behavior.clone(),
+                        crate::parser::inline::image::image(state.clone()),
+                    ),
+                    conditional_inline(
+                        state.config.inline_reference_link_behavior.clone(),
+                        crate::parser::inline::reference_link::image_reference(state.clone()),
+                    ),
+                )),
             ),
-            conditional_inline(
-                state.config.inline_code_span_behavior.clone(),
-                map(crate::parser::inline::code_span::code_span, Inline::Code),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::CodeSpan,
+                conditional_inline(
+                    state.config.inline_code_span_behavior.clone(),
+                    map(crate::parser::inline::code_span::code_span, Inline::Code),
+                ),
             ),
-            map(
-                crate::parser::inline::environment_variable::environment_variable,
-                |env_var| vec![env_var],
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::EnvironmentVariable,
+                conditional_inline(
+                    state.config.inline_environment_variable_behavior.clone(),
+                    crate::parser::inline::environment_variable::environment_variable,
+                ),
             ),
             // NOTE: It's important that the latex parser comes before the text parser
-            crate::parser::inline::latex::latex,
-            conditional_inline(
-                state.config.inline_emphasis_behavior.clone(),
-                crate::parser::inline::emphasis::emphasis(state.clone()),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Latex,
+                alt((
+                    conditional_inline(
+                        state.config.inline_latex_code_behavior.clone(),
+                        crate::parser::inline::latex::latex_code,
+                    ),
+                    conditional_inline(
+                        state.config.inline_latex_behavior.clone(),
+                        crate::parser::inline::latex::latex(state.clone()),
+                    ),
+                    conditional_inline(
+                        state.config.inline_latex_parentheses_behavior.clone(),
+                        crate::parser::inline::latex::latex_parentheses,
+                    ),
+                )),
             ),
-            conditional_inline(
-                state.config.inline_strikethrough_behavior.clone(),
-                crate::parser::inline::strikethrough::strikethrough(state.clone()),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::GitHubReference,
+                alt((
+                    conditional_inline(
+                        state.config.inline_mention_behavior.clone(),
+                        crate::parser::inline::github_reference::mention(state.clone()),
+                    ),
+                    conditional_inline(
+                        state.config.inline_issue_reference_behavior.clone(),
+                        crate::parser::inline::github_reference::issue_reference(state.clone()),
+                    ),
+                    conditional_inline(
+                        state.config.inline_commit_reference_behavior.clone(),
+                        crate::parser::inline::github_reference::commit_reference(state.clone()),
+                    ),
+                )),
             ),
-            custom_parser(state.clone()),
-            conditional_inline(
-                state.config.inline_text_behavior.clone(),
-                crate::parser::inline::text::text(state.clone()),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Emoji,
+                conditional_inline(
+                    state.config.inline_emoji_behavior.clone(),
+                    crate::parser::inline::emoji::emoji(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Emphasis,
+                conditional_inline(
+                    state.config.inline_emphasis_behavior.clone(),
+                    crate::parser::inline::emphasis::emphasis(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Strikethrough,
+                conditional_inline(
+                    state.config.inline_strikethrough_behavior.clone(),
+                    crate::parser::inline::strikethrough::strikethrough(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Superscript,
+                conditional_inline(
+                    state.config.inline_superscript_behavior.clone(),
+                    crate::parser::inline::script::superscript(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Subscript,
+                conditional_inline(
+                    state.config.inline_subscript_behavior.clone(),
+                    crate::parser::inline::script::subscript(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Highlight,
+                conditional_inline(
+                    state.config.inline_highlight_behavior.clone(),
+                    crate::parser::inline::highlight::highlight(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Insert,
+                conditional_inline(
+                    state.config.inline_insert_behavior.clone(),
+                    crate::parser::inline::highlight::insert(state.clone()),
+                ),
+            ),
+            with_custom_parsers(
+                state.clone(),
+                BuiltinInlineParser::Text,
+                conditional_inline(
+                    state.config.inline_text_behavior.clone(),
+                    crate::parser::inline::text::text(state.clone()),
+                ),
             ),
         ))
         .parse(input)
     }
 }
 
-fn custom_parser(state: Rc<MarkdownParserState>) -> impl FnMut(&str) -> IResult<&str, Vec<Inline>> {
+/// Try the custom parsers registered around `builtin`, and `inner` in between
+fn with_custom_parsers<'a, P>(
+    state: Rc<MarkdownParserState>,
+    builtin: BuiltinInlineParser,
+    mut inner: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>>
+where
+    P: Parser<&'a str, Output = Vec<Inline>, Error = nom::error::Error<&'a str>>,
+{
+    move |input: &'a str| {
+        if state.config.custom_inline_parsers.is_empty() {
+            return inner.parse(input);
+        }
+        alt((
+            custom_parser(state.clone(), Some(ParserPriority::Before(builtin))),
+            |i| inner.parse(i),
+            custom_parser(state.clone(), Some(ParserPriority::After(builtin))),
+        ))
+        .parse(input)
+    }
+}
+
+/// The custom inline parsers registered at `priority`, or all of them if it is `None`,
+/// tried in registration order
+pub(crate) fn custom_parser(
+    state: Rc<MarkdownParserState>,
+    priority: Option<ParserPriority<BuiltinInlineParser>>,
+) -> impl FnMut(&str) -> IResult<&str, Vec<Inline>> {
     move |input: &str| {
-        if let Some(custom_parser) = state.config.custom_inline_parser.as_ref() {
-            let mut p = (**custom_parser).borrow_mut();
-            (p.as_mut())(input)
-        } else {
-            fail().parse(input)
+        let parsers = state.config.custom_inline_parsers.iter();
+        for custom in parsers.filter(|p| priority.is_none_or(|priority| p.priority == priority)) {
+            // A parser that is already running higher up the stack does not match
+            let Ok(mut p) = custom.parser.try_borrow_mut() else {
+                continue;
+            };
+            match (p.as_mut())(input) {
+                Err(nom::Err::Error(_)) => continue,
+                result => {
+                    return result.inspect_err(|err| {
+                        state.record_failure(err, "custom inline", |e| {
+                            format!("parser `{}` failed: {}", custom.name, e.code.description())
+                        })
+                    })
+                }
+            }
         }
+        fail().parse(input)
     }
 }
@@ -1,12 +1,64 @@
 use crate::ast::{Inline, LinkReference};
-use crate::parser::link_util::link_label;
+use crate::parser::config::ElementBehavior;
+use crate::parser::inline::citation::bracketed_citation;
+use crate::parser::inline::footnote_reference::footnote_reference;
+use crate::parser::inline::inline_link::inline_link;
+use crate::parser::inline::span::span;
+use crate::parser::link_util::link_text;
 use crate::parser::MarkdownParserState;
-use nom::{branch::alt, bytes::complete::tag, sequence::terminated, IResult, Parser};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{fail, map},
+    sequence::terminated,
+    IResult, Parser,
+};
 use std::rc::Rc;
 
 pub(crate) fn reference_link<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| map(link_reference(state.clone()), Inline::LinkReference).parse(input)
+}
+
+/// `![alt][label]`, `![alt][]` or `![alt]`
+///
+/// The `!` only makes an image of what would be a reference link without it:
+/// `![^1]`, `![@doe]` and `![text]{.class}` stay a `!` followed by a footnote
+/// reference, a citation and a span.
+pub(crate) fn image_reference<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    let enabled = |behavior: &ElementBehavior<Inline>| !matches!(behavior, ElementBehavior::Ignore);
+    let link = enabled(&state.config.inline_link_behavior);
+    let citation = enabled(&state.config.inline_citation_behavior);
+    let span_enabled = enabled(&state.config.inline_span_behavior);
+    let footnote = enabled(&state.config.inline_footnote_reference_behavior);
+    move |input: &'a str| {
+        let (input, _) = char('!').parse(input)?;
+        // The constructs that are tried before reference links
+        let precedes = (link && inline_link(state.clone()).parse(input).is_ok())
+            || (citation && bracketed_citation(input).is_ok())
+            || (span_enabled && span(state.clone()).parse(input).is_ok())
+            || (footnote && footnote_reference(input).is_ok());
+        if precedes {
+            return fail().parse(input);
+        }
+        let (input, link_reference) = link_reference(state.clone()).parse(input)?;
+        Ok((
+            input,
+            Inline::LinkReference(LinkReference {
+                image: true,
+                ..link_reference
+            }),
+        ))
+    }
+}
+
+fn link_reference<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, LinkReference> {
     move |input: &'a str| {
         alt((
             reference_link_full(state.clone()),
@@ -17,39 +69,45 @@ pub(crate) fn reference_link<'a>(
     }
 }
 
-pub(crate) fn reference_link_full<'a>(
+fn reference_link_full<'a>(
     state: Rc<MarkdownParserState>,
-) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+) -> impl FnMut(&'a str) -> IResult<&'a str, LinkReference> {
     move |input: &'a str| {
         let (input, (text, label)) =
-            (link_label(state.clone()), link_label(state.clone())).parse(input)?;
-        let link_reference = LinkReference { label, text };
-        Ok((input, Inline::LinkReference(link_reference)))
+            (link_text(state.clone()), link_text(state.clone())).parse(input)?;
+        let link_reference = LinkReference {
+            label,
+            text,
+            image: false,
+        };
+        Ok((input, link_reference))
     }
 }
 
-pub(crate) fn reference_link_collapsed<'a>(
+fn reference_link_collapsed<'a>(
     state: Rc<MarkdownParserState>,
-) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+) -> impl FnMut(&'a str) -> IResult<&'a str, LinkReference> {
     move |input: &'a str| {
-        let (input, text) = terminated(link_label(state.clone()), tag("[]")).parse(input)?;
+        let (input, text) = terminated(link_text(state.clone()), tag("[]")).parse(input)?;
         let link_reference = LinkReference {
             label: text.clone(),
             text,
+            image: false,
         };
-        Ok((input, Inline::LinkReference(link_reference)))
+        Ok((input, link_reference))
     }
 }
 
-pub(crate) fn reference_link_shortcut<'a>(
+fn reference_link_shortcut<'a>(
     state: Rc<MarkdownParserState>,
-) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+) -> impl FnMut(&'a str) -> IResult<&'a str, LinkReference> {
     move |input: &'a str| {
-        let (input, text) = link_label(state.clone()).parse(input)?;
+        let (input, text) = link_text(state.clone()).parse(input)?;
         let link_reference = LinkReference {
             label: text.clone(),
             text,
+            image: false,
         };
-        Ok((input, Inline::LinkReference(link_reference)))
+        Ok((input, link_reference))
     }
 }
@@ -0,0 +1,55 @@
+use crate::ast::Inline;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    character::complete::{char, satisfy},
+    combinator::{not, peek, recognize},
+    multi::many1,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// Pandoc superscript: `^text^`
+pub(crate) fn superscript<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        let (input, content) = script(state.clone(), '^').parse(input)?;
+        Ok((input, Inline::Superscript(content)))
+    }
+}
+
+/// Pandoc subscript: `~text~`
+///
+/// A doubled `~~` opens a strikethrough instead.
+pub(crate) fn subscript<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        let (input, content) = script(state.clone(), '~').parse(input)?;
+        Ok((input, Inline::Subscript(content)))
+    }
+}
+
+/// Content between single `delimiter`s, without whitespace unless escaped
+fn script<'a>(
+    state: Rc<MarkdownParserState>,
+    delimiter: char,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
+    move |input: &'a str| {
+        let (input, _) = terminated(char(delimiter), peek(not(char(delimiter)))).parse(input)?;
+        let (input, content) = recognize(many1(alt((
+            recognize(preceded(char('\\'), satisfy(|c| c != '\n' && c != '\r'))),
+            recognize(satisfy(|c| {
+                c != delimiter && c != '\\' && !c.is_whitespace()
+            })),
+        ))))
+        .parse(input)?;
+        let (input, _) = char(delimiter).parse(input)?;
+
+        let (_, inline) = crate::parser::inline::inline_many1(state.clone()).parse(content)?;
+
+        Ok((input, inline))
+    }
+}
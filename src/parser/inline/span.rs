@@ -0,0 +1,27 @@
+use crate::ast::{Inline, Span};
+use crate::parser::attributes::attributes;
+use crate::parser::link_util::link_label;
+use crate::parser::MarkdownParserState;
+use nom::{IResult, Parser};
+use std::rc::Rc;
+
+/// `[text]{#id .class key=value}`
+///
+/// The attribute list must follow the closing bracket directly; it may be
+/// empty, as in `[text]{}`.
+pub(crate) fn span<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
+    move |input: &'a str| {
+        let (input, (children, attributes)) =
+            (link_label(state.clone()), attributes).parse(input)?;
+
+        Ok((
+            input,
+            Inline::Span(Span {
+                attributes,
+                children,
+            }),
+        ))
+    }
+}
@@ -7,9 +7,10 @@ fn autolink1() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Autolink(
-                "http://foo.bar.baz".to_owned()
-            )])]
+            blocks: vec![Block::Paragraph(vec![Inline::Autolink {
+                url: "http://foo.bar.baz".to_owned(),
+                bare: false
+            }])]
         }
     );
 }
@@ -20,9 +21,10 @@ fn autolink2() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Autolink(
-                "irc://foo.bar:2233/baz".to_owned()
-            )])]
+            blocks: vec![Block::Paragraph(vec![Inline::Autolink {
+                url: "irc://foo.bar:2233/baz".to_owned(),
+                bare: false
+            }])]
         }
     );
 }
@@ -33,9 +35,10 @@ fn autolink3() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Autolink(
-                "MAILTO:FOO@BAR.BAZ".to_owned()
-            )])]
+            blocks: vec![Block::Paragraph(vec![Inline::Autolink {
+                url: "MAILTO:FOO@BAR.BAZ".to_owned(),
+                bare: false
+            }])]
         }
     );
 }
@@ -59,9 +62,10 @@ fn autolink5() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Autolink(
-                "http://example.com/\\[\\".to_owned()
-            )])]
+            blocks: vec![Block::Paragraph(vec![Inline::Autolink {
+                url: "http://example.com/\\[\\".to_owned(),
+                bare: false
+            }])]
         }
     );
 }
@@ -76,3 +80,98 @@ fn autolink6() {
         }
     );
 }
+
+fn extended(input: &str) -> Vec<Inline> {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_extended_autolink_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), input).unwrap();
+    let [Block::Paragraph(content)] = doc.blocks.as_slice() else {
+        panic!("expected a single paragraph, got {:?}", doc.blocks);
+    };
+    content.clone()
+}
+
+fn bare(url: &str) -> Inline {
+    Inline::Autolink {
+        url: url.to_owned(),
+        bare: true,
+    }
+}
+
+#[test]
+fn extended_autolink_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "See https://example.com").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(
+            "See https://example.com".to_owned()
+        )])]
+    );
+}
+
+#[test]
+fn extended_autolinks() {
+    assert_eq!(
+        extended(
+            "See https://example.com/a?b=1, www.commonmark.org/help or foo.bar+baz@example.com."
+        ),
+        vec![
+            Inline::Text("See ".to_owned()),
+            bare("https://example.com/a?b=1"),
+            Inline::Text(", ".to_owned()),
+            bare("www.commonmark.org/help"),
+            Inline::Text(" or ".to_owned()),
+            bare("foo.bar+baz@example.com"),
+            Inline::Text(".".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn extended_autolink_parentheses() {
+    assert_eq!(
+        extended("(www.google.com/search?q=Markup+(business)))"),
+        vec![
+            Inline::Text("(".to_owned()),
+            bare("www.google.com/search?q=Markup+(business)"),
+            Inline::Text("))".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn extended_autolink_trailing_entity() {
+    assert_eq!(
+        extended("www.google.com/search?q=commonmark&hl;"),
+        vec![
+            bare("www.google.com/search?q=commonmark"),
+            Inline::Text("&hl;".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn extended_autolink_invalid() {
+    for input in [
+        "foohttps://example.com",
+        "http://localhost",
+        "www.exa_mple.com",
+        "foo@bar",
+        "foo@bar.example_",
+    ] {
+        assert_eq!(extended(input), vec![Inline::Text(input.to_owned())]);
+    }
+}
+
+#[test]
+fn extended_autolink_in_link_label() {
+    assert_eq!(
+        extended("[https://example.com](https://example.com)"),
+        vec![Inline::Link(Link {
+            destination: "https://example.com".to_owned(),
+            title: None,
+            children: vec![Inline::Text("https://example.com".to_owned())],
+            attributes: Attributes::default(),
+        })]
+    );
+}
@@ -0,0 +1,188 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn paragraph(config: MarkdownParserConfig, input: &str) -> Vec<Inline> {
+    let config = config.with_inline_citation_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+fn item(key: &str) -> CitationItem {
+    CitationItem {
+        key: key.to_owned(),
+        prefix: None,
+        locator: None,
+        suppress_author: false,
+    }
+}
+
+fn citation(items: Vec<CitationItem>, in_text: bool) -> Inline {
+    Inline::Citation(Citation { items, in_text })
+}
+
+#[test]
+fn citations_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "As [@doe99] shows").unwrap();
+    let label = vec![Inline::Text("@doe99".to_owned())];
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::Text("As ".to_owned()),
+            Inline::LinkReference(LinkReference {
+                label: label.clone(),
+                text: label,
+                image: false,
+            }),
+            Inline::Text(" shows".to_owned()),
+        ])]
+    );
+}
+
+#[test]
+fn bracketed_citation() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "Shown [see @doe99, pp. 33-35; also -@smith04 chap. 1]."
+        ),
+        vec![
+            Inline::Text("Shown ".to_owned()),
+            citation(
+                vec![
+                    CitationItem {
+                        prefix: Some("see".to_owned()),
+                        locator: Some("pp. 33-35".to_owned()),
+                        ..item("doe99")
+                    },
+                    CitationItem {
+                        prefix: Some("also".to_owned()),
+                        locator: Some("chap. 1".to_owned()),
+                        suppress_author: true,
+                        ..item("smith04")
+                    },
+                ],
+                false
+            ),
+            Inline::Text(".".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn citation_keys_with_internal_punctuation() {
+    assert_eq!(
+        paragraph(MarkdownParserConfig::default(), "[@doe:1999.a-b]"),
+        vec![citation(vec![item("doe:1999.a-b")], false)]
+    );
+}
+
+#[test]
+fn in_text_citations() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "@doe99 says so, and @smith04 [p. 33] agrees."
+        ),
+        vec![
+            citation(vec![item("doe99")], true),
+            Inline::Text(" says so, and ".to_owned()),
+            citation(
+                vec![CitationItem {
+                    locator: Some("p. 33".to_owned()),
+                    ..item("smith04")
+                }],
+                true
+            ),
+            Inline::Text(" agrees.".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn e_mail_addresses_stay_text() {
+    assert_eq!(
+        paragraph(MarkdownParserConfig::default(), "mail doe@example.com."),
+        vec![Inline::Text("mail doe@example.com.".to_owned())]
+    );
+}
+
+#[test]
+fn brackets_followed_by_a_destination_stay_a_link() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "[@doe99](https://example.com)"
+        ),
+        vec![Inline::Link(Link {
+            destination: "https://example.com".to_owned(),
+            title: None,
+            children: vec![Inline::Text("@doe99".to_owned())],
+            attributes: Attributes::default(),
+        })]
+    );
+}
+
+#[test]
+fn no_in_text_citations_in_link_text() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "[see *@doe99*](https://example.com) @smith04"
+        ),
+        vec![
+            Inline::Link(Link {
+                destination: "https://example.com".to_owned(),
+                title: None,
+                children: vec![
+                    Inline::Text("see ".to_owned()),
+                    Inline::Emphasis(vec![Inline::Text("@doe99".to_owned())]),
+                ],
+                attributes: Attributes::default(),
+            }),
+            Inline::Text(" ".to_owned()),
+            citation(vec![item("smith04")], true),
+        ]
+    );
+}
+
+#[test]
+fn no_in_text_citations_in_link_definition_labels() {
+    let config =
+        MarkdownParserConfig::default().with_inline_citation_behavior(ElementBehavior::Parse);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "[@doe99]: /def").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Definition(LinkDefinition {
+            label: vec![Inline::Text("@doe99".to_owned())],
+            destination: "/def".to_owned(),
+            title: None,
+        })]
+    );
+}
+
+#[test]
+fn every_bracketed_item_needs_a_key() {
+    let label = vec![Inline::Text("see p. 33; @doe99".to_owned())];
+    assert_eq!(
+        paragraph(MarkdownParserConfig::default(), "[see p. 33; @doe99]"),
+        vec![Inline::LinkReference(LinkReference {
+            label: label.clone(),
+            text: label,
+            image: false,
+        })]
+    );
+}
+
+#[test]
+fn citations_take_precedence_over_mentions() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default().with_inline_mention_behavior(ElementBehavior::Parse),
+            "thanks @alice"
+        ),
+        vec![
+            Inline::Text("thanks ".to_owned()),
+            citation(vec![item("alice")], true),
+        ]
+    );
+}
@@ -0,0 +1,93 @@
+use super::paragraph;
+use crate::ast::*;
+use crate::parser::config::{
+    BuiltinInlineParser, CustomInlineParserFn, MarkdownParserConfig, ParserPriority,
+};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    sequence::preceded,
+    Parser,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn mention() -> CustomInlineParserFn {
+    Rc::new(RefCell::new(Box::new(|input: &str| {
+        preceded(tag("@"), take_while1(|c: char| c.is_alphanumeric()))
+            .map(|name: &str| vec![Inline::Html(format!("<a href=\"/{name}\">@{name}</a>"))])
+            .parse(input)
+    })))
+}
+
+fn code_marker(marker: &'static str) -> CustomInlineParserFn {
+    Rc::new(RefCell::new(Box::new(move |input: &str| {
+        tag("`")
+            .map(|_| vec![Inline::Text(marker.to_owned())])
+            .parse(input)
+    })))
+}
+
+#[test]
+fn custom_inline_parser_inside_text() {
+    let config = MarkdownParserConfig::default().with_named_custom_inline_parser(
+        "mention",
+        ParserPriority::Before(BuiltinInlineParser::Text),
+        mention(),
+    );
+    assert_eq!(
+        paragraph(config, "hi @bob, *@alice*"),
+        vec![
+            Inline::Text("hi ".to_owned()),
+            Inline::Html("<a href=\"/bob\">@bob</a>".to_owned()),
+            Inline::Text(", ".to_owned()),
+            Inline::Emphasis(vec![Inline::Html(
+                "<a href=\"/alice\">@alice</a>".to_owned()
+            )]),
+        ]
+    );
+}
+
+#[test]
+fn custom_inline_parser_priority() {
+    let config = MarkdownParserConfig::default().with_named_custom_inline_parser(
+        "marker",
+        ParserPriority::Before(BuiltinInlineParser::CodeSpan),
+        code_marker("|"),
+    );
+    assert_eq!(
+        paragraph(config, "`code`"),
+        vec![Inline::Text("|code|".to_owned())]
+    );
+
+    let config = MarkdownParserConfig::default().with_named_custom_inline_parser(
+        "marker",
+        ParserPriority::After(BuiltinInlineParser::CodeSpan),
+        code_marker("|"),
+    );
+    assert_eq!(
+        paragraph(config, "`code` and ` alone"),
+        vec![
+            Inline::Code("code".to_owned()),
+            Inline::Text(" and | alone".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn custom_inline_parser_default_position() {
+    let config = MarkdownParserConfig::default()
+        .with_custom_inline_parser(mention())
+        .with_named_custom_inline_parser(
+            "shadowed",
+            ParserPriority::After(BuiltinInlineParser::Text),
+            code_marker("never"),
+        );
+    assert_eq!(
+        config.custom_inline_parser_names().collect::<Vec<_>>(),
+        vec!["custom", "shadowed"]
+    );
+    assert_eq!(
+        paragraph(config.without_custom_inline_parser("shadowed"), "@bob"),
+        vec![Inline::Html("<a href=\"/bob\">@bob</a>".to_owned())]
+    );
+}
@@ -0,0 +1,118 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn paragraph(config: MarkdownParserConfig, input: &str) -> Vec<Inline> {
+    let config = config.with_inline_emoji_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+#[test]
+fn emoji_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "Ship it :rocket:").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(
+            "Ship it :rocket:".to_owned()
+        )])]
+    );
+}
+
+#[test]
+fn emoji_shortcodes() {
+    assert_eq!(
+        paragraph(MarkdownParserConfig::default(), "Ship it :rocket: :+1:"),
+        vec![
+            Inline::Text("Ship it ".to_owned()),
+            Inline::Emoji {
+                shortcode: "rocket".to_owned(),
+                glyph: "🚀".to_owned(),
+            },
+            Inline::Text(" ".to_owned()),
+            Inline::Emoji {
+                shortcode: "+1".to_owned(),
+                glyph: "👍".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn emoji_unknown_shortcode_is_text() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "at 10:30:00 :not_an_emoji:"
+        ),
+        vec![Inline::Text("at 10:30:00 :not_an_emoji:".to_owned())]
+    );
+}
+
+#[test]
+fn emoji_as_text() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default().with_emoji_as_text(),
+            "Ship it :rocket:!"
+        ),
+        vec![Inline::Text("Ship it 🚀!".to_owned())]
+    );
+}
+
+#[test]
+fn emoji_custom_table() {
+    let config = MarkdownParserConfig::default().with_emoji("shipit", "🐿️");
+    assert_eq!(
+        paragraph(config, ":shipit: :tada:"),
+        vec![
+            Inline::Emoji {
+                shortcode: "shipit".to_owned(),
+                glyph: "🐿️".to_owned(),
+            },
+            Inline::Text(" ".to_owned()),
+            Inline::Emoji {
+                shortcode: "tada".to_owned(),
+                glyph: "🎉".to_owned(),
+            },
+        ]
+    );
+
+    let config = MarkdownParserConfig::default()
+        .with_emoji_map([("yes".to_owned(), "✅".to_owned())].into_iter().collect());
+    assert_eq!(
+        paragraph(config, ":yes: :tada:"),
+        vec![
+            Inline::Emoji {
+                shortcode: "yes".to_owned(),
+                glyph: "✅".to_owned(),
+            },
+            Inline::Text(" :tada:".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn emoji_table_sorted_and_unique() {
+    let table = crate::parser::inline::emoji::GITHUB_EMOJI;
+    assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn emoji_common_commit_shortcodes() {
+    for (shortcode, glyph) in [
+        ("adhesive_bandage", "🩹"),
+        ("alembic", "⚗️"),
+        ("ambulance", "🚑"),
+        ("arrow_up_small", "🔼"),
+        ("twisted_rightwards_arrows", "🔀"),
+        ("wheelchair", "♿"),
+    ] {
+        assert_eq!(
+            paragraph(MarkdownParserConfig::default(), &format!(":{shortcode}:")),
+            vec![Inline::Emoji {
+                shortcode: shortcode.to_owned(),
+                glyph: glyph.to_owned(),
+            }]
+        );
+    }
+}
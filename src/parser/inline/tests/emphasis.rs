@@ -191,3 +191,16 @@ fn test_false_positive_prevention() {
         }
     );
 }
+
+#[test]
+fn escaped_emphasis_after_text() {
+    let doc = parse_markdown(MarkdownParserState::default(), "foo \\*bar*").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![Inline::Text(
+                "foo \\*bar*".to_string()
+            )])],
+        }
+    );
+}
@@ -72,3 +72,44 @@ fn test_integration_with_full_parser() {
         }
     }
 }
+
+#[test]
+fn test_environment_variable_behavior() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use crate::parser::{parse_markdown, MarkdownParserState};
+    use std::{cell::RefCell, rc::Rc};
+
+    let config = MarkdownParserConfig::default().with_inline_environment_variable_behavior(
+        ElementBehavior::Map(Rc::new(RefCell::new(Box::new(|inline| match inline {
+            Inline::Text(name) => Inline::Code(name),
+            other => other,
+        })))),
+    );
+    let result = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "Set PKG_CONFIG_PATH first",
+    )
+    .unwrap();
+    assert_eq!(
+        result.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::Text("Set ".to_string()),
+            Inline::Code("PKG_CONFIG_PATH".to_string()),
+            Inline::Text(" first".to_string()),
+        ])]
+    );
+
+    let config = MarkdownParserConfig::default()
+        .with_inline_environment_variable_behavior(ElementBehavior::Ignore);
+    let result = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "Set PKG_CONFIG_PATH first",
+    )
+    .unwrap();
+    assert_eq!(
+        result.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(
+            "Set PKG_CONFIG_PATH first".to_string()
+        )])]
+    );
+}
@@ -0,0 +1,152 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+use std::rc::Rc;
+
+fn paragraph(config: MarkdownParserConfig, input: &str) -> Vec<Inline> {
+    let config = config
+        .with_inline_mention_behavior(ElementBehavior::Parse)
+        .with_inline_issue_reference_behavior(ElementBehavior::Parse)
+        .with_inline_commit_reference_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+fn reference(kind: GitHubReferenceKind) -> Inline {
+    Inline::GitHubReference(GitHubReference {
+        kind,
+        destination: None,
+    })
+}
+
+fn issue(repository: Option<&str>, number: u64) -> Inline {
+    reference(GitHubReferenceKind::Issue {
+        repository: repository.map(str::to_owned),
+        number,
+    })
+}
+
+fn commit(repository: Option<&str>, sha: &str) -> Inline {
+    reference(GitHubReferenceKind::Commit {
+        repository: repository.map(str::to_owned),
+        sha: sha.to_owned(),
+    })
+}
+
+#[test]
+fn github_references_disabled_by_default() {
+    let input = "Fixed #12 in a5c3785, thanks @alice";
+    let doc = parse_markdown(MarkdownParserState::default(), input).unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(input.to_owned())])]
+    );
+}
+
+#[test]
+fn mentions() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "thanks @alice-b and @org/core_team!"
+        ),
+        vec![
+            Inline::Text("thanks ".to_owned()),
+            reference(GitHubReferenceKind::Mention("alice-b".to_owned())),
+            Inline::Text(" and ".to_owned()),
+            reference(GitHubReferenceKind::Mention("org/core_team".to_owned())),
+            Inline::Text("!".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn mention_not_inside_email() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "mail foo@example.com or @-x"
+        ),
+        vec![Inline::Text("mail foo@example.com or @-x".to_owned())]
+    );
+}
+
+#[test]
+fn issue_references() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "Fixes #12 and owner/repo.rs#45."
+        ),
+        vec![
+            Inline::Text("Fixes ".to_owned()),
+            issue(None, 12),
+            Inline::Text(" and ".to_owned()),
+            issue(Some("owner/repo.rs"), 45),
+            Inline::Text(".".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn issue_reference_boundaries() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "C#1 #12a #012 https://x.org/a/b#3"
+        ),
+        vec![Inline::Text("C#1 #12a #012 https://x.org/a/b#3".to_owned())]
+    );
+}
+
+#[test]
+fn commit_references() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "In a5c3785 (owner/repo@0f1e2d3c)"
+        ),
+        vec![
+            Inline::Text("In ".to_owned()),
+            commit(None, "a5c3785"),
+            Inline::Text(" (".to_owned()),
+            commit(Some("owner/repo"), "0f1e2d3c"),
+            Inline::Text(")".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn commit_reference_needs_letter_and_digit() {
+    assert_eq!(
+        paragraph(
+            MarkdownParserConfig::default(),
+            "deadbeef 1234567 a5c37 xa5c3785"
+        ),
+        vec![Inline::Text("deadbeef 1234567 a5c37 xa5c3785".to_owned())]
+    );
+}
+
+#[test]
+fn github_reference_resolver() {
+    let config =
+        MarkdownParserConfig::default().with_github_reference_resolver(Rc::new(|reference| {
+            match reference.kind {
+                GitHubReferenceKind::Mention(_) => None,
+                _ => Some(reference.github_url("owner/repo")),
+            }
+        }));
+    assert_eq!(
+        paragraph(config, "#7 @bob"),
+        vec![
+            Inline::GitHubReference(GitHubReference {
+                kind: GitHubReferenceKind::Issue {
+                    repository: None,
+                    number: 7,
+                },
+                destination: Some("https://github.com/owner/repo/issues/7".to_owned()),
+            }),
+            Inline::Text(" ".to_owned()),
+            reference(GitHubReferenceKind::Mention("bob".to_owned())),
+        ]
+    );
+}
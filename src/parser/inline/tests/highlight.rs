@@ -0,0 +1,52 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn paragraph(input: &str) -> Vec<Inline> {
+    let config = MarkdownParserConfig::default()
+        .with_inline_highlight_behavior(ElementBehavior::Parse)
+        .with_inline_insert_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+#[test]
+fn highlight_and_insert_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "==key== ++new++").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(
+            "==key== ++new++".to_owned()
+        )])]
+    );
+}
+
+#[test]
+fn highlight() {
+    assert_eq!(
+        paragraph("a ==key **point**== here"),
+        vec![
+            Inline::Text("a ".to_owned()),
+            Inline::Highlight(vec![
+                Inline::Text("key ".to_owned()),
+                Inline::Strong(vec![Inline::Text("point".to_owned())]),
+            ]),
+            Inline::Text(" here".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn insert() {
+    assert_eq!(
+        paragraph("++new text++"),
+        vec![Inline::Insert(vec![Inline::Text("new text".to_owned())])]
+    );
+}
+
+#[test]
+fn delimiters_next_to_whitespace_stay_text() {
+    assert_eq!(
+        paragraph("a == b == c, C++ and C++"),
+        vec![Inline::Text("a == b == c, C++ and C++".to_owned())]
+    );
+}
@@ -15,10 +15,13 @@ fn image_with_attributes() {
                 destination: "/url".to_owned(),
                 title: None,
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: Some("100pt".to_owned()),
-                    height: Some("50pt".to_owned()),
-                }),
+                attributes: Attributes {
+                    pairs: vec![
+                        ("width".to_owned(), "100pt".to_owned()),
+                        ("height".to_owned(), "50pt".to_owned()),
+                    ],
+                    ..Default::default()
+                },
             })])]
         }
     );
@@ -38,10 +41,13 @@ fn image_with_attributes_and_title() {
                 destination: "/url".to_owned(),
                 title: Some("title".to_owned()),
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: Some("100pt".to_owned()),
-                    height: Some("50pt".to_owned()),
-                }),
+                attributes: Attributes {
+                    pairs: vec![
+                        ("width".to_owned(), "100pt".to_owned()),
+                        ("height".to_owned(), "50pt".to_owned()),
+                    ],
+                    ..Default::default()
+                },
             })])]
         }
     );
@@ -61,20 +67,20 @@ fn image_with_single_attribute() {
                 destination: "/url".to_owned(),
                 title: None,
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: Some("100pt".to_owned()),
-                    height: None,
-                }),
+                attributes: Attributes {
+                    pairs: vec![("width".to_owned(), "100pt".to_owned()),],
+                    ..Default::default()
+                },
             })])]
         }
     );
 }
 
 #[test]
-fn image_with_invalid_attribute() {
+fn image_with_other_attribute() {
     let doc = parse_markdown(
         MarkdownParserState::default(),
-        r#"![foo](/url){width="100pt" invalid="50pt"}"#,
+        r#"![foo](/url){width="100pt" other="50pt"}"#,
     )
     .unwrap();
     assert_eq!(
@@ -84,10 +90,13 @@ fn image_with_invalid_attribute() {
                 destination: "/url".to_owned(),
                 title: None,
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: Some("100pt".to_owned()),
-                    height: None,
-                }),
+                attributes: Attributes {
+                    pairs: vec![
+                        ("width".to_owned(), "100pt".to_owned()),
+                        ("other".to_owned(), "50pt".to_owned()),
+                    ],
+                    ..Default::default()
+                },
             })])]
         }
     );
@@ -103,10 +112,7 @@ fn image_with_empty_attributes() {
                 destination: "/url".to_owned(),
                 title: None,
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: None,
-                    height: None,
-                }),
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -15,10 +15,13 @@ fn image_with_unquoted_attributes() {
                 destination: "/url".to_owned(),
                 title: None,
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: Some("100pt".to_owned()),
-                    height: Some("50pt".to_owned()),
-                }),
+                attributes: Attributes {
+                    pairs: vec![
+                        ("width".to_owned(), "100pt".to_owned()),
+                        ("height".to_owned(), "50pt".to_owned()),
+                    ],
+                    ..Default::default()
+                },
             })])]
         }
     );
@@ -38,10 +41,13 @@ fn image_with_mixed_attributes() {
                 destination: "/url".to_owned(),
                 title: None,
                 alt: "foo".to_owned(),
-                attr: Some(ImageAttributes {
-                    width: Some("100pt".to_owned()),
-                    height: Some("50pt".to_owned()),
-                }),
+                attributes: Attributes {
+                    pairs: vec![
+                        ("width".to_owned(), "100pt".to_owned()),
+                        ("height".to_owned(), "50pt".to_owned()),
+                    ],
+                    ..Default::default()
+                },
             })])]
         }
     );
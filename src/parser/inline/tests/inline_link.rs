@@ -1,5 +1,5 @@
 use crate::ast::*;
-use crate::parser::{parse_markdown, MarkdownParserState};
+use crate::parser::{parse_markdown, MarkdownParserConfig, MarkdownParserState};
 
 #[test]
 fn inline_link_with_nested_image() {
@@ -20,8 +20,9 @@ fn inline_link_with_nested_image() {
                     destination: "https://img.shields.io/badge/userstyles-green".to_owned(),
                     title: None,
                     alt: "userstyles".to_owned(),
-                    attr: None,
-                })]
+                    attributes: Attributes::default(),
+                })],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -36,7 +37,8 @@ fn inline_link1() {
             blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
                 destination: "/url".to_owned(),
                 title: Some("title".to_owned()),
-                children: vec![Inline::Text("foo".to_owned())]
+                children: vec![Inline::Text("foo".to_owned())],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -51,7 +53,8 @@ fn inline_link2() {
             blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
                 destination: "train.jpg".to_owned(),
                 title: None,
-                children: vec![Inline::Text("foo".to_owned())]
+                children: vec![Inline::Text("foo".to_owned())],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -66,7 +69,8 @@ fn inline_link3() {
             blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
                 destination: "url".to_owned(),
                 title: None,
-                children: vec![Inline::Text("foo".to_owned())]
+                children: vec![Inline::Text("foo".to_owned())],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -91,8 +95,9 @@ fn inline_link_badge_pattern() {
                     destination: "https://travis-ci.org/user/repo.svg".to_owned(),
                     title: None,
                     alt: "Build Status".to_owned(),
-                    attr: None,
-                })]
+                    attributes: Attributes::default(),
+                })],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -118,9 +123,11 @@ fn inline_link_with_nested_brackets() {
                     Inline::LinkReference(LinkReference {
                         label: vec![Inline::Text("nested".to_owned())],
                         text: vec![Inline::Text("nested".to_owned())],
+                        image: false,
                     }),
                     Inline::Text(" more".to_owned()),
-                ]
+                ],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -149,6 +156,7 @@ fn inline_link_deeply_nested_brackets() {
                             Inline::LinkReference(LinkReference {
                                 label: vec![Inline::Text("c".to_owned())],
                                 text: vec![Inline::Text("c".to_owned())],
+                                image: false,
                             }),
                             Inline::Text(" d".to_owned()),
                         ],
@@ -157,12 +165,15 @@ fn inline_link_deeply_nested_brackets() {
                             Inline::LinkReference(LinkReference {
                                 label: vec![Inline::Text("c".to_owned())],
                                 text: vec![Inline::Text("c".to_owned())],
+                                image: false,
                             }),
                             Inline::Text(" d".to_owned()),
                         ],
+                        image: false,
                     }),
                     Inline::Text(" e".to_owned()),
-                ]
+                ],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -188,16 +199,17 @@ fn inline_link_multiple_images() {
                         destination: "url1".to_owned(),
                         title: None,
                         alt: "a".to_owned(),
-                        attr: None,
+                        attributes: Attributes::default(),
                     }),
                     Inline::Text(" ".to_owned()),
                     Inline::Image(Image {
                         destination: "url2".to_owned(),
                         title: None,
                         alt: "b".to_owned(),
-                        attr: None,
+                        attributes: Attributes::default(),
                     }),
-                ]
+                ],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -218,7 +230,8 @@ fn inline_link_with_escaped_closing_bracket() {
             blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
                 destination: "https://example.com".to_owned(),
                 title: None,
-                children: vec![Inline::Text("text with ] bracket".to_owned())]
+                children: vec![Inline::Text("text with ] bracket".to_owned())],
+                attributes: Attributes::default(),
             })])]
         }
     );
@@ -293,8 +306,48 @@ fn inline_link_empty_nested_brackets() {
             blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
                 destination: "https://example.com".to_owned(),
                 title: None,
-                children: vec![Inline::Text("text [] more".to_owned())]
+                children: vec![Inline::Text("text [] more".to_owned())],
+                attributes: Attributes::default(),
             })])]
         }
     );
 }
+
+#[test]
+fn inline_link_attributes() {
+    let config = MarkdownParserConfig::default().with_allow_attributes();
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "[docs](/docs){.external target=_blank} [plain](/plain) {#not-attached}",
+    )
+    .unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::Link(Link {
+                destination: "/docs".to_owned(),
+                title: None,
+                children: vec![Inline::Text("docs".to_owned())],
+                attributes: Attributes {
+                    classes: vec!["external".to_owned()],
+                    pairs: vec![("target".to_owned(), "_blank".to_owned())],
+                    ..Default::default()
+                },
+            }),
+            Inline::Text(" ".to_owned()),
+            Inline::Link(Link {
+                destination: "/plain".to_owned(),
+                title: None,
+                children: vec![Inline::Text("plain".to_owned())],
+                attributes: Attributes::default(),
+            }),
+            Inline::Text(" {#not-attached}".to_owned()),
+        ])]
+    );
+
+    let doc = parse_markdown(MarkdownParserState::default(), "[docs](/docs){.external}").unwrap();
+    let Block::Paragraph(content) = &doc.blocks[0] else {
+        panic!("expected a paragraph");
+    };
+    assert_eq!(content[1], Inline::Text("{.external}".to_owned()));
+}
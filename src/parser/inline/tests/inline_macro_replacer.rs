@@ -1,7 +1,7 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    ast::{Block, Document, Inline},
+    ast::{Block, Document, Inline, InlineMathDelimiter},
     parser::{
         config::{InlineMacroReplacerFn, MarkdownParserConfig},
         parse_markdown, MarkdownParserState,
@@ -34,7 +34,10 @@ fn test_inline_macro_replacer() {
             Block::MacroBlock("macro block".to_string()),
             Block::Paragraph(vec![
                 Inline::Text("Hello, ".to_string()),
-                Inline::Latex("replacement".to_string()),
+                Inline::Latex {
+                    delimiter: InlineMathDelimiter::Dollar,
+                    content: "replacement".to_string(),
+                },
                 Inline::Text(". Nested: OUTER. and another replacement".to_string()),
             ]),
         ],
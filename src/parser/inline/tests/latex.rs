@@ -1,4 +1,6 @@
+use super::paragraph;
 use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
 use crate::parser::{parse_markdown, MarkdownParserState};
 
 #[test]
@@ -7,9 +9,10 @@ fn inline_latex() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Latex(
-                "a^2 + b^2 = c^2".to_string()
-            )])],
+            blocks: vec![Block::Paragraph(vec![Inline::Latex {
+                delimiter: InlineMathDelimiter::Dollar,
+                content: "a^2 + b^2 = c^2".to_string()
+            }])],
         }
     );
 }
@@ -22,9 +25,129 @@ fn inline_latex_with_text() {
         Document {
             blocks: vec![Block::Paragraph(vec![
                 Inline::Text("The formula is ".to_string()),
-                Inline::Latex("E=mc^2".to_string()),
+                Inline::Latex {
+                    delimiter: InlineMathDelimiter::Dollar,
+                    content: "E=mc^2".to_string()
+                },
                 Inline::Text(".".to_string()),
             ])],
         }
     );
 }
+
+#[test]
+fn inline_latex_unterminated_dollar_is_text() {
+    assert_eq!(
+        paragraph(MarkdownParserConfig::default(), "costs $5 only"),
+        vec![Inline::Text("costs $5 only".to_string())]
+    );
+}
+
+#[test]
+fn inline_latex_ignore() {
+    let config =
+        MarkdownParserConfig::default().with_inline_latex_behavior(ElementBehavior::Ignore);
+    assert_eq!(
+        paragraph(config, "from $5 to $10, *or* $x$"),
+        vec![
+            Inline::Text("from $5 to $10, ".to_string()),
+            Inline::Emphasis(vec![Inline::Text("or".to_string())]),
+            Inline::Text(" $x$".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn inline_latex_skip() {
+    let config = MarkdownParserConfig::default().with_inline_latex_behavior(ElementBehavior::Skip);
+    assert_eq!(
+        paragraph(config, "a $x$ b"),
+        vec![
+            Inline::Text("a ".to_string()),
+            Inline::Empty,
+            Inline::Text(" b".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn inline_latex_strict() {
+    let config = || MarkdownParserConfig::default().with_strict_dollar_math();
+    assert_eq!(
+        paragraph(config(), "$5 and $10"),
+        vec![Inline::Text("$5 and $10".to_string())]
+    );
+    assert_eq!(
+        paragraph(config(), "$ x $ and $y $"),
+        vec![Inline::Text("$ x $ and $y $".to_string())]
+    );
+    assert_eq!(
+        paragraph(config(), "Let $x = 2$."),
+        vec![
+            Inline::Text("Let ".to_string()),
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::Dollar,
+                content: "x = 2".to_string()
+            },
+            Inline::Text(".".to_string()),
+        ]
+    );
+    // A closing `$` followed by a digit or preceded by whitespace is skipped
+    assert_eq!(
+        paragraph(config(), "$a$1 $ b$ c"),
+        vec![
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::Dollar,
+                content: "a$1 $ b".to_string()
+            },
+            Inline::Text(" c".to_string())
+        ]
+    );
+    assert_eq!(
+        paragraph(config(), "$a\\$b$"),
+        vec![Inline::Latex {
+            delimiter: InlineMathDelimiter::Dollar,
+            content: "a\\$b".to_string()
+        }]
+    );
+}
+
+#[test]
+fn inline_latex_parentheses() {
+    let config = MarkdownParserConfig::default()
+        .with_inline_latex_parentheses_behavior(ElementBehavior::Parse);
+    assert_eq!(
+        paragraph(config, r"Let \(x = (a + b)\)."),
+        vec![
+            Inline::Text("Let ".to_string()),
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::Parentheses,
+                content: "x = (a + b)".to_string()
+            },
+            Inline::Text(".".to_string()),
+        ]
+    );
+
+    // Disabled by default: `\(` stays text
+    assert_eq!(
+        paragraph(MarkdownParserConfig::default(), r"Let \(x\)."),
+        vec![Inline::Text(r"Let \(x\).".to_string())]
+    );
+}
+
+#[test]
+fn inline_latex_code() {
+    let config =
+        MarkdownParserConfig::default().with_inline_latex_code_behavior(ElementBehavior::Parse);
+    assert_eq!(
+        paragraph(config, "Let $`a^2 + b$c`$ hold"),
+        vec![
+            Inline::Text("Let ".to_string()),
+            Inline::Latex {
+                delimiter: InlineMathDelimiter::DollarBacktick,
+                content: "a^2 + b$c".to_string()
+            },
+            Inline::Text(" hold".to_string()),
+        ]
+    );
+}
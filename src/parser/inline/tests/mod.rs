@@ -1,10 +1,19 @@
+use crate::ast::{Block, Inline};
+use crate::parser::config::MarkdownParserConfig;
+use crate::parser::{parse_markdown, MarkdownParserState};
+
 mod autolink;
+mod citation;
 mod code_span;
 mod consecutive_text_elements;
+mod custom_parser;
+mod emoji;
 mod emphasis;
 mod environment_variable;
 mod footnote_reference;
+mod github_reference;
 mod hard_newline;
+mod highlight;
 mod html_entity;
 mod image;
 mod image_attr;
@@ -13,4 +22,16 @@ mod inline_link;
 mod latex;
 mod inline_macro_replacer;
 mod reference_link;
+mod script;
+mod span;
 mod strikethrough;
+mod wiki_link;
+
+/// The inlines of `input`, which must parse to a single paragraph
+fn paragraph(config: MarkdownParserConfig, input: &str) -> Vec<Inline> {
+    let doc = parse_markdown(MarkdownParserState::with_config(config), input).unwrap();
+    let [Block::Paragraph(content)] = doc.blocks.as_slice() else {
+        panic!("expected a single paragraph, got {:?}", doc.blocks);
+    };
+    content.clone()
+}
@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
 use crate::parser::{parse_markdown, MarkdownParserState};
 
 #[test]
@@ -11,6 +12,7 @@ fn reference_link1() {
                 LinkReference {
                     label: vec![Inline::Text("label".to_owned())],
                     text: vec![Inline::Text("text".to_owned())],
+                    image: false,
                 }
             )])],
         }
@@ -26,7 +28,8 @@ fn reference_link2() {
             blocks: vec![Block::Paragraph(vec![Inline::LinkReference(
                 LinkReference {
                     label: vec![Inline::Text("text".to_owned())],
-                    text: vec![Inline::Text("text".to_owned())]
+                    text: vec![Inline::Text("text".to_owned())],
+                    image: false,
                 }
             )])],
         }
@@ -42,9 +45,53 @@ fn reference_link3() {
             blocks: vec![Block::Paragraph(vec![Inline::LinkReference(
                 LinkReference {
                     label: vec![Inline::Text("text".to_owned())],
-                    text: vec![Inline::Text("text".to_owned())]
+                    text: vec![Inline::Text("text".to_owned())],
+                    image: false,
                 }
             )])],
         }
     );
 }
+
+#[test]
+fn image_reference() {
+    let doc = parse_markdown(MarkdownParserState::default(), "![alt][label] \\![text]").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("label".to_owned())],
+                    text: vec![Inline::Text("alt".to_owned())],
+                    image: true,
+                }),
+                Inline::Text(" \\!".to_owned()),
+                Inline::LinkReference(LinkReference {
+                    label: vec![Inline::Text("text".to_owned())],
+                    text: vec![Inline::Text("text".to_owned())],
+                    image: false,
+                }),
+            ])],
+        }
+    );
+}
+
+#[test]
+fn image_reference_defers_to_other_brackets() {
+    let config = MarkdownParserConfig::default().with_inline_span_behavior(ElementBehavior::Parse);
+    assert_eq!(
+        super::paragraph(config, "![^1] ![text]{.c}"),
+        vec![
+            Inline::Text("!".to_owned()),
+            Inline::FootnoteReference("1".to_owned()),
+            Inline::Text(" !".to_owned()),
+            Inline::Span(Span {
+                attributes: Attributes {
+                    classes: vec!["c".to_owned()],
+                    ..Default::default()
+                },
+                children: vec![Inline::Text("text".to_owned())],
+            }),
+        ]
+    );
+}
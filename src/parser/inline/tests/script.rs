@@ -0,0 +1,86 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn paragraph(input: &str) -> Vec<Inline> {
+    let config = MarkdownParserConfig::default()
+        .with_inline_superscript_behavior(ElementBehavior::Parse)
+        .with_inline_subscript_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+#[test]
+fn scripts_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "2^10^ H~2~O").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Text(
+            "2^10^ H~2~O".to_owned()
+        )])]
+    );
+}
+
+#[test]
+fn superscript() {
+    assert_eq!(
+        paragraph("2^10^ is *big*"),
+        vec![
+            Inline::Text("2".to_owned()),
+            Inline::Superscript(vec![Inline::Text("10".to_owned())]),
+            Inline::Text(" is ".to_owned()),
+            Inline::Emphasis(vec![Inline::Text("big".to_owned())]),
+        ]
+    );
+}
+
+#[test]
+fn superscript_without_whitespace() {
+    assert_eq!(
+        paragraph("x^2 + y^2"),
+        vec![Inline::Text("x^2 + y^2".to_owned())]
+    );
+}
+
+#[test]
+fn superscript_with_escaped_space() {
+    assert_eq!(
+        paragraph(r"^a\ b^"),
+        vec![Inline::Superscript(vec![Inline::Text(r"a\ b".to_owned())])]
+    );
+}
+
+#[test]
+fn subscript() {
+    assert_eq!(
+        paragraph("H~2~O"),
+        vec![
+            Inline::Text("H".to_owned()),
+            Inline::Subscript(vec![Inline::Text("2".to_owned())]),
+            Inline::Text("O".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn subscript_and_strikethrough() {
+    assert_eq!(
+        paragraph("~~gone~~ and ~i~"),
+        vec![
+            Inline::Strikethrough(vec![Inline::Text("gone".to_owned())]),
+            Inline::Text(" and ".to_owned()),
+            Inline::Subscript(vec![Inline::Text("i".to_owned())]),
+        ]
+    );
+}
+
+#[test]
+fn subscript_inside_strikethrough() {
+    assert_eq!(
+        paragraph("~~H~2~O~~"),
+        vec![Inline::Strikethrough(vec![
+            Inline::Text("H".to_owned()),
+            Inline::Subscript(vec![Inline::Text("2".to_owned())]),
+            Inline::Text("O".to_owned()),
+        ])]
+    );
+}
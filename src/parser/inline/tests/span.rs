@@ -0,0 +1,87 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn paragraph(input: &str) -> Vec<Inline> {
+    let config = MarkdownParserConfig::default().with_inline_span_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+#[test]
+fn span_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "[Ctrl]{.kbd}").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::LinkReference(LinkReference {
+                label: vec![Inline::Text("Ctrl".to_owned())],
+                text: vec![Inline::Text("Ctrl".to_owned())],
+                image: false,
+            }),
+            Inline::Text("{.kbd}".to_owned()),
+        ])]
+    );
+}
+
+#[test]
+fn span_with_attributes() {
+    assert_eq!(
+        paragraph("Press [Ctrl]{.kbd} and [*bonjour*]{#greeting lang=fr}."),
+        vec![
+            Inline::Text("Press ".to_owned()),
+            Inline::Span(Span {
+                attributes: Attributes {
+                    classes: vec!["kbd".to_owned()],
+                    ..Default::default()
+                },
+                children: vec![Inline::Text("Ctrl".to_owned())],
+            }),
+            Inline::Text(" and ".to_owned()),
+            Inline::Span(Span {
+                attributes: Attributes {
+                    id: Some("greeting".to_owned()),
+                    pairs: vec![("lang".to_owned(), "fr".to_owned())],
+                    ..Default::default()
+                },
+                children: vec![Inline::Emphasis(vec![Inline::Text("bonjour".to_owned())])],
+            }),
+            Inline::Text(".".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn span_with_empty_attributes() {
+    assert_eq!(
+        paragraph("[text]{}"),
+        vec![Inline::Span(Span {
+            attributes: Attributes::default(),
+            children: vec![Inline::Text("text".to_owned())],
+        })]
+    );
+}
+
+#[test]
+fn span_is_not_a_link_reference() {
+    let content = paragraph("[text]{.highlight} [ref] [ref] {.x} [text](/url)");
+    assert!(matches!(content[0], Inline::Span(_)));
+    assert!(matches!(content[2], Inline::LinkReference(_)));
+    assert!(matches!(content[4], Inline::LinkReference(_)));
+    assert_eq!(content[5], Inline::Text(" {.x} ".to_owned()));
+    assert!(matches!(content[6], Inline::Link(_)));
+}
+
+#[test]
+fn span_with_invalid_attributes_is_text() {
+    assert_eq!(
+        paragraph("[text]{not valid}"),
+        vec![
+            Inline::LinkReference(LinkReference {
+                label: vec![Inline::Text("text".to_owned())],
+                text: vec![Inline::Text("text".to_owned())],
+                image: false,
+            }),
+            Inline::Text("{not valid}".to_owned()),
+        ]
+    );
+}
@@ -0,0 +1,78 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn paragraph(input: &str) -> Vec<Inline> {
+    let config =
+        MarkdownParserConfig::default().with_inline_wiki_link_behavior(ElementBehavior::Parse);
+    super::paragraph(config, input)
+}
+
+fn wiki_link(target: &str, fragment: Option<&str>, alias: Option<&str>, embed: bool) -> Inline {
+    Inline::WikiLink(WikiLink {
+        target: target.to_owned(),
+        fragment: fragment.map(str::to_owned),
+        alias: alias.map(str::to_owned),
+        embed,
+    })
+}
+
+#[test]
+fn wiki_link_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "[[Page]]").unwrap();
+    let Block::Paragraph(content) = &doc.blocks[0] else {
+        panic!("expected a paragraph");
+    };
+    assert!(!content
+        .iter()
+        .any(|inline| matches!(inline, Inline::WikiLink(_))));
+}
+
+#[test]
+fn wiki_link_forms() {
+    assert_eq!(
+        paragraph("See [[Home Page]], [[Setup#Linux|install notes]] and ![[diagram.png]]."),
+        vec![
+            Inline::Text("See ".to_owned()),
+            wiki_link("Home Page", None, None, false),
+            Inline::Text(", ".to_owned()),
+            wiki_link("Setup", Some("Linux"), Some("install notes"), false),
+            Inline::Text(" and ".to_owned()),
+            wiki_link("diagram.png", None, None, true),
+            Inline::Text(".".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn wiki_link_same_page_fragment() {
+    assert_eq!(
+        paragraph("[[#Usage]]"),
+        vec![wiki_link("", Some("Usage"), None, false)]
+    );
+}
+
+#[test]
+fn wiki_link_nested_in_emphasis() {
+    assert_eq!(
+        paragraph("*[[Page|alias]]*"),
+        vec![Inline::Emphasis(vec![wiki_link(
+            "Page",
+            None,
+            Some("alias"),
+            false
+        )])]
+    );
+}
+
+#[test]
+fn wiki_link_invalid() {
+    for input in ["[[]]", "[[ ]]", "[[#]]", "[[Page\nName]]", "[[Page]"] {
+        assert!(
+            !paragraph(input)
+                .iter()
+                .any(|inline| matches!(inline, Inline::WikiLink(_))),
+            "{input:?}"
+        );
+    }
+}
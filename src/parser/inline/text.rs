@@ -1,9 +1,13 @@
+use crate::parser::config::ElementBehavior;
+use crate::parser::inline::autolink::{extended_autolink, extended_autolink_after};
+use crate::parser::inline::citation::starts_in_text_citation;
+use crate::parser::inline::github_reference::starts_github_reference;
 use crate::parser::MarkdownParserState;
 use crate::{ast::Inline, parser::util::conditional_inline_unit};
 use nom::{
     branch::alt,
-    character::complete::{anychar, char, one_of},
-    combinator::{map, not, peek, recognize, value},
+    character::complete::{char, one_of},
+    combinator::{map, not, value},
     multi::many1,
     sequence::preceded,
     IResult, Parser,
@@ -16,18 +20,20 @@ pub(crate) fn text<'a>(
     move |input: &'a str| {
         map(
             many1(alt((
-                map(escaped_char, |c| c.to_string()),
                 map(
-                    crate::parser::inline::html_entity::html_entity(state.clone()),
+                    |i| {
+                        // Escapes are rare, so check for constructs only here
+                        let (rest, c) = escaped_char(i)?;
+                        is_text(state.clone()).parse(i)?;
+                        Ok((rest, c))
+                    },
                     |c| c.to_string(),
                 ),
                 map(
-                    recognize(many1(preceded(
-                        peek(is_text(state.clone())),
-                        preceded(not(char('$')), anychar),
-                    ))),
+                    crate::parser::inline::html_entity::html_entity(state.clone()),
                     |c| c.to_string(),
                 ),
+                map(plain_text(state.clone()), |c| c.to_string()),
             ))),
             |vec| Inline::Text(vec.join("")),
         )
@@ -35,87 +41,205 @@ pub(crate) fn text<'a>(
     }
 }
 
+/// A run of text characters
+///
+/// Extended autolinks, in-text citations and GitHub references depend on the
+/// preceding character, so they are checked here rather than in [`not_a_text`].
+/// Only the enabled ones are checked. Backslash escapes within the run are kept
+/// as they are, but the escaped character does not start a construct.
+fn plain_text<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    let enabled = |behavior: &ElementBehavior<Inline>| !matches!(behavior, ElementBehavior::Ignore);
+    let config = &state.config;
+    let extended_autolinks = enabled(&config.inline_extended_autolink_behavior);
+    let references = enabled(&config.inline_citation_behavior)
+        || enabled(&config.inline_mention_behavior)
+        || enabled(&config.inline_issue_reference_behavior)
+        || enabled(&config.inline_commit_reference_behavior);
+    let mut is_text = is_text(state.clone());
+    move |input: &'a str| {
+        let mut prev = None;
+        let mut len = 0;
+        let mut escaped = false;
+        for (i, c) in input.char_indices() {
+            let rest = &input[i..];
+            if escaped {
+                escaped = false;
+            } else {
+                if is_text.parse(rest).is_err() {
+                    break;
+                }
+                if extended_autolinks {
+                    let link = match prev {
+                        Some(prev) => extended_autolink_after(prev)(rest),
+                        None => extended_autolink(rest),
+                    };
+                    if link.is_ok() {
+                        break;
+                    }
+                }
+                if references
+                    && (starts_in_text_citation(&state, prev, rest)
+                        || starts_github_reference(&state, prev, rest))
+                {
+                    break;
+                }
+                escaped = c == '\\' && escaped_char(rest).is_ok();
+            }
+            prev = Some(c);
+            len = i + c.len_utf8();
+        }
+        if len == 0 {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Many1,
+            )));
+        }
+        Ok((&input[len..], &input[..len]))
+    }
+}
+
 fn is_text<'a>(state: Rc<MarkdownParserState>) -> impl FnMut(&'a str) -> IResult<&'a str, ()> {
-    move |input: &'a str| not(not_a_text(state.clone())).parse(input)
+    let mut not_a_text = not_a_text(state);
+    move |input: &'a str| not(&mut not_a_text).parse(input)
 }
 
 fn not_a_text<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<()>> {
-    move |input: &'a str| {
+    let mut parser = alt((
         alt((
-            alt((
-                conditional_inline_unit(
-                    state.config.inline_autolink_behavior.clone(),
-                    value((), crate::parser::inline::autolink::autolink),
-                ),
-                conditional_inline_unit(
-                    state.config.inline_reference_link_behavior.clone(),
-                    value(
-                        (),
-                        crate::parser::inline::reference_link::reference_link(state.clone()),
-                    ),
-                ),
-                conditional_inline_unit(
-                    state.config.inline_hard_newline_behavior.clone(),
-                    value((), crate::parser::inline::hard_newline::hard_newline),
+            conditional_inline_unit(
+                state.config.inline_autolink_behavior.clone(),
+                value((), crate::parser::inline::autolink::autolink),
+            ),
+            conditional_inline_unit(
+                state.config.inline_wiki_link_behavior.clone(),
+                value((), crate::parser::inline::wiki_link::wiki_link),
+            ),
+            conditional_inline_unit(
+                state.config.inline_span_behavior.clone(),
+                value((), crate::parser::inline::span::span(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_citation_behavior.clone(),
+                value((), crate::parser::inline::citation::bracketed_citation),
+            ),
+            conditional_inline_unit(
+                state.config.inline_reference_link_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::reference_link::reference_link(state.clone()),
                 ),
-                conditional_inline_unit(
-                    state.config.inline_text_behavior.clone(),
-                    value(
-                        (),
-                        crate::parser::inline::html_entity::html_entity(state.clone()),
-                    ),
+            ),
+            conditional_inline_unit(
+                state.config.inline_hard_newline_behavior.clone(),
+                value((), crate::parser::inline::hard_newline::hard_newline),
+            ),
+            conditional_inline_unit(
+                state.config.inline_text_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::html_entity::html_entity(state.clone()),
                 ),
-                conditional_inline_unit(
-                    state.config.inline_image_behavior.clone(),
-                    value((), crate::parser::inline::image::image(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_image_behavior.clone(),
+                value((), crate::parser::inline::image::image(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_reference_link_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::reference_link::image_reference(state.clone()),
                 ),
-            )),
-            alt((
-                conditional_inline_unit(
-                    state.config.inline_link_behavior.clone(),
-                    value(
-                        (),
-                        crate::parser::inline::inline_link::inline_link(state.clone()),
-                    ),
+            ),
+        )),
+        alt((
+            conditional_inline_unit(
+                state.config.inline_link_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::inline_link::inline_link(state.clone()),
                 ),
-                conditional_inline_unit(
-                    state.config.inline_code_span_behavior.clone(),
-                    value((), crate::parser::inline::code_span::code_span),
+            ),
+            conditional_inline_unit(
+                state.config.inline_code_span_behavior.clone(),
+                value((), crate::parser::inline::code_span::code_span),
+            ),
+            conditional_inline_unit(
+                state.config.inline_emphasis_behavior.clone(),
+                value((), crate::parser::inline::emphasis::emphasis(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_footnote_reference_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::footnote_reference::footnote_reference,
                 ),
-                conditional_inline_unit(
-                    state.config.inline_emphasis_behavior.clone(),
-                    value((), crate::parser::inline::emphasis::emphasis(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_strikethrough_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::strikethrough::strikethrough(state.clone()),
                 ),
-                conditional_inline_unit(
-                    state.config.inline_footnote_reference_behavior.clone(),
-                    value(
-                        (),
-                        crate::parser::inline::footnote_reference::footnote_reference,
-                    ),
+            ),
+            conditional_inline_unit(
+                state.config.inline_superscript_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::script::superscript(state.clone()),
                 ),
-                conditional_inline_unit(
-                    state.config.inline_strikethrough_behavior.clone(),
-                    value(
-                        (),
-                        crate::parser::inline::strikethrough::strikethrough(state.clone()),
-                    ),
+            ),
+            conditional_inline_unit(
+                state.config.inline_subscript_behavior.clone(),
+                value((), crate::parser::inline::script::subscript(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_highlight_behavior.clone(),
+                value(
+                    (),
+                    crate::parser::inline::highlight::highlight(state.clone()),
                 ),
-            )),
-            map(
+            ),
+            conditional_inline_unit(
+                state.config.inline_insert_behavior.clone(),
+                value((), crate::parser::inline::highlight::insert(state.clone())),
+            ),
+        )),
+        alt((
+            conditional_inline_unit(
+                state.config.inline_environment_variable_behavior.clone(),
                 value(
                     (),
-                    map(
-                        crate::parser::inline::environment_variable::environment_variable,
-                        |_| (),
-                    ),
+                    crate::parser::inline::environment_variable::environment_variable,
                 ),
-                |_| vec![()],
             ),
-        ))
-        .parse(input)
-    }
+            conditional_inline_unit(
+                state.config.inline_latex_code_behavior.clone(),
+                value((), crate::parser::inline::latex::latex_code),
+            ),
+            conditional_inline_unit(
+                state.config.inline_latex_behavior.clone(),
+                value((), crate::parser::inline::latex::latex(state.clone())),
+            ),
+            conditional_inline_unit(
+                state.config.inline_latex_parentheses_behavior.clone(),
+                value((), crate::parser::inline::latex::latex_parentheses),
+            ),
+            conditional_inline_unit(
+                state.config.inline_emoji_behavior.clone(),
+                value((), crate::parser::inline::emoji::emoji(state.clone())),
+            ),
+            value(
+                vec![()],
+                crate::parser::inline::custom_parser(state.clone(), None),
+            ),
+        )),
+    ));
+    move |input: &'a str| parser.parse(input)
 }
 
 fn escaped_char(input: &str) -> IResult<&str, char> {
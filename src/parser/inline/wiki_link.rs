@@ -0,0 +1,44 @@
+use crate::ast::{Inline, WikiLink};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    combinator::{map_opt, opt},
+    sequence::{delimited, pair},
+    IResult, Parser,
+};
+
+/// `[[Target#fragment|alias]]`, or `![[Target]]` for an embed
+///
+/// The alias starts at the first `|` and the fragment at the first `#` before it.
+/// A link needs a target or a fragment, and cannot span lines.
+pub(crate) fn wiki_link(input: &str) -> IResult<&str, Inline> {
+    map_opt(
+        pair(
+            opt(tag("!")),
+            delimited(
+                tag("[["),
+                take_while1(|c: char| !matches!(c, '[' | ']' | '\n' | '\r')),
+                tag("]]"),
+            ),
+        ),
+        |(embed, content): (Option<&str>, &str)| {
+            let (target, alias) = match content.split_once('|') {
+                Some((target, alias)) => (target, Some(alias)),
+                None => (content, None),
+            };
+            let (target, fragment) = match target.split_once('#') {
+                Some((target, fragment)) => (target, Some(fragment)),
+                None => (target, None),
+            };
+            if target.trim().is_empty() && fragment.is_none_or(|f| f.trim().is_empty()) {
+                return None;
+            }
+            Some(Inline::WikiLink(WikiLink {
+                target: target.to_owned(),
+                fragment: fragment.map(str::to_owned),
+                alias: alias.map(str::to_owned),
+                embed: embed.is_some(),
+            }))
+        },
+    )
+    .parse(input)
+}
@@ -9,7 +9,9 @@ use nom::{
 };
 use std::rc::Rc;
 
+use super::source_map::SourceText;
 use super::MarkdownParserState;
+use crate::ast::Inline;
 
 pub(crate) fn link_label<'a>(
     state: Rc<MarkdownParserState>,
@@ -19,10 +21,24 @@ pub(crate) fn link_label<'a>(
     }
 }
 
+/// The text or label of a link, where in-text citations are not recognized, as
+/// in Pandoc
+pub(crate) fn link_text<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Inline>> {
+    move |input: &'a str| {
+        // Text parsers try links at every character, so check for a label
+        // before creating the state
+        peek(tag("[")).parse(input)?;
+        link_label(state.for_link_text()).parse(input)
+    }
+}
+
 fn link_label_inner<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Inline>> {
     move |input: &'a str| {
+        let label_start = input;
         // Parse content with balanced brackets (handles nested [...] properly)
         let (input, label) = verify(balanced_brackets_content, |s: &String| {
             s.chars().any(|c| c != ' ' && c != '\n') && s.len() < 1000
@@ -30,14 +46,40 @@ fn link_label_inner<'a>(
         .parse(input)?;
 
         // Recursively parse the label content as inline elements
-        let (_, label) = crate::parser::inline::inline_many1(state.clone())
+        let label = SourceText::aligned(
+            &state,
+            &label_start[..label_start.len() - input.len()],
+            label,
+        );
+        let (_, label) = crate::parser::inline::inline_many1(state.for_text(&label))
             .parse(label.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
-        Ok((input, label))
+        Ok((input, unlink_bare_autolinks(label)))
     }
 }
 
+/// Links do not nest, so bare URLs in a link label stay text
+fn unlink_bare_autolinks(inlines: Vec<Inline>) -> Vec<Inline> {
+    let inlines = inlines
+        .into_iter()
+        .map(|inline| match inline {
+            Inline::Autolink { url, bare: true } => Inline::Text(url),
+            Inline::Emphasis(children) => Inline::Emphasis(unlink_bare_autolinks(children)),
+            Inline::Strong(children) => Inline::Strong(unlink_bare_autolinks(children)),
+            Inline::Strikethrough(children) => {
+                Inline::Strikethrough(unlink_bare_autolinks(children))
+            }
+            Inline::Superscript(children) => Inline::Superscript(unlink_bare_autolinks(children)),
+            Inline::Subscript(children) => Inline::Subscript(unlink_bare_autolinks(children)),
+            Inline::Highlight(children) => Inline::Highlight(unlink_bare_autolinks(children)),
+            Inline::Insert(children) => Inline::Insert(unlink_bare_autolinks(children)),
+            other => other,
+        })
+        .collect();
+    crate::parser::inline::merge_consecutive_text_elements(inlines)
+}
+
 pub(crate) fn link_title(input: &str) -> IResult<&str, String> {
     alt((
         link_title_double_quoted,
@@ -10,7 +10,8 @@
 //! - **GitHub extensions**: Tables, task lists, strikethrough, autolinks, footnotes, alerts
 //! - **Configurable parsing**: Control which elements to parse, skip, or transform
 //! - **Custom parsers**: Register custom block and inline element parsers
-//! - **Error handling**: Comprehensive error reporting with nom-based parsing
+//! - **Error handling**: Errors with line, column and construct, plus a
+//!   recovering mode that keeps unparseable lines as paragraphs
 //!
 //! # Basic Usage
 //!
@@ -44,22 +45,31 @@
 //! let state = MarkdownParserState::with_config(config);
 //! ```
 
+mod attributes;
 mod blocks;
 
 /// Configuration options for Markdown parsing behavior.
 pub mod config;
+mod error;
+mod incremental;
 mod inline;
 mod link_util;
+mod source_map;
+#[cfg(feature = "ast-specialized")]
+mod spans;
+mod stream;
 mod util;
 
-use crate::ast::Document;
+use crate::ast::{Block, Document, Inline};
 use crate::parser::config::MarkdownParserConfig;
+pub use crate::parser::error::ParseError;
+pub use crate::parser::incremental::{IncrementalDocument, TextEdit};
+pub use crate::parser::stream::{BlockStream, Definitions, StreamError, StreamParser};
 use nom::{
     branch::alt,
     character::complete::{line_ending, space1},
     combinator::eof,
     multi::many0,
-    sequence::terminated,
     Parser,
 };
 use std::rc::Rc;
@@ -96,6 +106,13 @@ pub struct MarkdownParserState {
     /// The stack of containers that are currently being parsed.
     /// This is used to prevent self-nesting.
     pub(crate) containers: Vec<String>,
+
+    /// Collects the positions of parsed nodes when parsing with spans.
+    pub(crate) span_tracker: Option<source_map::SpanTracker>,
+
+    /// Whether we are parsing the text or label of a link, where in-text
+    /// citations are not recognized.
+    pub(crate) is_link_text: bool,
 }
 
 impl MarkdownParserState {
@@ -131,6 +148,8 @@ impl MarkdownParserState {
             config: Rc::new(config),
             is_nested_block_context: false,
             containers: Vec::new(),
+            span_tracker: None,
+            is_link_text: false,
         }
     }
 
@@ -144,8 +163,24 @@ impl MarkdownParserState {
             config: self.config.clone(),
             is_nested_block_context: true,
             containers: self.containers.clone(),
+            span_tracker: self.span_tracker.clone(),
+            is_link_text: self.is_link_text,
         }
     }
+
+    /// Create a parser state for the text or label of a link
+    pub(crate) fn for_link_text(self: &Rc<Self>) -> Rc<Self> {
+        if self.is_link_text {
+            return self.clone();
+        }
+        Rc::new(Self {
+            config: self.config.clone(),
+            is_nested_block_context: self.is_nested_block_context,
+            containers: self.containers.clone(),
+            span_tracker: self.span_tracker.clone(),
+            is_link_text: true,
+        })
+    }
 }
 
 impl Default for MarkdownParserState {
@@ -169,7 +204,7 @@ impl Default for MarkdownParserState {
 ///
 /// Returns a `Result` containing either:
 /// - `Ok(Document)` - Successfully parsed AST document
-/// - `Err(nom::Err)` - Parse error with position and context information
+/// - `Err(ParseError)` - Parse error with position and context information
 ///
 /// # Examples
 ///
@@ -200,33 +235,190 @@ impl Default for MarkdownParserState {
 ///
 /// # Errors
 ///
-/// Returns a parse error if the input contains invalid Markdown syntax
+/// Returns a [`ParseError`] if the input contains invalid Markdown syntax
 /// that cannot be recovered from. Most malformed Markdown is handled
-/// gracefully according to CommonMark's error handling rules.
-pub fn parse_markdown(
+/// gracefully according to CommonMark's error handling rules. Use
+/// [`parse_markdown_with_recovery`] to parse such input anyway.
+pub fn parse_markdown(state: MarkdownParserState, input: &str) -> Result<Document, ParseError> {
+    let (blocks, mut errors) = parse_blocks(state, input, false);
+    match errors.pop() {
+        Some(error) => Err(error),
+        None => Ok(Document { blocks }),
+    }
+}
+
+/// Parse a Markdown string, recovering from parse errors
+///
+/// Works like [`parse_markdown`], but a line that cannot be parsed becomes a
+/// paragraph holding its text, and parsing continues on the next line. The
+/// errors are returned next to the document.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::parser::{parse_markdown_with_recovery, MarkdownParserState};
+///
+/// let (doc, errors) =
+///     parse_markdown_with_recovery(MarkdownParserState::new(), ":::note{kind=}\nBody\n");
+///
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].construct, "container block");
+/// assert_eq!(
+///     doc.blocks,
+///     vec![
+///         Block::Paragraph(vec![Inline::Text(":::note{kind=}".to_string())]),
+///         Block::Paragraph(vec![Inline::Text("Body".to_string())]),
+///     ]
+/// );
+/// ```
+pub fn parse_markdown_with_recovery(
     state: MarkdownParserState,
     input: &str,
-) -> Result<Document, nom::Err<nom::error::Error<String>>> {
-    let empty_lines = many0(alt((space1, line_ending)));
-    let mut parser = terminated(
-        many0(crate::parser::blocks::block(Rc::new(state))),
-        (empty_lines, eof),
-    );
-    let result = parser.parse(input);
+) -> (Document, Vec<ParseError>) {
+    let (blocks, errors) = parse_blocks(state, input, true);
+    (Document { blocks }, errors)
+}
+
+/// Parse blocks until only whitespace is left
+///
+/// With `recover`, a line no block parser accepts becomes a paragraph and
+/// parsing continues after it. Otherwise parsing stops at the first error.
+fn parse_blocks(
+    mut state: MarkdownParserState,
+    input: &str,
+    recover: bool,
+) -> (Vec<Block>, Vec<ParseError>) {
+    let tracker = state
+        .span_tracker
+        .get_or_insert_with(|| source_map::SpanTracker::new(input, false))
+        .clone();
+    let state = Rc::new(state);
+    let mut block = crate::parser::blocks::block(state.clone());
 
-    match result {
-        Ok((_, blocks)) => {
-            let blocks = blocks.into_iter().flatten().collect();
-            Ok(Document { blocks })
+    let mut blocks = Vec::new();
+    let mut errors = Vec::new();
+    let mut rest = input;
+    if let Ok((remaining, parsed)) = crate::parser::blocks::front_matter_block(state.clone())(input)
+    {
+        blocks.extend(parsed);
+        rest = remaining;
+    }
+    while let Some(result) = next_block(&mut block, &tracker, input, rest) {
+        let error = match result {
+            Ok((remaining, parsed)) => {
+                blocks.extend(parsed);
+                rest = remaining;
+                continue;
+            }
+            Err(error) => error,
+        };
+        errors.push(error);
+        if !recover {
+            break;
         }
-        Err(nom::Err::Error(e)) => Err(nom::Err::Error(nom::error::Error {
-            input: e.input.to_string(),
-            code: e.code,
-        })),
-        Err(nom::Err::Failure(e)) => Err(nom::Err::Failure(nom::error::Error {
-            input: e.input.to_string(),
-            code: e.code,
-        })),
-        Err(nom::Err::Incomplete(needed)) => Err(nom::Err::Incomplete(needed)),
+
+        let (line, _) = util::many_empty_lines0(rest).unwrap_or((rest, Vec::new()));
+        let (remaining, text) = util::line_terminated(util::not_eof_or_eol0)
+            .parse(line)
+            .unwrap_or(("", line));
+        let end = &line[text.trim_end().len()..];
+        state.record_span("paragraph", line, end);
+        state.record_span("text", line, end);
+        blocks.push(Block::Paragraph(vec![Inline::Text(
+            text.trim_end().to_string(),
+        )]));
+        rest = remaining;
     }
+
+    (blocks, errors)
+}
+
+/// Parse the top-level block at the start of `rest`, a suffix of `input`
+///
+/// Returns `None` once only whitespace is left. Errors are reported at the
+/// recorded failure if there is one, and at the start of the line that
+/// failed to parse otherwise.
+fn next_block<'a>(
+    block: &mut impl FnMut(&'a str) -> nom::IResult<&'a str, Vec<Block>>,
+    tracker: &source_map::SpanTracker,
+    input: &'a str,
+    rest: &'a str,
+) -> Option<Result<(&'a str, Vec<Block>), ParseError>> {
+    let mut trailing_whitespace = (
+        many0(alt((space1::<_, nom::error::Error<&str>>, line_ending))),
+        eof,
+    );
+    if trailing_whitespace.parse(rest).is_ok() {
+        return None;
+    }
+
+    tracker.take_failure();
+    let err = match block(rest) {
+        Ok((remaining, parsed)) if remaining.len() < rest.len() => {
+            return Some(Ok((remaining, parsed)))
+        }
+        Ok(_) => None,
+        Err(err) => Some(err),
+    };
+
+    let (line, _) = util::many_empty_lines0(rest).unwrap_or((rest, Vec::new()));
+    let line_offset = input.len() - line.len();
+    let error = match (tracker.take_failure(), err) {
+        (Some(failure), _) => {
+            ParseError::new(input, failure.offset, failure.construct, failure.message)
+        }
+        (None, Some(nom::Err::Failure(e))) => ParseError::new(
+            input,
+            tracker.offset(e.input).unwrap_or(line_offset),
+            "block",
+            e.code.description(),
+        ),
+        _ => ParseError::new(
+            input,
+            line_offset,
+            "block",
+            "no block construct matches this line",
+        ),
+    };
+    Some(Err(error))
+}
+
+/// Parse a Markdown string into an AST annotated with source spans
+///
+/// Works like [`parse_markdown`], but every node of the returned generic
+/// document carries a [`Span`](crate::ast_specialized::Span) in its
+/// `user_data`: the byte offsets and line/column positions of the text it
+/// was parsed from. Nodes produced by a custom parser or a `Map` behavior
+/// get the span of the text they replace when their kind matches, and an
+/// empty span at the position of the previous node otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::generic::Block;
+/// use markdown_ppp::parser::{parse_markdown_with_spans, MarkdownParserState};
+///
+/// let input = "# Title\n\n> quoted *text*";
+/// let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+///
+/// let Block::BlockQuote { user_data: span, .. } = &doc.blocks[1] else {
+///     panic!("expected a block quote");
+/// };
+/// assert_eq!(&input[span.range()], "> quoted *text*");
+/// assert_eq!((span.start.line, span.start.column), (3, 1));
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_markdown`].
+#[cfg(feature = "ast-specialized")]
+pub fn parse_markdown_with_spans(
+    mut state: MarkdownParserState,
+    input: &str,
+) -> Result<crate::ast::generic::Document<crate::ast_specialized::Span>, ParseError> {
+    let tracker = source_map::SpanTracker::new(input, true);
+    state.span_tracker = Some(tracker.clone());
+    let document = parse_markdown(state, input)?;
+    Ok(spans::attach_spans(document, input, tracker.take_records()))
 }
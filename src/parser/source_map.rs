@@ -0,0 +1,503 @@
+//! Source positions of parsed nodes
+//!
+//! Container blocks (block quotes, list items, footnotes, …) strip their
+//! markers and indentation and parse the remaining text as a new string.
+//! To report positions relative to the original input, such strings are
+//! assembled with [`SourceText`], which remembers where every byte came from.
+//!
+//! The parser state carries a [`SpanTracker`], which locates failures in the
+//! original input. When spans are requested, the block and inline parsers
+//! also record a [`SpanRecord`] for every node they produce.
+
+use crate::ast::{Block, Inline};
+use crate::parser::MarkdownParserState;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Kind of record produced for list items.
+pub(crate) const LIST_ITEM: &str = "list_item";
+
+/// Kind of record produced for definition list items.
+pub(crate) const DEFINITION_LIST_ITEM: &str = "definition_list_item";
+
+/// A run of bytes copied verbatim from the original input.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    /// Offset of the run in the derived string.
+    local: usize,
+    /// Offset of the run in the original input.
+    original: usize,
+    len: usize,
+}
+
+/// Mapping from offsets in a derived string to offsets in the original input
+///
+/// Bytes not covered by any segment were inserted by the parser (such as the
+/// `\n` joining the lines of a block quote) and map to the end of the
+/// preceding segment.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceMap {
+    segments: Vec<Segment>,
+}
+
+impl SourceMap {
+    fn identity(len: usize) -> Self {
+        let mut map = Self::default();
+        map.push(0, 0, len);
+        map
+    }
+
+    /// Original offset of the byte at `local`.
+    fn start(&self, local: usize) -> usize {
+        let index = self.segments.partition_point(|s| s.local <= local);
+        match index.checked_sub(1).map(|i| &self.segments[i]) {
+            Some(s) => s.original + (local - s.local).min(s.len),
+            None => self.segments.first().map_or(0, |s| s.original),
+        }
+    }
+
+    /// Original offset just past the byte before `local`.
+    fn end(&self, local: usize) -> usize {
+        let index = self.segments.partition_point(|s| s.local < local);
+        match index.checked_sub(1).map(|i| &self.segments[i]) {
+            Some(s) => s.original + (local - s.local).min(s.len),
+            None => self.start(local),
+        }
+    }
+
+    fn push(&mut self, local: usize, original: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        if let Some(last) = self.segments.last_mut() {
+            if last.local + last.len == local && last.original + last.len == original {
+                last.len += len;
+                return;
+            }
+        }
+        self.segments.push(Segment {
+            local,
+            original,
+            len,
+        });
+    }
+
+    /// Append the mapping of `len` bytes starting at `start` to `target`,
+    /// where they are placed at offset `at`.
+    fn copy_to(&self, target: &mut SourceMap, at: usize, start: usize, len: usize) {
+        let end = start + len;
+        let first = self.segments.partition_point(|s| s.local + s.len <= start);
+        for s in &self.segments[first..] {
+            if s.local >= end {
+                break;
+            }
+            let from = s.local.max(start);
+            let to = (s.local + s.len).min(end);
+            target.push(
+                at + (from - start),
+                s.original + (from - s.local),
+                to - from,
+            );
+        }
+    }
+}
+
+/// Byte range of a parsed node in the original input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SpanRecord {
+    pub kind: &'static str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A failure reported by a parser, located in the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Failure {
+    pub construct: &'static str,
+    pub message: String,
+    pub offset: usize,
+}
+
+/// Collects the positions of parsed nodes and failures
+///
+/// A tracker is bound to the string the parser state it belongs to works on,
+/// identified by its address. All trackers created for one parse share the
+/// same list of records.
+#[derive(Debug, Clone)]
+pub(crate) struct SpanTracker {
+    base: usize,
+    len: usize,
+    map: Rc<SourceMap>,
+    /// Records of parsed nodes, `None` if only failures are located.
+    records: Option<Rc<RefCell<Vec<SpanRecord>>>>,
+    /// The innermost failure since the last call to `take_failure`.
+    failure: Rc<RefCell<Option<Failure>>>,
+}
+
+impl SpanTracker {
+    /// Create a tracker for the original input.
+    pub(crate) fn new(input: &str, record_nodes: bool) -> Self {
+        Self {
+            base: input.as_ptr() as usize,
+            len: input.len(),
+            map: Rc::new(SourceMap::identity(input.len())),
+            records: record_nodes.then(|| Rc::new(RefCell::new(Vec::new()))),
+            failure: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Take all records collected so far.
+    #[cfg_attr(not(feature = "ast-specialized"), allow(dead_code))]
+    pub(crate) fn take_records(&self) -> Vec<SpanRecord> {
+        self.records
+            .as_ref()
+            .map(|records| records.take())
+            .unwrap_or_default()
+    }
+
+    /// Take the failure recorded since the last call.
+    pub(crate) fn take_failure(&self) -> Option<Failure> {
+        self.failure.take()
+    }
+
+    /// Offset in the original input of the start of `s`, if it is a slice of
+    /// the tracked string.
+    pub(crate) fn offset(&self, s: &str) -> Option<usize> {
+        self.local(s).map(|local| self.map.start(local))
+    }
+
+    /// Offset of `s` in the tracked string, if it is a slice of it.
+    fn local(&self, s: &str) -> Option<usize> {
+        let address = s.as_ptr() as usize;
+        (address >= self.base && address + s.len() <= self.base + self.len)
+            .then(|| address - self.base)
+    }
+
+    /// Record a node spanning from the start of `start` to the start of `end`.
+    ///
+    /// Both must be slices of the tracked string, otherwise nothing is recorded.
+    pub(crate) fn record(&self, kind: &'static str, start: &str, end: &str) {
+        let Some(records) = &self.records else {
+            return;
+        };
+        let (Some(start), Some(end)) = (self.local(start), self.local(end)) else {
+            return;
+        };
+        if start > end {
+            return;
+        }
+        let start_offset = self.map.start(start);
+        let end_offset = if start == end {
+            start_offset
+        } else {
+            self.map.end(end).max(start_offset)
+        };
+        records.borrow_mut().push(SpanRecord {
+            kind,
+            start: start_offset,
+            end: end_offset,
+        });
+    }
+
+    /// Create a tracker for parsing `text`.
+    fn for_text(&self, text: &SourceText) -> Self {
+        Self {
+            base: text.text.as_ptr() as usize,
+            len: text.text.len(),
+            map: Rc::new(
+                text.map
+                    .as_ref()
+                    .map(|(_, map)| map.clone())
+                    .unwrap_or_default(),
+            ),
+            records: self.records.clone(),
+            failure: self.failure.clone(),
+        }
+    }
+}
+
+/// A string assembled from pieces of the text being parsed
+///
+/// Pieces added with [`SourceText::push_slice`] keep their position in the
+/// original input, everything else is treated as inserted by the parser.
+pub(crate) struct SourceText {
+    text: String,
+    /// Tracker of the text the pieces come from, and the map built so far.
+    /// `None` unless spans are tracked.
+    map: Option<(SpanTracker, SourceMap)>,
+}
+
+impl SourceText {
+    /// Create an empty text assembled from pieces of the text parsed with `state`.
+    pub(crate) fn new(state: &MarkdownParserState) -> Self {
+        Self {
+            text: String::new(),
+            map: state
+                .span_tracker
+                .as_ref()
+                .map(|tracker| (tracker.clone(), SourceMap::default())),
+        }
+    }
+
+    /// Join `lines`, which must be slices of the text parsed with `state`.
+    pub(crate) fn join(state: &MarkdownParserState, lines: &[&str], separator: &str) -> Self {
+        let mut text = Self::new(state);
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                text.push_str(separator);
+            }
+            text.push_slice(line);
+        }
+        text
+    }
+
+    /// Map `text`, built by dropping characters from `source`, back to `source`.
+    ///
+    /// Characters are matched greedily, which is exact for escapes and trimmed
+    /// whitespace.
+    pub(crate) fn aligned(state: &MarkdownParserState, source: &str, text: String) -> Self {
+        let mut result = Self::new(state);
+        if let Some((parent, map)) = result.map.as_mut() {
+            if let Some(base) = parent.local(source) {
+                let mut chars = source.char_indices();
+                for (local, c) in text.char_indices() {
+                    match chars.find(|(_, s)| *s == c) {
+                        Some((offset, _)) => {
+                            parent.map.copy_to(map, local, base + offset, c.len_utf8())
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+        result.text = text;
+        result
+    }
+
+    /// Append a slice of the text this one is assembled from.
+    pub(crate) fn push_slice(&mut self, slice: &str) {
+        if let Some((parent, map)) = self.map.as_mut() {
+            if let Some(local) = parent.local(slice) {
+                parent.map.copy_to(map, self.text.len(), local, slice.len());
+            }
+        }
+        self.text.push_str(slice);
+    }
+
+    /// Append text inserted by the parser.
+    pub(crate) fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+impl MarkdownParserState {
+    /// Whether the positions of parsed nodes are recorded.
+    pub(crate) fn tracks_spans(&self) -> bool {
+        self.span_tracker
+            .as_ref()
+            .is_some_and(|tracker| tracker.records.is_some())
+    }
+
+    /// Remember which construct failed, if `err` is a failure
+    ///
+    /// `message` describes the failure from the error of the parser that
+    /// gave up. Only the innermost failure is kept, as it is reported first
+    /// while the failure propagates.
+    pub(crate) fn record_failure(
+        &self,
+        err: &nom::Err<nom::error::Error<&str>>,
+        construct: &'static str,
+        message: impl FnOnce(&nom::error::Error<&str>) -> String,
+    ) {
+        let (Some(tracker), nom::Err::Failure(e)) = (&self.span_tracker, err) else {
+            return;
+        };
+        let Some(offset) = tracker.offset(e.input) else {
+            return;
+        };
+        let mut failure = tracker.failure.borrow_mut();
+        if failure.is_none() {
+            *failure = Some(Failure {
+                construct,
+                message: message(e),
+                offset,
+            });
+        }
+    }
+
+    /// Record the position of a node, if spans are tracked.
+    pub(crate) fn record_span(&self, kind: &'static str, start: &str, end: &str) {
+        if let Some(tracker) = &self.span_tracker {
+            tracker.record(kind, start, end);
+        }
+    }
+
+    /// Create a nested parser state for parsing `text`
+    ///
+    /// See [`MarkdownParserState::nested`].
+    pub(crate) fn nested_for(&self, text: &SourceText) -> Self {
+        let mut state = self.nested();
+        state.span_tracker = self.span_tracker.as_ref().map(|t| t.for_text(text));
+        state
+    }
+
+    /// Get a parser state for parsing `text` in the same context.
+    pub(crate) fn for_text(self: &Rc<Self>, text: &SourceText) -> Rc<Self> {
+        match &self.span_tracker {
+            Some(tracker) => Rc::new(Self {
+                config: self.config.clone(),
+                is_nested_block_context: self.is_nested_block_context,
+                containers: self.containers.clone(),
+                span_tracker: Some(tracker.for_text(text)),
+                is_link_text: self.is_link_text,
+            }),
+            None => self.clone(),
+        }
+    }
+}
+
+/// Name of the kind of a block, used to match records to nodes.
+pub(crate) fn block_kind(block: &Block) -> &'static str {
+    match block {
+        Block::Paragraph(_) => "paragraph",
+        Block::Heading(_) => "heading",
+        Block::ThematicBreak => "thematic_break",
+        Block::BlockQuote(_) => "blockquote",
+        Block::List(_) => "list",
+        Block::DefinitionList(_) => "definition_list",
+        Block::CodeBlock(_) => "code_block",
+        Block::HtmlBlock(_) => "html_block",
+        Block::Definition(_) => "definition",
+        Block::AbbreviationDefinition { .. } => "abbreviation_definition",
+        Block::Table(_) => "table",
+        Block::Figure { .. } => "figure",
+        Block::FootnoteDefinition(_) => "footnote_definition",
+        Block::GitHubAlert(_) => "github_alert",
+        Block::LatexBlock { .. } => "latex_block",
+        Block::Empty => "empty",
+        Block::Container(_) => "container",
+        Block::MacroBlock(_) => "macro_block",
+        Block::FrontMatter { .. } => "front_matter",
+        Block::Extension(_) => "extension",
+    }
+}
+
+/// Name of the kind of an inline, used to match records to nodes.
+pub(crate) fn inline_kind(inline: &Inline) -> &'static str {
+    match inline {
+        Inline::Text(_) => "text",
+        Inline::LineBreak => "line_break",
+        Inline::Code(_) => "code",
+        Inline::Html(_) => "html",
+        Inline::Link(_) => "link",
+        Inline::LinkReference(_) => "link_reference",
+        Inline::Image(_) => "image",
+        Inline::Emphasis(_) => "emphasis",
+        Inline::Strong(_) => "strong",
+        Inline::Strikethrough(_) => "strikethrough",
+        Inline::Superscript(_) => "superscript",
+        Inline::Subscript(_) => "subscript",
+        Inline::Highlight(_) => "highlight",
+        Inline::Insert(_) => "insert",
+        Inline::Autolink { .. } => "autolink",
+        Inline::FootnoteReference(_) => "footnote_reference",
+        Inline::WikiLink(_) => "wiki_link",
+        Inline::Span(_) => "span",
+        Inline::GitHubReference(_) => "github_reference",
+        Inline::Citation(_) => "citation",
+        Inline::Emoji { .. } => "emoji",
+        Inline::Abbreviation { .. } => "abbreviation",
+        Inline::Empty => "empty",
+        Inline::Latex { .. } => "latex",
+        Inline::Extension(_) => "extension",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_map_offsets() {
+        let mut map = SourceMap::default();
+        // "ab" from 2..4, an inserted "\n", then "cd" from 7..9
+        map.push(0, 2, 2);
+        map.push(3, 7, 2);
+        assert_eq!(map.start(0), 2);
+        assert_eq!(map.start(1), 3);
+        assert_eq!(map.start(2), 4);
+        assert_eq!(map.start(3), 7);
+        assert_eq!(map.end(2), 4);
+        assert_eq!(map.end(3), 4);
+        assert_eq!(map.end(5), 9);
+    }
+
+    #[test]
+    fn test_source_map_copy_to() {
+        let mut map = SourceMap::default();
+        map.push(0, 2, 2);
+        map.push(3, 7, 2);
+        let mut target = SourceMap::default();
+        map.copy_to(&mut target, 10, 1, 3);
+        assert_eq!(target.start(10), 3);
+        assert_eq!(target.end(11), 4);
+        assert_eq!(target.start(12), 7);
+    }
+
+    #[test]
+    fn test_source_text_join() {
+        let input = "> a\n> b";
+        let mut state = MarkdownParserState::new();
+        state.span_tracker = Some(SpanTracker::new(input, true));
+        let text = SourceText::join(&state, &[&input[2..3], &input[6..7]], "\n");
+        assert_eq!(text.as_str(), "a\nb");
+
+        let nested = state.nested_for(&text);
+        let tracker = nested.span_tracker.as_ref().unwrap();
+        tracker.record("text", &text.as_str()[2..], &text.as_str()[3..]);
+        tracker.record("paragraph", text.as_str(), &text.as_str()[3..]);
+        assert_eq!(
+            tracker.take_records(),
+            vec![
+                SpanRecord {
+                    kind: "text",
+                    start: 6,
+                    end: 7
+                },
+                SpanRecord {
+                    kind: "paragraph",
+                    start: 2,
+                    end: 7
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_source_text_aligned() {
+        let input = r" a \| b ";
+        let mut state = MarkdownParserState::new();
+        state.span_tracker = Some(SpanTracker::new(input, true));
+        let text = SourceText::aligned(&state, input, "a | b".to_string());
+
+        let nested = state.nested_for(&text);
+        let tracker = nested.span_tracker.as_ref().unwrap();
+        tracker.record("text", &text.as_str()[4..], &text.as_str()[5..]);
+        assert_eq!(
+            tracker.take_records(),
+            vec![SpanRecord {
+                kind: "text",
+                start: 6,
+                end: 7
+            }]
+        );
+    }
+}
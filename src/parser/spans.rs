@@ -0,0 +1,286 @@
+//! Attach source spans to a parsed document
+//!
+//! While parsing with a [`SpanTracker`](super::source_map::SpanTracker) the
+//! parsers record the byte range and kind of every node they produce,
+//! including nodes from alternatives that were tried and discarded. The tree
+//! is then walked in source order and every node takes the earliest record
+//! of its kind that lies after its previous sibling and within its parent.
+
+use super::source_map::{SpanRecord, DEFINITION_LIST_ITEM, LIST_ITEM};
+use crate::ast::convert::WithData;
+use crate::ast::{generic, Document};
+use crate::ast_specialized::{Position, Span};
+
+/// Convert `document` to a generic document with the spans from `records`.
+pub(crate) fn attach_spans(
+    document: Document,
+    input: &str,
+    mut records: Vec<SpanRecord>,
+) -> generic::Document<Span> {
+    records.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    let resolver = Resolver {
+        input,
+        records,
+        line_starts: std::iter::once(0)
+            .chain(input.match_indices('\n').map(|(i, _)| i + 1))
+            .collect(),
+    };
+
+    let mut document: generic::Document<Span> = document.with_default_data();
+    document.user_data = resolver.span(0, input.len());
+    resolver.blocks(&mut document.blocks, 0, input.len());
+    document
+}
+
+struct Resolver<'a> {
+    input: &'a str,
+    /// Records sorted by start, longest first.
+    records: Vec<SpanRecord>,
+    /// Byte offsets at which lines start.
+    line_starts: Vec<usize>,
+}
+
+impl Resolver<'_> {
+    /// Find the first record of `kind` within `cursor..limit`.
+    ///
+    /// Falls back to an empty range at `cursor` if there is none.
+    fn find(&self, kind: &str, cursor: usize, limit: usize) -> (usize, usize) {
+        let first = self.records.partition_point(|r| r.start < cursor);
+        self.records[first..]
+            .iter()
+            .take_while(|r| r.start <= limit)
+            .find(|r| r.kind == kind && r.end <= limit)
+            .map_or((cursor, cursor), |r| (r.start, r.end))
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self
+            .input
+            .get(line_start..offset)
+            .map_or(offset - line_start, |s| s.chars().count());
+        Position::new(offset, line, column + 1)
+    }
+
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(self.position(start), self.position(end))
+    }
+
+    /// Assign spans to `blocks`, returning the offset past the last one.
+    fn blocks(&self, blocks: &mut [generic::Block<Span>], start: usize, limit: usize) -> usize {
+        blocks
+            .iter_mut()
+            .fold(start, |cursor, block| self.block(block, cursor, limit))
+    }
+
+    fn block(&self, block: &mut generic::Block<Span>, cursor: usize, limit: usize) -> usize {
+        let (start, end) = self.find(block_kind(block), cursor, limit);
+        let span = self.span(start, end);
+        match block {
+            generic::Block::Paragraph { content, user_data } => {
+                *user_data = span;
+                self.inlines(content, start, end);
+            }
+            generic::Block::Heading(heading) => {
+                heading.user_data = span;
+                self.inlines(&mut heading.content, start, end);
+            }
+            generic::Block::BlockQuote { blocks, user_data } => {
+                *user_data = span;
+                self.blocks(blocks, start, end);
+            }
+            generic::Block::List(list) => {
+                list.user_data = span;
+                let mut cursor = start;
+                for item in &mut list.items {
+                    let (item_start, item_end) = self.find(LIST_ITEM, cursor, end);
+                    item.user_data = self.span(item_start, item_end);
+                    self.blocks(&mut item.blocks, item_start, item_end);
+                    cursor = item_end;
+                }
+            }
+            generic::Block::DefinitionList(list) => {
+                list.user_data = span;
+                let mut cursor = start;
+                for item in &mut list.items {
+                    let (item_start, item_end) = self.find(DEFINITION_LIST_ITEM, cursor, end);
+                    item.user_data = self.span(item_start, item_end);
+                    let mut item_cursor = self.inlines(&mut item.term, item_start, item_end);
+                    for definition in &mut item.definitions {
+                        item_cursor = self.blocks(definition, item_cursor, item_end);
+                    }
+                    cursor = item_end;
+                }
+            }
+            generic::Block::CodeBlock(code_block) => code_block.user_data = span,
+            generic::Block::Definition(definition) => {
+                definition.user_data = span;
+                self.inlines(&mut definition.label, start, end);
+            }
+            generic::Block::Table(table) => {
+                table.user_data = span;
+                let mut cursor = start;
+                for cell in table.rows.iter_mut().flatten() {
+                    cursor = self.inlines(&mut cell.content, cursor, end);
+                }
+            }
+            generic::Block::Figure {
+                content,
+                caption,
+                user_data,
+                ..
+            } => {
+                *user_data = span;
+                // A table caption may come before the table
+                let content_start = content
+                    .first()
+                    .map_or(start, |block| self.find(block_kind(block), start, end).0);
+                if content_start > start {
+                    self.inlines(caption, start, content_start);
+                    self.blocks(content, content_start, end);
+                } else {
+                    let cursor = self.blocks(content, start, end);
+                    self.inlines(caption, cursor, end);
+                }
+            }
+            generic::Block::FootnoteDefinition(footnote) => {
+                footnote.user_data = span;
+                self.blocks(&mut footnote.blocks, start, end);
+            }
+            generic::Block::GitHubAlert(alert) => {
+                alert.user_data = span;
+                self.blocks(&mut alert.blocks, start, end);
+            }
+            generic::Block::Container(container) => {
+                container.user_data = span;
+                self.blocks(&mut container.blocks, start, end);
+            }
+            generic::Block::Extension(extension) => {
+                extension.user_data = span;
+                self.blocks(&mut extension.children, start, end);
+            }
+            generic::Block::ThematicBreak { user_data }
+            | generic::Block::HtmlBlock { user_data, .. }
+            | generic::Block::LatexBlock { user_data, .. }
+            | generic::Block::Empty { user_data }
+            | generic::Block::MacroBlock { user_data, .. }
+            | generic::Block::AbbreviationDefinition { user_data, .. }
+            | generic::Block::FrontMatter { user_data, .. } => *user_data = span,
+        }
+        end
+    }
+
+    /// Assign spans to `inlines`, returning the offset past the last one.
+    fn inlines(&self, inlines: &mut [generic::Inline<Span>], start: usize, limit: usize) -> usize {
+        inlines
+            .iter_mut()
+            .fold(start, |cursor, inline| self.inline(inline, cursor, limit))
+    }
+
+    fn inline(&self, inline: &mut generic::Inline<Span>, cursor: usize, limit: usize) -> usize {
+        let (start, end) = self.find(inline_kind(inline), cursor, limit);
+        let span = self.span(start, end);
+        match inline {
+            generic::Inline::Link(link) => {
+                link.user_data = span;
+                self.inlines(&mut link.children, start, end);
+            }
+            generic::Inline::LinkReference(link_ref) => {
+                link_ref.user_data = span;
+                self.inlines(&mut link_ref.text, start, end);
+                self.inlines(&mut link_ref.label, start, end);
+            }
+            generic::Inline::Image(image) => image.user_data = span,
+            generic::Inline::WikiLink(wiki_link) => wiki_link.user_data = span,
+            generic::Inline::Emphasis { content, user_data }
+            | generic::Inline::Strong { content, user_data }
+            | generic::Inline::Strikethrough { content, user_data }
+            | generic::Inline::Superscript { content, user_data }
+            | generic::Inline::Subscript { content, user_data }
+            | generic::Inline::Highlight { content, user_data }
+            | generic::Inline::Insert { content, user_data } => {
+                *user_data = span;
+                self.inlines(content, start, end);
+            }
+            generic::Inline::Span(bracketed) => {
+                bracketed.user_data = span;
+                self.inlines(&mut bracketed.children, start, end);
+            }
+            generic::Inline::Extension(extension) => {
+                extension.user_data = span;
+                self.inlines(&mut extension.children, start, end);
+            }
+            generic::Inline::Text { user_data, .. }
+            | generic::Inline::LineBreak { user_data }
+            | generic::Inline::Code { user_data, .. }
+            | generic::Inline::Latex { user_data, .. }
+            | generic::Inline::Html { user_data, .. }
+            | generic::Inline::Autolink { user_data, .. }
+            | generic::Inline::FootnoteReference { user_data, .. }
+            | generic::Inline::GitHubReference { user_data, .. }
+            | generic::Inline::Citation { user_data, .. }
+            | generic::Inline::Emoji { user_data, .. }
+            | generic::Inline::Abbreviation { user_data, .. }
+            | generic::Inline::Empty { user_data } => *user_data = span,
+        }
+        end
+    }
+}
+
+/// Kind of a block, as recorded by [`block_kind`](super::source_map::block_kind).
+fn block_kind<T: Default>(block: &generic::Block<T>) -> &'static str {
+    match block {
+        generic::Block::Paragraph { .. } => "paragraph",
+        generic::Block::Heading(_) => "heading",
+        generic::Block::ThematicBreak { .. } => "thematic_break",
+        generic::Block::BlockQuote { .. } => "blockquote",
+        generic::Block::List(_) => "list",
+        generic::Block::DefinitionList(_) => "definition_list",
+        generic::Block::CodeBlock(_) => "code_block",
+        generic::Block::HtmlBlock { .. } => "html_block",
+        generic::Block::Definition(_) => "definition",
+        generic::Block::AbbreviationDefinition { .. } => "abbreviation_definition",
+        generic::Block::Table(_) => "table",
+        generic::Block::Figure { .. } => "figure",
+        generic::Block::FootnoteDefinition(_) => "footnote_definition",
+        generic::Block::GitHubAlert(_) => "github_alert",
+        generic::Block::LatexBlock { .. } => "latex_block",
+        generic::Block::Empty { .. } => "empty",
+        generic::Block::Container(_) => "container",
+        generic::Block::MacroBlock { .. } => "macro_block",
+        generic::Block::FrontMatter { .. } => "front_matter",
+        generic::Block::Extension(_) => "extension",
+    }
+}
+
+/// Kind of an inline, as recorded by [`inline_kind`](super::source_map::inline_kind).
+fn inline_kind<T: Default>(inline: &generic::Inline<T>) -> &'static str {
+    match inline {
+        generic::Inline::Text { .. } => "text",
+        generic::Inline::LineBreak { .. } => "line_break",
+        generic::Inline::Code { .. } => "code",
+        generic::Inline::Html { .. } => "html",
+        generic::Inline::Link(_) => "link",
+        generic::Inline::LinkReference(_) => "link_reference",
+        generic::Inline::Image(_) => "image",
+        generic::Inline::Emphasis { .. } => "emphasis",
+        generic::Inline::Strong { .. } => "strong",
+        generic::Inline::Strikethrough { .. } => "strikethrough",
+        generic::Inline::Superscript { .. } => "superscript",
+        generic::Inline::Subscript { .. } => "subscript",
+        generic::Inline::Highlight { .. } => "highlight",
+        generic::Inline::Insert { .. } => "insert",
+        generic::Inline::Autolink { .. } => "autolink",
+        generic::Inline::FootnoteReference { .. } => "footnote_reference",
+        generic::Inline::WikiLink(_) => "wiki_link",
+        generic::Inline::Span(_) => "span",
+        generic::Inline::GitHubReference { .. } => "github_reference",
+        generic::Inline::Citation { .. } => "citation",
+        generic::Inline::Emoji { .. } => "emoji",
+        generic::Inline::Abbreviation { .. } => "abbreviation",
+        generic::Inline::Empty { .. } => "empty",
+        generic::Inline::Latex { .. } => "latex",
+        generic::Inline::Extension(_) => "extension",
+    }
+}
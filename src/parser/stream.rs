@@ -0,0 +1,482 @@
+//! Streaming block parser
+//!
+//! [`StreamParser`] accepts the input in chunks and hands out top-level
+//! blocks as soon as they are complete, and [`BlockStream`] does the same for
+//! any [`std::io::Read`]. Only the text of the blocks that may still change is
+//! kept in memory.
+//!
+//! A block is complete once the block after it has been parsed and the next
+//! one has started: text appended to the input can only change the last two
+//! blocks, as with an [`IncrementalDocument`](super::IncrementalDocument)
+//! edit at its end. Streaming therefore yields the same blocks as
+//! [`parse_markdown`](super::parse_markdown) does for the whole input.
+//! Fences are the exception: nothing is yielded from a fence without its
+//! closing line (```` ``` ````, `:::`, `<!--`, ...) onwards, or from the
+//! start when the fence may open front matter, until the closing line or the
+//! end of the input is read. Display math (`$$`, `\[`) and macro blocks
+//! (`{{`) cannot span a blank line, so their openers only hold blocks back
+//! until the next one. While a fence holds everything back, the input is
+//! only parsed again once a line that may close it is read.
+//!
+//! # Deferred resolution
+//!
+//! Link reference definitions and footnote definitions may appear after the
+//! blocks that use them. The definitions yielded so far are collected in
+//! [`Definitions`]. A consumer that needs them, e.g. to render links, checks
+//! each block with [`Definitions::is_resolved`], handles resolved blocks right
+//! away and keeps the others until the end of the stream, when every
+//! definition is known.
+//!
+//! ```rust
+//! use markdown_ppp::parser::{BlockStream, MarkdownParserState};
+//!
+//! let input = "See [the docs][docs].\n\nPlain text.\n\n[docs]: https://docs.rs\n";
+//! let mut stream = BlockStream::new(MarkdownParserState::new(), input.as_bytes());
+//!
+//! let mut ready = Vec::new();
+//! let mut deferred = Vec::new();
+//! while let Some(block) = stream.next() {
+//!     let block = block.unwrap();
+//!     if stream.definitions().is_resolved(&block) {
+//!         ready.push(block);
+//!     } else {
+//!         deferred.push(block);
+//!     }
+//! }
+//!
+//! // All definitions are known now
+//! assert_eq!((ready.len(), deferred.len()), (2, 1));
+//! assert!(deferred.iter().all(|b| stream.definitions().is_resolved(b)));
+//! ```
+
+use super::incremental::{may_close_fence, parse_chunks, OpenFence, LOOKBEHIND_BLOCKS};
+use super::{MarkdownParserState, ParseError};
+use crate::ast::{normalize_label, Block, FootnoteDefinition, Inline, LinkDefinition};
+use crate::parser::blocks::front_matter::front_matter_pending;
+use crate::parser::config::MarkdownParserConfig;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io::{self, Read};
+use std::rc::Rc;
+
+/// Number of bytes [`BlockStream`] reads at once
+const READ_SIZE: usize = 64 * 1024;
+
+/// A parser that is fed the input in chunks
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::parser::{MarkdownParserState, StreamParser};
+///
+/// let mut parser = StreamParser::new(MarkdownParserState::new());
+/// assert!(parser.feed("# Title\n\nFirst").unwrap().is_empty());
+/// let blocks = parser.feed(" paragraph\n\nSecond\n").unwrap();
+/// assert_eq!(blocks.len(), 1);
+///
+/// let rest = parser.finish().unwrap();
+/// assert_eq!(
+///     rest,
+///     vec![
+///         Block::Paragraph(vec![Inline::Text("First paragraph".to_string())]),
+///         Block::Paragraph(vec![Inline::Text("Second".to_string())]),
+///     ]
+/// );
+/// ```
+pub struct StreamParser {
+    config: Rc<MarkdownParserConfig>,
+    /// Input that has not been turned into complete blocks yet
+    buffer: String,
+    /// Byte offset of the buffer in the whole input
+    offset: usize,
+    /// Number of lines before the buffer
+    line: usize,
+    /// Length of the buffer when it was last parsed, if a fence without its
+    /// closing line at its start held back all blocks
+    held_at: Option<usize>,
+    definitions: Definitions,
+}
+
+impl StreamParser {
+    /// Create a parser with the configuration of `state`
+    pub fn new(state: MarkdownParserState) -> Self {
+        Self {
+            config: state.config,
+            buffer: String::new(),
+            offset: 0,
+            line: 0,
+            held_at: None,
+            definitions: Definitions::default(),
+        }
+    }
+
+    /// Append `chunk` to the input, returning the blocks it completed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffered input cannot be parsed. Errors are
+    /// reported at their position in the whole input.
+    pub fn feed(&mut self, chunk: &str) -> Result<Vec<Block>, ParseError> {
+        self.buffer.push_str(chunk);
+        self.parse(false)
+    }
+
+    /// Signal the end of the input, returning the remaining blocks
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffered input cannot be parsed.
+    pub fn finish(mut self) -> Result<Vec<Block>, ParseError> {
+        self.parse(true)
+    }
+
+    /// The link and footnote definitions returned so far
+    pub fn definitions(&self) -> &Definitions {
+        &self.definitions
+    }
+
+    fn parse(&mut self, at_end: bool) -> Result<Vec<Block>, ParseError> {
+        let document_start = self.offset == 0;
+        // Until its closing fence is read, front matter looks like ordinary blocks
+        if !at_end && document_start && front_matter_pending(&self.config, &self.buffer) {
+            return Ok(Vec::new());
+        }
+        if let Some(held_at) = self.held_at.filter(|_| !at_end) {
+            // Only a closing line can release the blocks, so look for one
+            // from the start of the last line read before
+            let line_start = self.buffer[..held_at].rfind('\n').map_or(0, |i| i + 1);
+            if !self.buffer[line_start..].lines().any(may_close_fence) {
+                self.held_at = Some(self.buffer.len());
+                return Ok(Vec::new());
+            }
+        }
+        let (mut blocks, chunks) =
+            parse_chunks(&self.config, &self.buffer, 0, document_start, |_| false)
+                .map_err(|err| err.shifted(self.offset, self.line))?;
+
+        // A closing line may still turn the blocks after a fence into one
+        let open = if at_end {
+            None
+        } else {
+            chunks
+                .iter()
+                .position(|c| c.is_open(&self.buffer, self.buffer.len()))
+        };
+        let complete = if at_end {
+            chunks.len()
+        } else {
+            chunks
+                .len()
+                .saturating_sub(LOOKBEHIND_BLOCKS + 1)
+                .min(open.unwrap_or(chunks.len()))
+        };
+        let consumed = chunks[..complete].last().map_or(0, |c| c.end);
+        blocks.truncate(chunks[..complete].iter().map(|c| c.blocks).sum());
+
+        self.offset += consumed;
+        self.line += self.buffer[..consumed].matches('\n').count();
+        self.buffer.drain(..consumed);
+        self.held_at = open
+            .filter(|&open| {
+                open == complete && chunks[open].open_fence == Some(OpenFence::Unbounded)
+            })
+            .map(|_| self.buffer.len());
+        for block in &blocks {
+            self.definitions.collect(block);
+        }
+        Ok(blocks)
+    }
+}
+
+/// An iterator over the top-level blocks read from a [`Read`]
+///
+/// A block is yielded once the block after it has been parsed and the next one
+/// has started, or, after a fence without its closing line, once the closing
+/// line or the end of the input is read. A display math or macro block opener
+/// without its closing delimiter holds blocks back only up to the next blank
+/// line. The blocks are the same as
+/// [`parse_markdown`](super::parse_markdown) returns for the whole input.
+/// References to definitions further down are resolved with
+/// [`BlockStream::definitions`].
+pub struct BlockStream<R> {
+    reader: R,
+    parser: Option<StreamParser>,
+    /// Bytes of an incomplete UTF-8 sequence at the end of the last read
+    pending_bytes: Vec<u8>,
+    ready: VecDeque<Block>,
+    definitions: Definitions,
+}
+
+impl<R: Read> BlockStream<R> {
+    /// Create a stream reading from `reader` with the configuration of `state`
+    pub fn new(state: MarkdownParserState, reader: R) -> Self {
+        Self {
+            reader,
+            parser: Some(StreamParser::new(state)),
+            pending_bytes: Vec::new(),
+            ready: VecDeque::new(),
+            definitions: Definitions::default(),
+        }
+    }
+
+    /// The link and footnote definitions yielded so far
+    pub fn definitions(&self) -> &Definitions {
+        &self.definitions
+    }
+
+    /// Read and parse the next chunk of input
+    ///
+    /// Returns `Ok(false)` once the input is exhausted.
+    fn fill(&mut self) -> Result<bool, StreamError> {
+        let Some(parser) = self.parser.as_mut() else {
+            return Ok(false);
+        };
+
+        let mut bytes = std::mem::take(&mut self.pending_bytes);
+        let start = bytes.len();
+        bytes.resize(start + READ_SIZE, 0);
+        let read = loop {
+            match self.reader.read(&mut bytes[start..]) {
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result?,
+            }
+        };
+        bytes.truncate(start + read);
+
+        if read == 0 {
+            if !bytes.is_empty() {
+                return Err(invalid_utf8().into());
+            }
+            let blocks = self.parser.take().map_or(Ok(Vec::new()), |p| p.finish())?;
+            self.ready.extend(blocks);
+            return Ok(false);
+        }
+
+        let valid = match std::str::from_utf8(&bytes) {
+            Ok(text) => text.len(),
+            // The read may end in the middle of a character
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(_) => return Err(invalid_utf8().into()),
+        };
+        self.pending_bytes = bytes.split_off(valid);
+        let text = std::str::from_utf8(&bytes).map_err(|_| invalid_utf8())?;
+        self.ready.extend(parser.feed(text)?);
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for BlockStream<R> {
+    type Item = Result<Block, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.ready.is_empty() {
+            match self.fill() {
+                Ok(true) => {}
+                Ok(false) if self.ready.is_empty() => return None,
+                Ok(false) => break,
+                Err(err) => {
+                    // Stop after reporting the error
+                    self.parser = None;
+                    return Some(Err(err));
+                }
+            }
+        }
+        let block = self.ready.pop_front()?;
+        self.definitions.collect(&block);
+        Some(Ok(block))
+    }
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    )
+}
+
+/// An error encountered while streaming blocks
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StreamError {
+    /// Reading the input failed
+    Io(io::Error),
+    /// The input could not be parsed
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "read error: {err}"),
+            StreamError::Parse(err) => write!(f, "parse error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+/// Link and footnote definitions collected from streamed blocks
+///
+/// Link definitions are keyed by their normalized label and footnote
+/// definitions by their label. When a label is defined more than once, the
+/// first definition wins.
+#[derive(Debug, Clone, Default)]
+pub struct Definitions {
+    /// Link reference definitions by normalized label
+    pub links: HashMap<String, LinkDefinition>,
+    /// Footnote definitions by label
+    pub footnotes: HashMap<String, FootnoteDefinition>,
+}
+
+impl Definitions {
+    /// Get the definition for a link reference label
+    ///
+    /// Labels are matched as described in [`normalize_label`].
+    pub fn link(&self, label: &[Inline]) -> Option<&LinkDefinition> {
+        self.links.get(&normalize_label(label))
+    }
+
+    /// Get the definition for a footnote label
+    pub fn footnote(&self, label: &str) -> Option<&FootnoteDefinition> {
+        self.footnotes.get(label)
+    }
+
+    /// Check if every link reference and footnote reference in `block` is
+    /// defined
+    pub fn is_resolved(&self, block: &Block) -> bool {
+        let mut resolved = true;
+        visit_block_inlines(block, &mut |inline| match inline {
+            Inline::LinkReference(link) => resolved &= self.link(&link.label).is_some(),
+            Inline::FootnoteReference(label) => resolved &= self.footnotes.contains_key(label),
+            _ => {}
+        });
+        resolved
+    }
+
+    /// Add the definitions found in `block`
+    fn collect(&mut self, block: &Block) {
+        match block {
+            Block::Definition(def) => {
+                self.links
+                    .entry(normalize_label(&def.label))
+                    .or_insert_with(|| def.clone());
+            }
+            Block::FootnoteDefinition(def) => {
+                self.footnotes
+                    .entry(def.label.clone())
+                    .or_insert_with(|| def.clone());
+            }
+            Block::List(list) => {
+                for item in &list.items {
+                    item.blocks.iter().for_each(|b| self.collect(b));
+                }
+            }
+            Block::DefinitionList(list) => {
+                for item in &list.items {
+                    item.definitions
+                        .iter()
+                        .flatten()
+                        .for_each(|b| self.collect(b));
+                }
+            }
+            Block::BlockQuote(blocks) => blocks.iter().for_each(|b| self.collect(b)),
+            Block::GitHubAlert(alert) => alert.blocks.iter().for_each(|b| self.collect(b)),
+            Block::Container(container) => container.blocks.iter().for_each(|b| self.collect(b)),
+            Block::Extension(extension) => extension.children.iter().for_each(|b| self.collect(b)),
+            _ => {}
+        }
+    }
+}
+
+/// Call `f` for every inline in `block`, including nested ones
+fn visit_block_inlines(block: &Block, f: &mut impl FnMut(&Inline)) {
+    match block {
+        Block::Paragraph(inlines) => visit_inlines(inlines, f),
+        Block::Heading(heading) => visit_inlines(&heading.content, f),
+        Block::BlockQuote(blocks) => blocks.iter().for_each(|b| visit_block_inlines(b, f)),
+        Block::List(list) => {
+            for item in &list.items {
+                item.blocks.iter().for_each(|b| visit_block_inlines(b, f));
+            }
+        }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                visit_inlines(&item.term, f);
+                item.definitions
+                    .iter()
+                    .flatten()
+                    .for_each(|b| visit_block_inlines(b, f));
+            }
+        }
+        Block::Table(table) => {
+            for cell in table.rows.iter().flatten() {
+                visit_inlines(&cell.content, f);
+            }
+        }
+        Block::Figure {
+            content, caption, ..
+        } => {
+            content.iter().for_each(|b| visit_block_inlines(b, f));
+            visit_inlines(caption, f);
+        }
+        Block::FootnoteDefinition(def) => def.blocks.iter().for_each(|b| visit_block_inlines(b, f)),
+        Block::GitHubAlert(alert) => alert.blocks.iter().for_each(|b| visit_block_inlines(b, f)),
+        Block::Container(container) => container
+            .blocks
+            .iter()
+            .for_each(|b| visit_block_inlines(b, f)),
+        Block::Extension(extension) => extension
+            .children
+            .iter()
+            .for_each(|b| visit_block_inlines(b, f)),
+        Block::ThematicBreak
+        | Block::CodeBlock(_)
+        | Block::HtmlBlock(_)
+        | Block::Definition(_)
+        | Block::AbbreviationDefinition { .. }
+        | Block::LatexBlock { .. }
+        | Block::Empty
+        | Block::MacroBlock(_)
+        | Block::FrontMatter { .. } => {}
+    }
+}
+
+fn visit_inlines(inlines: &[Inline], f: &mut impl FnMut(&Inline)) {
+    for inline in inlines {
+        f(inline);
+        match inline {
+            Inline::Link(link) => visit_inlines(&link.children, f),
+            Inline::LinkReference(link) => visit_inlines(&link.text, f),
+            Inline::Emphasis(children)
+            | Inline::Strong(children)
+            | Inline::Strikethrough(children)
+            | Inline::Superscript(children)
+            | Inline::Subscript(children)
+            | Inline::Highlight(children)
+            | Inline::Insert(children) => visit_inlines(children, f),
+            Inline::Span(span) => visit_inlines(&span.children, f),
+            Inline::Extension(extension) => visit_inlines(&extension.children, f),
+            _ => {}
+        }
+    }
+}
@@ -12,6 +12,16 @@ pub(crate) fn eof_or_eol(input: &str) -> IResult<&str, &str> {
     alt((line_ending, eof)).parse(input)
 }
 
+/// Whether delimited `content` contains a blank line between its first and
+/// last lines; fenced-by-delimiter blocks such as display math cannot span one
+pub(crate) fn has_blank_line(content: &str) -> bool {
+    let lines: Vec<&str> = content.split('\n').collect();
+    lines.len() > 2
+        && lines[1..lines.len() - 1]
+            .iter()
+            .any(|l| l.trim().is_empty())
+}
+
 pub(crate) fn many_empty_lines0(input: &str) -> IResult<&str, Vec<&str>> {
     many0(preceded(space0, eof_or_eol)).parse(input)
 }
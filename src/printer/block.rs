@@ -30,7 +30,14 @@ impl<'a> ToDoc<'a> for Vec<&Block> {
                         // empty line before list block
                         acc = acc.append(arena.hardline());
                     }
-                } else {
+                } else if !matches!(
+                    (&self[i - 1], block),
+                    (
+                        Block::AbbreviationDefinition { .. },
+                        Block::AbbreviationDefinition { .. }
+                    )
+                ) {
+                    // consecutive abbreviation definitions stay on consecutive lines
                     acc = acc.append(arena.hardline());
                 }
             }
@@ -55,10 +62,21 @@ impl<'a> ToDoc<'a> for Block {
                 crate::printer::blockquote::blockquote_to_doc(config, arena, inner)
             }
             Block::List(v) => v.to_doc(config, arena),
-            Block::CodeBlock(CodeBlock { kind, literal }) => {
+            Block::DefinitionList(v) => v.to_doc(config, arena),
+            Block::CodeBlock(CodeBlock {
+                kind,
+                literal,
+                attributes,
+            }) => {
                 match kind {
                     CodeBlockKind::Fenced { info } => {
-                        let info = info.as_deref().unwrap_or("");
+                        let info = match (info.as_deref(), attributes.is_empty()) {
+                            (info, true) => info.unwrap_or("").to_owned(),
+                            (None, false) => crate::printer::attributes_text(attributes),
+                            (Some(info), false) => {
+                                format!("{info} {}", crate::printer::attributes_text(attributes))
+                            }
+                        };
                         // Use hardline() between lines so nest() indentation applies correctly
                         // when the code block is inside a list or other nested structure.
                         // We use split('\n') instead of lines() to preserve trailing newlines.
@@ -99,6 +117,10 @@ impl<'a> ToDoc<'a> for Block {
                 }
             }
             Block::HtmlBlock(html) => arena.text(html.clone()),
+            Block::AbbreviationDefinition {
+                abbreviation,
+                expansion,
+            } => arena.text(format!("*[{abbreviation}]: {expansion}")),
             Block::Definition(def) => arena
                 .text("[")
                 .append(def.label.to_doc_inline(true, arena, config.clone()))
@@ -114,13 +136,41 @@ impl<'a> ToDoc<'a> for Block {
 
             Block::Empty => arena.nil(),
             Block::Table(v) => v.to_doc(config, arena),
+            Block::Figure {
+                content,
+                caption,
+                label,
+                caption_syntax,
+            } => {
+                let doc = labelled_figure_content(content, label).to_doc(config.clone(), arena);
+                let marker = match caption_syntax {
+                    // The caption is printed as the title or alt text of the image
+                    CaptionSyntax::Image => return doc,
+                    CaptionSyntax::Table => "Table: ",
+                    CaptionSyntax::Colon => ": ",
+                };
+                doc.append(arena.hardline())
+                    .append(arena.text(marker))
+                    .append(caption.to_doc_inline(false, arena, config))
+            }
             Block::FootnoteDefinition(def) => arena
                 .text(format!("[^{}]: ", def.label))
                 .append(def.blocks.to_doc(config, arena)),
             Block::GitHubAlert(alert) => {
                 crate::printer::github_alert::github_alert_to_doc(alert, config, arena)
             }
-            Block::LatexBlock(latex) => arena.text(format!("$${}$$", latex)),
+            Block::LatexBlock { delimiter, content } => match delimiter {
+                DisplayMathDelimiter::Fence => arena
+                    .text(delimiter.open())
+                    .append(arena.hardline())
+                    .append(crate::printer::verbatim(arena, content))
+                    .append(arena.hardline())
+                    .append(arena.text(delimiter.close())),
+                _ => arena
+                    .text(delimiter.open())
+                    .append(crate::printer::verbatim(arena, content))
+                    .append(arena.text(delimiter.close())),
+            },
             Block::Container(container) => {
                 let mut doc = arena.text(format!(":::{}", container.kind));
                 if !container.blocks.is_empty() {
@@ -131,6 +181,50 @@ impl<'a> ToDoc<'a> for Block {
                 doc.append(arena.text(":::"))
             }
             Block::MacroBlock(content) => arena.text(format!("{{{{ {} }}}}", content)),
+            Block::Extension(extension) => {
+                if let Some(renderer) = &config.block_extension_renderer {
+                    let children = crate::printer::render_fragment(config.width, |arena| {
+                        extension.children.to_doc(config.clone(), arena)
+                    });
+                    if let Some(rendered) = renderer(extension, &children) {
+                        return crate::printer::verbatim(arena, &rendered);
+                    }
+                }
+                extension.children.to_doc(config, arena)
+            }
+            Block::FrontMatter { format, raw } => {
+                // The metadata is printed verbatim, line by line
+                let mut doc = arena.text(format.fence());
+                if !raw.is_empty() {
+                    for line in raw.split('\n') {
+                        doc = doc
+                            .append(arena.hardline())
+                            .append(arena.text(line.to_string()));
+                    }
+                }
+                doc.append(arena.hardline())
+                    .append(arena.text(format.fence()))
+            }
         }
     }
 }
+
+/// The content of a figure, with the label put back as the `{#id}` of its image or table
+fn labelled_figure_content(content: &[Block], label: &Option<String>) -> Vec<Block> {
+    let mut content = content.to_vec();
+    let Some(label) = label else {
+        return content;
+    };
+    let attributes = content.iter_mut().find_map(|block| match block {
+        Block::Table(table) => Some(&mut table.attributes),
+        Block::Paragraph(inlines) => inlines.iter_mut().find_map(|inline| match inline {
+            Inline::Image(image) => Some(&mut image.attributes),
+            _ => None,
+        }),
+        _ => None,
+    });
+    if let Some(attributes) = attributes {
+        attributes.id.get_or_insert_with(|| label.clone());
+    }
+    content
+}
@@ -1,9 +1,26 @@
+use crate::ast::{BlockExtension, InlineExtension};
+use std::rc::Rc;
+
+/// Renders a [`BlockExtension`] node to Markdown.
+///
+/// The second argument is the node's children, already rendered. Returning
+/// `None` falls back to the default rendering, which prints the children only.
+pub type BlockExtensionRenderer = Rc<dyn Fn(&BlockExtension, &str) -> Option<String>>;
+
+/// Renders an [`InlineExtension`] node to Markdown.
+///
+/// The second argument is the node's children, already rendered. Returning
+/// `None` falls back to the default rendering, which prints the children only.
+pub type InlineExtensionRenderer = Rc<dyn Fn(&InlineExtension, &str) -> Option<String>>;
+
 /// Configuration for Markdown pretty-printing output.
 pub struct Config {
     pub(crate) width: usize,
     pub(crate) spaces_before_list_item: usize,
     pub(crate) empty_line_before_list: bool,
     pub(crate) smart_wrapping: bool,
+    pub(crate) block_extension_renderer: Option<BlockExtensionRenderer>,
+    pub(crate) inline_extension_renderer: Option<InlineExtensionRenderer>,
 }
 
 impl Default for Config {
@@ -13,6 +30,8 @@ impl Default for Config {
             spaces_before_list_item: 1,
             empty_line_before_list: true,
             smart_wrapping: false,
+            block_extension_renderer: None,
+            inline_extension_renderer: None,
         }
     }
 }
@@ -64,4 +83,24 @@ impl Config {
             ..self
         }
     }
+
+    /// Sets the renderer used for [`Block::Extension`](crate::ast::Block::Extension) nodes.
+    ///
+    /// Without a renderer, extension blocks print their children only.
+    pub fn with_block_extension_renderer(self, renderer: BlockExtensionRenderer) -> Self {
+        Self {
+            block_extension_renderer: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Sets the renderer used for [`Inline::Extension`](crate::ast::Inline::Extension) nodes.
+    ///
+    /// Without a renderer, extension inlines print their children only.
+    pub fn with_inline_extension_renderer(self, renderer: InlineExtensionRenderer) -> Self {
+        Self {
+            inline_extension_renderer: Some(renderer),
+            ..self
+        }
+    }
 }
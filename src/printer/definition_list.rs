@@ -0,0 +1,55 @@
+use crate::ast::*;
+use crate::printer::{inline::ToDocInline, ToDoc};
+use pretty::{Arena, DocAllocator, DocBuilder};
+use std::rc::Rc;
+
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(
+        &self,
+        config: Rc<crate::printer::config::Config>,
+        arena: &'a Arena<'a>,
+    ) -> DocBuilder<'a, Arena<'a>, ()> {
+        let items = self.items.iter().map(|item| {
+            // The term must stay on a single line, otherwise it becomes a paragraph
+            let term = item.term.to_doc_inline(false, arena, config.clone());
+            let definitions = item
+                .definitions
+                .iter()
+                .map(|blocks| definition_to_doc(blocks, config.clone(), arena));
+            term.append(arena.hardline())
+                .append(arena.intersperse(definitions, arena.hardline()))
+        });
+
+        arena.intersperse(items, arena.hardline().append(arena.hardline()))
+    }
+}
+
+/// Render the blocks of a definition after a `: ` marker, indenting the
+/// continuation lines like blockquotes prefix theirs, so that empty lines
+/// stay empty.
+fn definition_to_doc<'a>(
+    blocks: &[Block],
+    config: Rc<crate::printer::config::Config>,
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let blocks = blocks.to_owned();
+    arena.column(move |current_column| {
+        let prefix = ": ";
+        let text = crate::printer::render_fragment(
+            config.width.saturating_sub(current_column + prefix.len()),
+            |arena| blocks.to_doc(config.clone(), arena),
+        );
+
+        let lines = text.lines().enumerate().map(|(index, line)| {
+            if index == 0 {
+                arena.text(format!("{prefix}{line}"))
+            } else if line.is_empty() {
+                arena.nil()
+            } else {
+                arena.text(format!("  {line}"))
+            }
+        });
+
+        arena.intersperse(lines, arena.hardline()).into_doc()
+    })
+}
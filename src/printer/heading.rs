@@ -1,5 +1,5 @@
 use crate::ast::*;
-use crate::printer::{inline::ToDocInline, ToDoc};
+use crate::printer::{attributes_text, inline::ToDocInline, ToDoc};
 use pretty::{Arena, DocAllocator, DocBuilder};
 use std::rc::Rc;
 
@@ -9,6 +9,11 @@ impl<'a> ToDoc<'a> for Heading {
         config: Rc<crate::printer::config::Config>,
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
+        let id = if self.attributes.is_empty() {
+            arena.nil()
+        } else {
+            arena.text(format!(" {}", attributes_text(&self.attributes)))
+        };
         match self.kind {
             HeadingKind::Atx(level) => {
                 let hashes = "#".repeat(level as usize);
@@ -16,15 +21,18 @@ impl<'a> ToDoc<'a> for Heading {
                     .text(hashes)
                     .append(arena.space())
                     .append(self.content.to_doc_inline(false, arena, config.clone()))
+                    .append(id)
             }
             HeadingKind::Setext(SetextHeading::Level1) => self
                 .content
                 .to_doc_inline(true, arena, config.clone())
+                .append(id)
                 .append(arena.hardline())
                 .append(arena.text("==========")),
             HeadingKind::Setext(SetextHeading::Level2) => self
                 .content
                 .to_doc_inline(true, arena, config.clone())
+                .append(id)
                 .append(arena.hardline())
                 .append(arena.text("----------")),
         }
@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::printer::attributes_text;
 use crate::printer::config::Config;
 use crate::printer::markdown_syntax_detector::is_safe_line_break_before;
 use pretty::{Arena, DocAllocator, DocBuilder};
@@ -55,7 +56,12 @@ impl<'a> ToDocInline<'a> for Inline {
             // TODO parametrize format
             Inline::LineBreak => arena.text("  \n"),
             Inline::Code(code) => arena.text("`").append(code.clone()).append(arena.text("`")),
-            Inline::Latex(latex) => arena.text(format!("${}$", latex)),
+            Inline::Latex { delimiter, content } => arena.text(format!(
+                "{}{}{}",
+                delimiter.open(),
+                content,
+                delimiter.close()
+            )),
             Inline::Html(html) => arena.text(html.clone()),
             Inline::Emphasis(children) => arena
                 .text("*")
@@ -69,10 +75,27 @@ impl<'a> ToDocInline<'a> for Inline {
                 .text("~~")
                 .append(children.to_doc_inline(allow_newlines, arena, config.clone()))
                 .append(arena.text("~~")),
+            Inline::Superscript(children) => arena
+                .text("^")
+                .append(children.to_doc_inline(allow_newlines, arena, config.clone()))
+                .append(arena.text("^")),
+            Inline::Subscript(children) => arena
+                .text("~")
+                .append(children.to_doc_inline(allow_newlines, arena, config.clone()))
+                .append(arena.text("~")),
+            Inline::Highlight(children) => arena
+                .text("==")
+                .append(children.to_doc_inline(allow_newlines, arena, config.clone()))
+                .append(arena.text("==")),
+            Inline::Insert(children) => arena
+                .text("++")
+                .append(children.to_doc_inline(allow_newlines, arena, config.clone()))
+                .append(arena.text("++")),
             Inline::Link(Link {
                 destination,
                 title,
                 children,
+                attributes,
             }) => {
                 let title = match title {
                     Some(v) => arena
@@ -88,36 +111,19 @@ impl<'a> ToDocInline<'a> for Inline {
                     .append(arena.text(destination.clone()))
                     .append(title)
                     .append(")")
+                    .append(arena.text(attributes_text(attributes)))
             }
             Inline::Image(Image {
                 destination,
                 title,
                 alt,
-                attr,
+                attributes,
             }) => {
                 let title_part = title
                     .as_ref()
                     .map(|t| format!(" \"{t}\""))
                     .unwrap_or_default();
 
-                let attr_part = attr
-                    .as_ref()
-                    .map(|a| {
-                        let mut attrs = Vec::new();
-                        if let Some(width) = &a.width {
-                            attrs.push(format!("width=\"{}\"", width));
-                        }
-                        if let Some(height) = &a.height {
-                            attrs.push(format!("height=\"{}\"", height));
-                        }
-                        if attrs.is_empty() {
-                            String::new()
-                        } else {
-                            format!("{{{}}}", attrs.join(" "))
-                        }
-                    })
-                    .unwrap_or_default();
-
                 arena
                     .text("![")
                     .append(arena.text(alt.clone()))
@@ -125,20 +131,70 @@ impl<'a> ToDocInline<'a> for Inline {
                     .append(arena.text(destination.clone()))
                     .append(arena.text(title_part))
                     .append(arena.text(")"))
-                    .append(arena.text(attr_part))
+                    .append(arena.text(attributes_text(attributes)))
             }
-            Inline::Autolink(link) => arena.text(format!("<{link}>")),
+            Inline::Autolink { url, bare: true } => arena.text(url.clone()),
+            Inline::Autolink { url, bare: false } => arena.text(format!("<{url}>")),
             Inline::FootnoteReference(label) => arena.text(format!("[^{label}]")),
+            Inline::WikiLink(wiki_link) => {
+                let mut text = String::from(if wiki_link.embed { "![[" } else { "[[" });
+                text.push_str(&wiki_link.target);
+                if let Some(fragment) = &wiki_link.fragment {
+                    text.push('#');
+                    text.push_str(fragment);
+                }
+                if let Some(alias) = &wiki_link.alias {
+                    text.push('|');
+                    text.push_str(alias);
+                }
+                text.push_str("]]");
+                arena.text(text)
+            }
+            Inline::Span(span) => {
+                // A span always needs its attribute list, even an empty one
+                let attributes = match attributes_text(&span.attributes) {
+                    text if text.is_empty() => "{}".to_owned(),
+                    text => text,
+                };
+                arena
+                    .text("[")
+                    .append(
+                        span.children
+                            .to_doc_inline(allow_newlines, arena, config.clone()),
+                    )
+                    .append(arena.text("]"))
+                    .append(arena.text(attributes))
+            }
+            Inline::GitHubReference(reference) => arena.text(reference.text()),
+            Inline::Citation(citation) => arena.text(citation.text()),
+            Inline::Emoji { shortcode, .. } => arena.text(format!(":{shortcode}:")),
+            Inline::Abbreviation { text, .. } => arena.text(text.clone()),
+            Inline::Extension(extension) => {
+                if let Some(renderer) = &config.inline_extension_renderer {
+                    let children = crate::printer::render_fragment(config.width, |arena| {
+                        extension
+                            .children
+                            .to_doc_inline(allow_newlines, arena, config.clone())
+                    });
+                    if let Some(rendered) = renderer(extension, &children) {
+                        return crate::printer::verbatim(arena, &rendered);
+                    }
+                }
+                extension
+                    .children
+                    .to_doc_inline(allow_newlines, arena, config)
+            }
             Inline::Empty => arena.nil(),
             Inline::LinkReference(v) => {
+                let open = if v.image { "![" } else { "[" };
                 if v.label == v.text {
                     return arena
-                        .text("[")
+                        .text(open)
                         .append(v.label.to_doc_inline(allow_newlines, arena, config.clone()))
                         .append("]");
                 }
                 arena
-                    .text("[")
+                    .text(open)
                     .append(v.text.to_doc_inline(allow_newlines, arena, config.clone()))
                     .append("][")
                     .append(v.label.to_doc_inline(allow_newlines, arena, config.clone()))
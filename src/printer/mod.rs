@@ -22,6 +22,7 @@
 //!     blocks: vec![
 //!         Block::Heading(Heading {
 //!             kind: HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
 //!             content: vec![Inline::Text("Hello World".to_string())],
 //!         }),
 //!         Block::Paragraph(vec![
@@ -52,6 +53,7 @@
 
 mod block;
 mod blockquote;
+mod definition_list;
 
 /// Configuration options for Markdown pretty-printing.
 pub mod config;
@@ -64,7 +66,7 @@ mod table;
 mod tests;
 
 use crate::ast::*;
-use pretty::{Arena, DocBuilder};
+use pretty::{Arena, DocAllocator, DocBuilder};
 use std::rc::Rc;
 
 /// Render a Markdown AST back to formatted Markdown text
@@ -147,3 +149,51 @@ impl<'a> ToDoc<'a> for Document {
         self.blocks.to_doc(config, arena)
     }
 }
+
+/// Render a fragment on its own arena, e.g. to hand it to an extension renderer.
+pub(crate) fn render_fragment(
+    width: usize,
+    f: impl for<'a> FnOnce(&'a Arena<'a>) -> DocBuilder<'a, Arena<'a>, ()>,
+) -> String {
+    let arena = Arena::new();
+    let mut buf = Vec::new();
+    f(&arena).render(width, &mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+/// Format an attribute list as `{#id .class key="value"}`, or an empty string
+/// if there is nothing to write.
+pub(crate) fn attributes_text(attributes: &crate::ast::Attributes) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let mut parts = Vec::new();
+    if let Some(id) = &attributes.id {
+        parts.push(format!("#{id}"));
+    }
+    for class in &attributes.classes {
+        parts.push(format!(".{class}"));
+    }
+    for (key, value) in &attributes.pairs {
+        if value.contains('"') {
+            parts.push(format!("{key}='{value}'"));
+        } else {
+            parts.push(format!("{key}=\"{value}\""));
+        }
+    }
+    format!("{{{}}}", parts.join(" "))
+}
+
+/// Embed renderer output line by line so that it follows the current indentation.
+pub(crate) fn verbatim<'a>(arena: &'a Arena<'a>, text: &str) -> DocBuilder<'a, Arena<'a>, ()> {
+    arena.intersperse(
+        text.split('\n').map(|line| {
+            if line.is_empty() {
+                arena.nil()
+            } else {
+                arena.text(line.to_string())
+            }
+        }),
+        arena.hardline(),
+    )
+}
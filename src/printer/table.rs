@@ -27,6 +27,9 @@ impl<'a> ToDoc<'a> for Table {
 
         let mut rows = vec![header, separator];
         rows.extend(body);
+        if !self.attributes.is_empty() {
+            rows.push(arena.text(crate::printer::attributes_text(&self.attributes)));
+        }
 
         // Render table with unlimited width to prevent line wrapping
         let table_doc = arena.intersperse(rows, arena.hardline());
@@ -320,3 +320,315 @@ fn symmetric_round_trip(input: &str) {
     let result = crate::printer::render_markdown(&doc, config);
     assert_eq!(input, result);
 }
+
+#[rstest(
+    input,
+    case("---\ntitle: Hello\n\ntags: [a, b]\n---\n\n# Hello"),
+    case("+++\ntitle = \"Hello\"\n+++\n\ntext"),
+    case("---\n---\n\n---")
+)]
+fn front_matter_round_trip(input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_front_matter_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    assert!(matches!(
+        doc.blocks[0],
+        crate::ast::Block::FrontMatter { .. }
+    ));
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(
+    input,
+    case("# Install *now* {#install}"),
+    case("Title {#title}\n==========")
+)]
+fn heading_id_round_trip(input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default().with_allow_heading_ids();
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[test]
+fn extension_renderer_round_trip() {
+    use crate::ast::{Inline, InlineExtension};
+    use crate::parser::config::{BuiltinInlineParser, MarkdownParserConfig, ParserPriority};
+    use nom::{bytes::complete::take_until, sequence::delimited, Parser};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let kbd: crate::parser::config::CustomInlineParserFn =
+        Rc::new(RefCell::new(Box::new(|input: &str| {
+            delimited(
+                nom::bytes::complete::tag("++"),
+                take_until("++"),
+                nom::bytes::complete::tag("++"),
+            )
+            .map(|key: &str| {
+                vec![Inline::Extension(InlineExtension {
+                    name: "kbd".to_string(),
+                    attrs: vec![],
+                    children: vec![Inline::Text(key.to_string())],
+                })]
+            })
+            .parse(input)
+        })));
+    let config = MarkdownParserConfig::default().with_named_custom_inline_parser(
+        "kbd",
+        ParserPriority::Before(BuiltinInlineParser::Text),
+        kbd,
+    );
+    let input = "Press ++Ctrl++ to copy";
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(result, "Press Ctrl to copy");
+
+    let config = crate::printer::config::Config::default().with_inline_extension_renderer(Rc::new(
+        |extension, children| (extension.name == "kbd").then(|| format!("++{children}++")),
+    ));
+    assert_eq!(input, crate::printer::render_markdown(&doc, config));
+}
+
+#[rstest(
+    input,
+    case("See [[Home Page]] and [[Setup#Linux|install notes]]."),
+    case("![[diagram.png]] and [[#Usage]]")
+)]
+fn wiki_link_round_trip(input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_wiki_link_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(
+    input,
+    case("Apple\n: A fruit\n: A company\n\n*Banana*\n: Yellow"),
+    case("Term\n: First paragraph\n\n  Second paragraph\n\n   - item")
+)]
+fn definition_list_round_trip(input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_definition_list_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(
+    input,
+    case("# Install {#install .unnumbered lang=\"en\"}"),
+    case("```rust {#main .numberLines}\nfn main() {}\n```"),
+    case("```{.text}\nplain\n```"),
+    case("[docs](/docs){.external target=\"_blank\"} ![logo](/l.png){#logo width=\"9pt\"}"),
+    case("| a |\n| - |\n| b |\n{#results .wide}")
+)]
+fn attributes_round_trip(input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default().with_allow_attributes();
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(
+    input,
+    case("Press [Ctrl]{.kbd} and [*bonjour*]{#greeting lang=\"fr\"}."),
+    case("[text]{}")
+)]
+fn span_round_trip(input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_span_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(
+    input,
+    case("Inline $a$, \\(b\\) and $`c`$."),
+    case("$$x^2$$"),
+    case("\\[x^2\\]"),
+    case("```math\na^2\n+ b^2\n```")
+)]
+fn latex_delimiters_round_trip(input: &str) {
+    use crate::parser::config::ElementBehavior;
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_latex_parentheses_behavior(ElementBehavior::Parse)
+        .with_inline_latex_code_behavior(ElementBehavior::Parse)
+        .with_block_latex_brackets_behavior(ElementBehavior::Parse)
+        .with_block_latex_fence_behavior(ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[test]
+fn emoji_round_trip() {
+    let input = "Ship it :rocket: :+1:";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_emoji_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[test]
+fn extended_autolink_round_trip() {
+    let input = "See https://example.com, www.example.com or foo@example.com and <https://a.b>.";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_extended_autolink_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[test]
+fn github_reference_round_trip() {
+    let input = "Fixed #12, owner/repo#45 in a5c3785 (owner/repo@0f1e2d3c) by @alice, @org/team.";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_mention_behavior(crate::parser::config::ElementBehavior::Parse)
+        .with_inline_issue_reference_behavior(crate::parser::config::ElementBehavior::Parse)
+        .with_inline_commit_reference_behavior(crate::parser::config::ElementBehavior::Parse)
+        .with_github_reference_resolver(std::rc::Rc::new(|reference| {
+            Some(reference.github_url("owner/repo"))
+        }));
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest]
+#[case("2^10^ and H~2~O, ~~gone~~")]
+#[case("==key== and ++new++ in C++ or a == b")]
+#[case("^a\\ b^ and x~i+1~")]
+fn superscript_subscript_highlight_insert_round_trip(#[case] input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_superscript_behavior(crate::parser::config::ElementBehavior::Parse)
+        .with_inline_subscript_behavior(crate::parser::config::ElementBehavior::Parse)
+        .with_inline_highlight_behavior(crate::parser::config::ElementBehavior::Parse)
+        .with_inline_insert_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[test]
+fn abbreviation_definitions_round_trip() {
+    let input =
+        "The HTML spec.\n\n*[HTML]: HyperText Markup Language\n*[W3C]: World Wide Web Consortium";
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_abbreviation_definition_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest]
+#[case("![A cat](/cat.png){#fig-cat}\n\nText")]
+#[case("![cat](/cat.png \"A sleeping cat\")")]
+#[case("| a |\n| - |\n| b |\nTable: Results of *a*")]
+#[case("| a |\n| - |\n| b |\n{#tbl-results}\n: Results")]
+fn figure_round_trip(#[case] input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_allow_attributes()
+        .with_block_figure_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    assert!(matches!(doc.blocks[0], crate::ast::Block::Figure { .. }));
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest]
+#[case("Table: Results\n\n| a |\n| - |\n| b |")]
+#[case("| a |\n| - |\n| b |\n\nTable: Results")]
+fn figure_caption_printed_after_table(#[case] input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_block_figure_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(result, "| a |\n| - |\n| b |\nTable: Results");
+}
+
+#[rstest]
+#[case("As shown [see @doe99, pp. 33-35; also -@smith04, chap. 1].")]
+#[case("@doe99 says so, and @smith04 [p. 33] agrees.")]
+fn citation_round_trip(#[case] input: &str) {
+    let config = crate::parser::config::MarkdownParserConfig::default()
+        .with_inline_citation_behavior(crate::parser::config::ElementBehavior::Parse);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::with_config(config),
+        input,
+    )
+    .unwrap();
+    let crate::ast::Block::Paragraph(inlines) = &doc.blocks[0] else {
+        panic!("expected a paragraph, got {:?}", doc.blocks);
+    };
+    assert!(inlines
+        .iter()
+        .any(|inline| matches!(inline, crate::ast::Inline::Citation(_))));
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
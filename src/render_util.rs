@@ -0,0 +1,19 @@
+//! Document helpers shared by the HTML, LaTeX and Typst renderers
+
+use pretty::{Arena, DocAllocator, DocBuilder};
+
+/// Emit text line by line, preserving line breaks and indentation.
+pub(crate) fn verbatim<'a>(arena: &'a Arena<'a>, text: &str) -> DocBuilder<'a, Arena<'a>, ()> {
+    arena.intersperse(
+        text.split('\n').map(|line| arena.text(line.to_string())),
+        arena.hardline(),
+    )
+}
+
+/// Render an already built document, e.g. to hand it to an extension renderer.
+#[cfg(any(feature = "html-printer", feature = "typst-printer"))]
+pub(crate) fn render_to_string<'a>(doc: DocBuilder<'a, Arena<'a>, ()>, width: usize) -> String {
+    let mut buf = Vec::new();
+    doc.render(width, &mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
@@ -1,5 +1,7 @@
 use crate::ast::*;
-use crate::typst_printer::util::{body, escape_typst};
+use crate::render_util::{render_to_string, verbatim};
+use crate::typst_printer::table::table_function;
+use crate::typst_printer::util::{body, escape_typst, label};
 use crate::typst_printer::ToDoc;
 use pretty::{Arena, DocAllocator, DocBuilder};
 
@@ -36,6 +38,10 @@ impl<'a> ToDoc<'a> for Block {
                     HeadingKind::Setext(SetextHeading::Level1) => 1,
                     HeadingKind::Setext(SetextHeading::Level2) => 2,
                 };
+                let label = match state.heading_label(heading) {
+                    Some(label) => state.arena.text(format!(" <{label}>")),
+                    None => state.arena.nil(),
+                };
                 state
                     .arena
                     .text("#heading(level: ")
@@ -44,6 +50,7 @@ impl<'a> ToDoc<'a> for Block {
                     // .append(state.arena.space())
                     .append(heading.content.to_doc(state))
                     .append("])")
+                    .append(label)
             }
 
             Block::ThematicBreak => state.arena.text("#thematic-break"),
@@ -62,6 +69,8 @@ impl<'a> ToDoc<'a> for Block {
 
             Block::List(list) => list.to_doc(state),
 
+            Block::DefinitionList(list) => list.to_doc(state),
+
             Block::CodeBlock(code_block) => {
                 let lang = match &code_block.kind {
                     CodeBlockKind::Fenced { info: Some(lang) } => lang.as_str(),
@@ -79,6 +88,7 @@ impl<'a> ToDoc<'a> for Block {
                 args.push(state.arena.text(format!(r#", "{}""#, escaped_code)));
 
                 body(&state.arena, "raw", Some(state.arena.concat(args)), vec![])
+                    .append(label(state.arena, &code_block.attributes))
             }
 
             Block::HtmlBlock(html) => body(
@@ -88,9 +98,45 @@ impl<'a> ToDoc<'a> for Block {
                 vec![state.arena.text(escape_typst(html))],
             ),
 
-            Block::Definition(_) => state.arena.nil(),
+            Block::Definition(_) | Block::AbbreviationDefinition { .. } => state.arena.nil(),
 
-            Block::Table(table) => table.to_doc(state),
+            Block::Table(table) => table
+                .to_doc(state)
+                .append(label(state.arena, &table.attributes)),
+
+            Block::Figure {
+                content,
+                caption,
+                label,
+                ..
+            } => {
+                // A table is the figure body itself, not content wrapped in `[...]`
+                let body = match content.as_slice() {
+                    [Block::Table(table)] if !table.rows.is_empty() => table_function(table, state),
+                    [Block::Paragraph(inlines)] => state
+                        .arena
+                        .text("[")
+                        .append(inlines.to_doc(state))
+                        .append(state.arena.text("]")),
+                    _ => state
+                        .arena
+                        .text("[")
+                        .append(content.to_doc(state))
+                        .append(state.arena.text("]")),
+                };
+                let label = match label {
+                    Some(label) => state.arena.text(format!(" <{label}>")),
+                    None => state.arena.nil(),
+                };
+                state
+                    .arena
+                    .text("#figure(")
+                    .append(body)
+                    .append(state.arena.text(", caption: ["))
+                    .append(caption.to_doc(state))
+                    .append(state.arena.text("])"))
+                    .append(label)
+            }
 
             Block::FootnoteDefinition(_) => state.arena.nil(),
 
@@ -114,7 +160,7 @@ impl<'a> ToDoc<'a> for Block {
             }
 
             Block::Empty => state.arena.nil(),
-            Block::LatexBlock(latex) => state
+            Block::LatexBlock { content: latex, .. } => state
                 .arena
                 .text("#mi(block: true, \"")
                 .append(state.arena.text(escape_typst(&latex.clone())))
@@ -157,6 +203,27 @@ impl<'a> ToDoc<'a> for Block {
                 }
             }
             Block::MacroBlock(_) => state.arena.nil(),
+            Block::Extension(extension) => {
+                let children = extension.children.to_doc(state);
+                if let Some(renderer) = &state.config.block_extension_renderer {
+                    let rendered = render_to_string(children.clone(), state.config.width);
+                    if let Some(typst) = renderer(extension, &rendered) {
+                        return verbatim(state.arena, &typst);
+                    }
+                }
+                children
+            }
+            Block::FrontMatter { format, raw } => {
+                // Kept as metadata so that it can be read back with `query`
+                let format = match format {
+                    FrontMatterFormat::Yaml => "yaml",
+                    FrontMatterFormat::Toml => "toml",
+                };
+                state.arena.text(format!(
+                    r#"#metadata((format: "{format}", raw: "{}"))<front-matter>"#,
+                    escape_typst(raw)
+                ))
+            }
         }
     }
 }
@@ -185,6 +252,56 @@ impl<'a> ToDoc<'a> for List {
     }
 }
 
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(&self, state: &'a crate::typst_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        if self.items.is_empty() {
+            return state.arena.nil();
+        }
+        let mut doc = state.arena.nil();
+        if state.render_with_hash {
+            doc = doc.append(state.arena.text("#"));
+        }
+        doc = doc.append("terms(\n  ");
+
+        let items = state.arena.intersperse(
+            self.items.iter().map(|item| item.to_doc(state)),
+            state.arena.text(",\n  "),
+        );
+
+        doc.append(items).append(state.arena.text(",\n)"))
+    }
+}
+
+impl DefinitionListItem {
+    fn to_doc<'a>(
+        &self,
+        state: &'a crate::typst_printer::State<'a>,
+    ) -> DocBuilder<'a, Arena<'a>, ()> {
+        // Paragraphs contribute their inlines only, as in list items, and
+        // separate definitions of one term are set as separate paragraphs.
+        let description = state.arena.intersperse(
+            self.definitions.iter().map(|blocks| {
+                state.arena.intersperse(
+                    blocks.iter().map(|block| match block {
+                        Block::Paragraph(inlines) => inlines.to_doc(state),
+                        _ => block.to_doc(state),
+                    }),
+                    state.arena.line(),
+                )
+            }),
+            state.arena.text("\n\n"),
+        );
+
+        state
+            .arena
+            .text("terms.item[")
+            .append(self.term.to_doc(state))
+            .append(state.arena.text("]["))
+            .append(description)
+            .append(state.arena.text("]"))
+    }
+}
+
 impl ListItem {
     fn to_doc<'a>(
         &self,
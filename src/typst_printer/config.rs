@@ -3,6 +3,31 @@
 //! This module provides configuration options to customize the Typst output
 //! style and format.
 
+use crate::ast::{BlockExtension, InlineExtension, WikiLink};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Renders a [`BlockExtension`] node to Typst
+///
+/// The second argument is the node's children, already rendered to Typst.
+/// Returning `None` falls back to the default rendering, which emits the
+/// children only.
+pub type BlockExtensionRenderer = Rc<dyn Fn(&BlockExtension, &str) -> Option<String>>;
+
+/// Renders an [`InlineExtension`] node to Typst
+///
+/// The second argument is the node's children, already rendered to Typst.
+/// Returning `None` falls back to the default rendering, which emits the
+/// children only.
+pub type InlineExtensionRenderer = Rc<dyn Fn(&InlineExtension, &str) -> Option<String>>;
+
+/// Resolves the target of a [`WikiLink`] to a link destination
+///
+/// Returning `None` leaves the link unresolved: only its text is rendered,
+/// without a link. Without a resolver every wiki link is rendered as a link to
+/// its target, see [`WikiLink::destination`].
+pub type WikiLinkResolver = Rc<dyn Fn(&WikiLink) -> Option<String>>;
+
 /// Configuration for Typst rendering
 ///
 /// This struct controls various aspects of how the Markdown AST is converted
@@ -22,6 +47,12 @@
 /// ```
 pub struct Config {
     pub(crate) width: usize,
+    pub(crate) heading_labels: bool,
+    pub(crate) block_extension_renderer: Option<BlockExtensionRenderer>,
+    pub(crate) inline_extension_renderer: Option<InlineExtensionRenderer>,
+    pub(crate) wiki_link_resolver: Option<WikiLinkResolver>,
+    pub(crate) span_functions: HashMap<String, String>,
+    pub(crate) bibliography: Option<String>,
 }
 
 impl Default for Config {
@@ -29,8 +60,21 @@ impl Default for Config {
     ///
     /// Default settings:
     /// - Width: 80 characters
+    /// - Heading labels: only explicit `{#id}` identifiers
+    /// - Extension renderers: none
+    /// - Wiki link resolver: none, every wiki link goes to its target (`Page#Section`)
+    /// - Span functions: none, spans render their content only
+    /// - Bibliography: none
     fn default() -> Self {
-        Self { width: 80 }
+        Self {
+            width: 80,
+            heading_labels: false,
+            block_extension_renderer: None,
+            inline_extension_renderer: None,
+            wiki_link_resolver: None,
+            span_functions: HashMap::new(),
+            bibliography: None,
+        }
     }
 }
 
@@ -54,4 +98,136 @@ impl Config {
     pub fn with_width(self, width: usize) -> Self {
         Self { width, ..self }
     }
+
+    /// Attach a `<label>` to every heading
+    ///
+    /// Headings without an explicit `{#id}` are labelled with the
+    /// GitHub-compatible slug of their text, made unique with a numeric
+    /// suffix (see [`Slugger`](crate::ast::Slugger)), so that links to
+    /// `#slug` can be turned into references. Explicit identifiers are always
+    /// emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::typst_printer::config::Config;
+    ///
+    /// let config = Config::default().with_heading_labels(true);
+    /// ```
+    pub fn with_heading_labels(self, heading_labels: bool) -> Self {
+        Self {
+            heading_labels,
+            ..self
+        }
+    }
+
+    /// Set the renderer for [`Block::Extension`](crate::ast::Block::Extension) nodes
+    ///
+    /// Without a renderer, or when it returns `None`, only the children of an
+    /// extension block are rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::typst_printer::config::Config;
+    /// use std::rc::Rc;
+    ///
+    /// let config = Config::default().with_block_extension_renderer(Rc::new(|ext, children| {
+    ///     (ext.name == "aside").then(|| format!("#block(inset: 8pt)[{children}]"))
+    /// }));
+    /// ```
+    pub fn with_block_extension_renderer(self, renderer: BlockExtensionRenderer) -> Self {
+        Self {
+            block_extension_renderer: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Set the renderer for [`Inline::Extension`](crate::ast::Inline::Extension) nodes
+    ///
+    /// Without a renderer, or when it returns `None`, only the children of an
+    /// extension inline are rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::typst_printer::config::Config;
+    /// use std::rc::Rc;
+    ///
+    /// let config = Config::default().with_inline_extension_renderer(Rc::new(|ext, children| {
+    ///     (ext.name == "kbd").then(|| format!("#box(stroke: 0.5pt)[{children}]"))
+    /// }));
+    /// ```
+    pub fn with_inline_extension_renderer(self, renderer: InlineExtensionRenderer) -> Self {
+        Self {
+            inline_extension_renderer: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Set the resolver for the targets of wiki links
+    ///
+    /// Without a resolver, `[[Page#Section]]` links to `Page#Section`. Links
+    /// for which the resolver returns `None` render as their text only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::typst_printer::config::Config;
+    /// use std::rc::Rc;
+    ///
+    /// let config = Config::default().with_wiki_link_resolver(Rc::new(|link| {
+    ///     Some(format!("https://wiki.example.com/{}", link.target.replace(' ', "_")))
+    /// }));
+    /// ```
+    pub fn with_wiki_link_resolver(self, resolver: WikiLinkResolver) -> Self {
+        Self {
+            wiki_link_resolver: Some(resolver),
+            ..self
+        }
+    }
+
+    /// Render spans with the given class through a Typst function
+    ///
+    /// A span `[text]{.kbd}` is rendered as `#function[text]` for every one of
+    /// its classes that has a function, the first class being the outermost
+    /// call. Spans without such a class render their content only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::typst_printer::config::Config;
+    ///
+    /// let config = Config::default()
+    ///     .with_span_function("highlight", "highlight")
+    ///     .with_span_function("smallcaps", "smallcaps");
+    /// ```
+    pub fn with_span_function(
+        mut self,
+        class: impl Into<String>,
+        function: impl Into<String>,
+    ) -> Self {
+        self.span_functions.insert(class.into(), function.into());
+        self
+    }
+
+    /// Print the bibliography from the given file (e.g. `refs.bib`) at the end of the
+    /// document
+    ///
+    /// Typst only compiles citations (`@key`, `#cite(<key>)`) when the document holds a
+    /// bibliography.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::typst_printer::config::Config;
+    ///
+    /// let config = Config::default().with_bibliography("refs.bib");
+    /// ```
+    pub fn with_bibliography(self, path: impl Into<String>) -> Self {
+        Self {
+            bibliography: Some(path.into()),
+            ..self
+        }
+    }
 }
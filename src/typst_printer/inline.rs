@@ -1,5 +1,6 @@
 use crate::ast::*;
-use crate::typst_printer::util::{body, escape_typst};
+use crate::render_util::{render_to_string, verbatim};
+use crate::typst_printer::util::{body, escape_typst, label};
 use crate::typst_printer::ToDoc;
 use once_cell::sync::Lazy;
 use pretty::{Arena, DocAllocator, DocBuilder};
@@ -72,10 +73,20 @@ impl<'a> ToDoc<'a> for Inline {
                     Some(state.arena.concat(args)),
                     vec![link.children.to_doc(state)],
                 )
+                .append(label(state.arena, &link.attributes))
             }
 
             Inline::LinkReference(link_ref) => {
                 if let Some(definition) = state.get_link_definition(&link_ref.label) {
+                    if link_ref.image {
+                        return Inline::Image(Image {
+                            destination: definition.destination.clone(),
+                            title: definition.title.clone(),
+                            alt: plain_text(&link_ref.text),
+                            attributes: Attributes::default(),
+                        })
+                        .to_doc(state);
+                    }
                     let url = escape_typst(&definition.destination);
                     let text = link_ref.text.to_doc(state);
                     let mut args = vec![state.arena.text(format!(r#""{}""#, url))];
@@ -92,6 +103,8 @@ impl<'a> ToDoc<'a> for Inline {
                         Some(state.arena.concat(args)),
                         vec![text],
                     )
+                } else if link_ref.image {
+                    state.arena.text("!").append(link_ref.text.to_doc(state))
                 } else {
                     link_ref.text.to_doc(state)
                 }
@@ -101,20 +114,21 @@ impl<'a> ToDoc<'a> for Inline {
                 let url = escape_typst(&image.destination);
                 let alt = escape_typst(&image.alt);
                 let mut res = format!("#box(image(\"{url}\", alt: \"{alt}\"");
-                if let Some(attr) = &image.attr {
-                    if let Some(width) = &attr.width {
-                        if TYPST_RELATIVE_VALUE_REGEX.is_match(width) {
-                            res.push_str(&format!(", width: {width}"));
-                        }
+                if let Some(width) = image.attributes.get("width") {
+                    if TYPST_RELATIVE_VALUE_REGEX.is_match(width) {
+                        res.push_str(&format!(", width: {width}"));
                     }
-                    if let Some(height) = &attr.height {
-                        if TYPST_RELATIVE_VALUE_REGEX.is_match(height) {
-                            res.push_str(&format!(", height: {height}"));
-                        }
+                }
+                if let Some(height) = image.attributes.get("height") {
+                    if TYPST_RELATIVE_VALUE_REGEX.is_match(height) {
+                        res.push_str(&format!(", height: {height}"));
                     }
                 }
                 res.push_str("))");
-                state.arena.text(res)
+                state
+                    .arena
+                    .text(res)
+                    .append(label(state.arena, &image.attributes))
             }
 
             Inline::Emphasis(content) => state
@@ -135,14 +149,148 @@ impl<'a> ToDoc<'a> for Inline {
                 .append(content.to_doc(state))
                 .append(state.arena.text("]")),
 
-            Inline::Autolink(url) => {
+            Inline::Superscript(content) => state
+                .arena
+                .text("#super[")
+                .append(content.to_doc(state))
+                .append(state.arena.text("]")),
+
+            Inline::Subscript(content) => state
+                .arena
+                .text("#sub[")
+                .append(content.to_doc(state))
+                .append(state.arena.text("]")),
+
+            Inline::Highlight(content) => state
+                .arena
+                .text("#highlight[")
+                .append(content.to_doc(state))
+                .append(state.arena.text("]")),
+
+            Inline::Insert(content) => state
+                .arena
+                .text("#underline[")
+                .append(content.to_doc(state))
+                .append(state.arena.text("]")),
+
+            Inline::WikiLink(wiki_link) => {
+                let text = state
+                    .arena
+                    .text(format!("#\"{}\"", escape_typst(&wiki_link.text())));
+                let destination = match &state.config.wiki_link_resolver {
+                    Some(resolver) => resolver(wiki_link),
+                    None => Some(wiki_link.destination()),
+                };
+                match destination {
+                    Some(destination) => body(
+                        state.arena,
+                        "link",
+                        Some(
+                            state
+                                .arena
+                                .text(format!(r#""{}""#, escape_typst(&destination))),
+                        ),
+                        vec![text],
+                    ),
+                    None => text,
+                }
+            }
+
+            Inline::Span(span) => {
+                let functions = span
+                    .attributes
+                    .classes
+                    .iter()
+                    .filter_map(|class| state.config.span_functions.get(class));
+                let doc = functions
+                    .rev()
+                    .fold(span.children.to_doc(state), |doc, function| {
+                        state
+                            .arena
+                            .text(format!("#{function}["))
+                            .append(doc)
+                            .append(state.arena.text("]"))
+                    });
+                doc.append(label(state.arena, &span.attributes))
+            }
+
+            Inline::GitHubReference(reference) => {
+                let text = state
+                    .arena
+                    .text(format!(r#"#"{}""#, escape_typst(&reference.text())));
+                match &reference.destination {
+                    Some(destination) => body(
+                        state.arena,
+                        "link",
+                        Some(
+                            state
+                                .arena
+                                .text(format!(r#""{}""#, escape_typst(destination))),
+                        ),
+                        vec![text],
+                    ),
+                    None => text,
+                }
+            }
+
+            Inline::Citation(citation) => state.arena.intersperse(
+                citation.items.iter().map(|item| {
+                    let prefix = match &item.prefix {
+                        Some(prefix) => {
+                            state.arena.text(format!(r#"#"{} ""#, escape_typst(prefix)))
+                        }
+                        None => state.arena.nil(),
+                    };
+                    prefix.append(state.arena.text(cite(item, citation.in_text)))
+                }),
+                state.arena.text(" "),
+            ),
+
+            Inline::Abbreviation { text, title } => {
+                let doc = state.arena.text(format!(r#"#"{}""#, escape_typst(text)));
+                if !title.is_empty() && state.first_use_of_abbreviation(text) {
+                    doc.append(
+                        state
+                            .arena
+                            .text(format!(r#"#footnote[#"{}"]"#, escape_typst(title))),
+                    )
+                } else {
+                    doc
+                }
+            }
+
+            Inline::Emoji { glyph, .. } => {
+                state.arena.text(format!("#\"{}\"", escape_typst(glyph)))
+            }
+
+            Inline::Extension(extension) => {
+                let children = extension.children.to_doc(state);
+                if let Some(renderer) = &state.config.inline_extension_renderer {
+                    let rendered = render_to_string(children.clone(), state.config.width);
+                    if let Some(typst) = renderer(extension, &rendered) {
+                        return verbatim(state.arena, &typst);
+                    }
+                }
+                children
+            }
+
+            Inline::Autolink { url, .. } => {
                 let escaped_url = escape_typst(url);
-                body(
-                    &state.arena,
-                    "link",
-                    Some(state.arena.text(format!(r#""{escaped_url}""#))),
-                    vec![],
-                )
+                if url.starts_with("www.") {
+                    body(
+                        state.arena,
+                        "link",
+                        Some(state.arena.text(format!(r#""http://{escaped_url}""#))),
+                        vec![state.arena.text(format!(r#"#"{escaped_url}""#))],
+                    )
+                } else {
+                    body(
+                        state.arena,
+                        "link",
+                        Some(state.arena.text(format!(r#""{escaped_url}""#))),
+                        vec![],
+                    )
+                }
             }
 
             Inline::FootnoteReference(label) => {
@@ -168,11 +316,43 @@ impl<'a> ToDoc<'a> for Inline {
 
             Inline::Empty => state.arena.nil(),
 
-            Inline::Latex(latex) => state
+            Inline::Latex { content: latex, .. } => state
                 .arena
                 .text("#mi(block: false, \"")
                 .append(state.arena.text(escape_typst(&latex.clone())))
                 .append(state.arena.text("\")")),
         }
     }
+}
+
+/// `@key`, or `#cite(<key>, ...)` when the item needs arguments
+fn cite(item: &CitationItem, in_text: bool) -> String {
+    let label_literal = item
+        .key
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+        && !item.key.ends_with(['.', ':']);
+    let form = if in_text {
+        Some("prose")
+    } else if item.suppress_author {
+        Some("year")
+    } else {
+        None
+    };
+    if label_literal && form.is_none() && item.locator.is_none() {
+        return format!("@{}", item.key);
+    }
+
+    let mut args = vec![if label_literal {
+        format!("<{}>", item.key)
+    } else {
+        format!(r#"label("{}")"#, escape_typst(&item.key))
+    }];
+    if let Some(locator) = &item.locator {
+        args.push(format!(r#"supplement: [#"{}"]"#, escape_typst(locator)));
+    }
+    if let Some(form) = form {
+        args.push(format!(r#"form: "{form}""#));
+    }
+    format!("#cite({})", args.join(", "))
 }
\ No newline at end of file
@@ -21,6 +21,7 @@
 //!     blocks: vec![
 //!         Block::Heading(Heading {
 //!             kind: HeadingKind::Atx(1),
+//!             attributes: Attributes::default(),
 //!             content: vec![Inline::Text("Hello Typst".to_string())],
 //!         }),
 //!         Block::Paragraph(vec![
@@ -55,7 +56,12 @@
 //! | `[link](url)`     | `#link("url")[link]`                 |
 //! | `![img](url)`     | `#image("url")`                      |
 //! | Tables            | `#table(...)`                        |
+//! | Figures           | `#figure([...], caption: [...])`     |
 //! | Code blocks       | ` ``` `                              |
+//! | Front matter      | `#metadata(...)<front-matter>`       |
+//! | Abbreviations     | `HTML#footnote[...]` on first use    |
+//! | `[@key]`          | `@key`                               |
+//! | `[-@key, p. 33]`  | `#cite(<key>, supplement: [...], form: "year")` |
 
 mod block;
 pub mod config;
@@ -67,8 +73,10 @@ pub mod util;
 mod tests;
 
 use crate::ast::*;
-use pretty::{Arena, DocBuilder};
-use std::collections::HashMap;
+use pretty::{Arena, DocAllocator, DocBuilder};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 /// Internal state for Typst rendering
 ///
@@ -77,13 +85,16 @@ use std::collections::HashMap;
 #[derive(Clone)]
 pub(crate) struct State<'a> {
     arena: &'a Arena<'a>,
-    #[allow(unused)]
     config: &'a crate::typst_printer::config::Config,
     /// Mapping of footnote labels to their definitions.
     footnote_definitions: &'a HashMap<String, FootnoteDefinition>,
     /// Mapping of link labels to their definitions.
-    link_definitions: &'a HashMap<Vec<Inline>, LinkDefinition>,
+    link_definitions: &'a HashMap<String, LinkDefinition>,
     render_with_hash: bool,
+    /// Identifiers of the headings rendered so far, shared by all clones.
+    slugger: Rc<RefCell<Slugger>>,
+    /// Abbreviations expanded so far, shared by all clones.
+    expanded_abbreviations: Rc<RefCell<HashSet<String>>>,
 }
 
 impl<'a> State<'a> {
@@ -95,7 +106,7 @@ impl<'a> State<'a> {
         arena: &'a Arena<'a>,
         config: &'a crate::typst_printer::config::Config,
         footnote_definitions: &'a HashMap<String, FootnoteDefinition>,
-        link_definitions: &'a HashMap<Vec<Inline>, LinkDefinition>,
+        link_definitions: &'a HashMap<String, LinkDefinition>,
     ) -> Self {
         Self {
             arena,
@@ -103,9 +114,33 @@ impl<'a> State<'a> {
             footnote_definitions,
             link_definitions,
             render_with_hash: true,
+            slugger: Rc::new(RefCell::new(Slugger::new())),
+            expanded_abbreviations: Rc::new(RefCell::new(HashSet::new())),
         }
     }
 
+    /// Whether an abbreviation is rendered for the first time
+    ///
+    /// Its expansion is only given as a footnote on first use.
+    pub fn first_use_of_abbreviation(&self, text: &str) -> bool {
+        self.expanded_abbreviations
+            .borrow_mut()
+            .insert(text.to_owned())
+    }
+
+    /// Get the label to attach to a heading, if any
+    ///
+    /// Labels are assigned in rendering order.
+    pub fn heading_label(&self, heading: &Heading) -> Option<String> {
+        let id = if self.config.heading_labels {
+            self.slugger.borrow_mut().heading_id(heading)
+        } else {
+            heading.attributes.id.clone()?
+        };
+        // A heading without text has an empty slug
+        (!id.is_empty()).then_some(id)
+    }
+
     /// Get the footnote definition for a label
     ///
     /// Returns `None` if the footnote is not defined in the document.
@@ -115,9 +150,10 @@ impl<'a> State<'a> {
 
     /// Get the link definition for a reference link
     ///
+    /// Labels are matched as described in [`normalize_label`].
     /// Returns `None` if the link reference is not defined in the document.
-    pub fn get_link_definition(&self, label: &Vec<Inline>) -> Option<&LinkDefinition> {
-        self.link_definitions.get(label)
+    pub fn get_link_definition(&self, label: &[Inline]) -> Option<&LinkDefinition> {
+        self.link_definitions.get(&normalize_label(label))
     }
 }
 
@@ -149,6 +185,7 @@ impl<'a> State<'a> {
 ///                 destination: "https://example.com".to_string(),
 ///                 title: None,
 ///                 children: vec![Inline::Text("this link".to_string())],
+///                 attributes: Attributes::default(),
 ///             }),
 ///             Inline::Text(" for more info.".to_string()),
 ///         ]),
@@ -193,7 +230,17 @@ trait ToDoc<'a> {
 
 impl<'a> ToDoc<'a> for Document {
     fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
-        self.blocks.to_doc(state)
+        let doc = self.blocks.to_doc(state);
+        match &state.config.bibliography {
+            Some(path) => doc
+                .append(state.arena.hardline())
+                .append(state.arena.hardline())
+                .append(state.arena.text(format!(
+                    r#"#bibliography("{}")"#,
+                    crate::typst_printer::util::escape_typst(path)
+                ))),
+            None => doc,
+        }
     }
 }
 
@@ -205,12 +252,12 @@ impl<'a> ToDoc<'a> for Document {
 ///
 /// Returns a tuple of (footnote_index, link_definitions) where:
 /// - footnote_index maps footnote labels to their numeric indices
-/// - link_definitions maps link labels to their full definitions
+/// - link_definitions maps normalized link labels to their full definitions
 fn get_indices(
     ast: &Document,
 ) -> (
     HashMap<String, FootnoteDefinition>,
-    HashMap<Vec<Inline>, LinkDefinition>,
+    HashMap<String, LinkDefinition>,
 ) {
     let mut footnote_definitions = HashMap::new();
     let mut link_definitions = HashMap::new();
@@ -218,7 +265,7 @@ fn get_indices(
     fn process_blocks(
         blocks: &[Block],
         footnote_definitions: &mut HashMap<String, FootnoteDefinition>,
-        link_definitions: &mut HashMap<Vec<Inline>, LinkDefinition>,
+        link_definitions: &mut HashMap<String, LinkDefinition>,
     ) {
         for block in blocks {
             match block {
@@ -226,13 +273,20 @@ fn get_indices(
                     footnote_definitions.insert(def.label.clone(), def.clone());
                 }
                 Block::Definition(def) => {
-                    link_definitions.insert(def.label.clone(), def.clone());
+                    link_definitions
+                        .entry(normalize_label(&def.label))
+                        .or_insert_with(|| def.clone());
                 }
                 Block::List(list) => {
                     for item in &list.items {
                         process_blocks(&item.blocks, footnote_definitions, link_definitions);
                     }
                 }
+                Block::DefinitionList(list) => {
+                    for definition in list.items.iter().flat_map(|item| &item.definitions) {
+                        process_blocks(definition, footnote_definitions, link_definitions);
+                    }
+                }
                 Block::BlockQuote(blocks) => {
                     process_blocks(blocks, footnote_definitions, link_definitions);
                 }
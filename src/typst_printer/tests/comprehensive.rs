@@ -11,6 +11,149 @@ fn test_thematic_break() {
     assert!(result.contains("#thematic-break"));
 }
 
+#[test]
+fn test_front_matter() {
+    let doc = Document {
+        blocks: vec![
+            Block::FrontMatter {
+                format: FrontMatterFormat::Yaml,
+                raw: "title: \"Hi\"\ntags: [a]".to_string(),
+            },
+            Block::ThematicBreak,
+        ],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert!(result.starts_with(
+        r#"#metadata((format: "yaml", raw: "title: \"Hi\"\ntags: [a]"))<front-matter>"#
+    ));
+}
+
+#[test]
+fn test_heading_labels() {
+    let doc = Document {
+        blocks: vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
+                content: vec![Inline::Text("Getting Started".to_string())],
+            }),
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(2),
+                attributes: Attributes {
+                    id: Some("custom".to_string()),
+                    ..Default::default()
+                },
+                content: vec![Inline::Text("Details".to_string())],
+            }),
+        ],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert!(!result.contains("<getting-started>"));
+    assert!(result.contains("#heading(level: 2, [#\"Details\"]) <custom>"));
+
+    let result = render_typst(&doc, Config::default().with_heading_labels(true));
+    assert!(result.contains("#heading(level: 1, [#\"Getting Started\"]) <getting-started>"));
+    assert!(result.contains("#heading(level: 2, [#\"Details\"]) <custom>"));
+}
+
+#[test]
+fn test_extension_nodes() {
+    let doc = Document {
+        blocks: vec![Block::Extension(BlockExtension {
+            name: "aside".to_string(),
+            attrs: vec![("kind".to_string(), "tip".to_string())],
+            children: vec![Block::Paragraph(vec![
+                Inline::Text("Press ".to_string()),
+                Inline::Extension(InlineExtension {
+                    name: "kbd".to_string(),
+                    attrs: vec![],
+                    children: vec![Inline::Text("Ctrl".to_string())],
+                }),
+            ])],
+        })],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(result, "#par[#\"Press \"#\"Ctrl\"]");
+
+    let config = Config::default()
+        .with_block_extension_renderer(std::rc::Rc::new(|extension, children| {
+            (extension.name == "aside").then(|| format!("#block(inset: 8pt)[{children}]"))
+        }))
+        .with_inline_extension_renderer(std::rc::Rc::new(|_, _| None));
+    let result = render_typst(&doc, config);
+    assert_eq!(result, "#block(inset: 8pt)[#par[#\"Press \"#\"Ctrl\"]]");
+}
+
+#[test]
+fn test_definition_list() {
+    let text = |s: &str| Inline::Text(s.to_string());
+    let doc = Document {
+        blocks: vec![Block::DefinitionList(DefinitionList {
+            items: vec![
+                DefinitionListItem {
+                    term: vec![text("Apple")],
+                    definitions: vec![
+                        vec![Block::Paragraph(vec![text("A fruit")])],
+                        vec![Block::Paragraph(vec![text("A company")])],
+                    ],
+                },
+                DefinitionListItem {
+                    term: vec![text("Pear")],
+                    definitions: vec![vec![Block::Paragraph(vec![text("Green")])]],
+                },
+            ],
+        })],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(
+        result,
+        "#terms(\n  terms.item[#\"Apple\"][#\"A fruit\"\n\n#\"A company\"],\n  terms.item[#\"Pear\"][#\"Green\"],\n)"
+    );
+}
+
+#[test]
+fn test_wiki_links() {
+    let wiki_link = |target: &str, alias: Option<&str>| {
+        Inline::WikiLink(WikiLink {
+            target: target.to_string(),
+            fragment: Some("Intro".to_string()),
+            alias: alias.map(str::to_string),
+            embed: false,
+        })
+    };
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            wiki_link("Home Page", None),
+            wiki_link("Missing", Some("gone")),
+        ])],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(
+        result,
+        "#par[#link(\"Home Page#Intro\")[#\"Home Page#Intro\"]#link(\"Missing#Intro\")[#\"gone\"]]"
+    );
+
+    let config = Config::default().with_wiki_link_resolver(std::rc::Rc::new(|link| {
+        (link.target != "Missing").then(|| {
+            format!(
+                "/wiki/{}#{}",
+                link.target.replace(' ', "_"),
+                link.fragment.as_deref().unwrap_or_default()
+            )
+        })
+    }));
+    let result = render_typst(&doc, config);
+    assert_eq!(
+        result,
+        "#par[#link(\"/wiki/Home_Page#Intro\")[#\"Home Page#Intro\"]#\"gone\"]"
+    );
+}
+
 #[test]
 fn test_html_block() {
     let doc = Document {
@@ -155,6 +298,7 @@ fn test_link_reference() {
                 Inline::LinkReference(LinkReference {
                     label: vec![Inline::Text("example".to_string())],
                     text: vec![Inline::Text("this site".to_string())],
+                    image: false,
                 }),
                 Inline::Text(".".to_string()),
             ]),
@@ -172,6 +316,7 @@ fn test_link_reference_unresolved() {
             LinkReference {
                 label: vec![Inline::Text("missing".to_string())],
                 text: vec![Inline::Text("broken link".to_string())],
+                image: false,
             },
         )])],
     };
@@ -187,10 +332,13 @@ fn test_image() {
             destination: "image.png".to_string(),
             title: Some("My Image".to_string()),
             alt: "Alt text".to_string(),
-            attr: Some(ImageAttributes {
-                width: Some("100pt".to_string()),
-                height: Some("50pt".to_string()),
-            }),
+            attributes: Attributes {
+                pairs: vec![
+                    ("width".to_string(), "100pt".to_string()),
+                    ("height".to_string(), "50pt".to_string()),
+                ],
+                ..Default::default()
+            },
         })])],
     };
 
@@ -207,10 +355,10 @@ fn test_image_with_single_attribute() {
             destination: "image.png".to_string(),
             title: Some("My Image".to_string()),
             alt: "Alt text".to_string(),
-            attr: Some(ImageAttributes {
-                width: Some("100pt".to_string()),
-                height: None,
-            }),
+            attributes: Attributes {
+                pairs: vec![("width".to_string(), "100pt".to_string())],
+                ..Default::default()
+            },
         })])],
     };
 
@@ -227,10 +375,13 @@ fn test_image_with_invalid_attribute() {
             destination: "image.png".to_string(),
             title: Some("My Image".to_string()),
             alt: "Alt text".to_string(),
-            attr: Some(ImageAttributes {
-                width: Some("invalid".to_string()),
-                height: Some("50pt".to_string()),
-            }),
+            attributes: Attributes {
+                pairs: vec![
+                    ("width".to_string(), "invalid".to_string()),
+                    ("height".to_string(), "50pt".to_string()),
+                ],
+                ..Default::default()
+            },
         })])],
     };
 
@@ -254,12 +405,56 @@ fn test_strikethrough() {
     assert!(result.contains(r#"#strike[#"crossed out"]"#));
 }
 
+#[test]
+fn test_superscript_subscript_highlight_insert() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Superscript(vec![Inline::Text("2".to_string())]),
+            Inline::Subscript(vec![Inline::Text("i".to_string())]),
+            Inline::Highlight(vec![Inline::Text("key".to_string())]),
+            Inline::Insert(vec![Inline::Text("new".to_string())]),
+        ])],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert!(result.contains(r#"#super[#"2"]"#));
+    assert!(result.contains(r#"#sub[#"i"]"#));
+    assert!(result.contains(r#"#highlight[#"key"]"#));
+    assert!(result.contains(r#"#underline[#"new"]"#));
+}
+
+#[test]
+fn test_abbreviation_expanded_on_first_use() {
+    let html = || Inline::Abbreviation {
+        text: "HTML".to_string(),
+        title: "HyperText Markup Language".to_string(),
+    };
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![html()]),
+            Block::Paragraph(vec![html()]),
+        ],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(
+        result
+            .matches(r##"#"HTML"#footnote[#"HyperText Markup Language"]"##)
+            .count(),
+        1
+    );
+    assert_eq!(result.matches(r##"#"HTML""##).count(), 2);
+}
+
 #[test]
 fn test_autolink() {
     let doc = Document {
         blocks: vec![Block::Paragraph(vec![
             Inline::Text("Visit ".to_string()),
-            Inline::Autolink("https://example.com".to_string()),
+            Inline::Autolink {
+                url: "https://example.com".to_string(),
+                bare: false,
+            },
             Inline::Text(".".to_string()),
         ])],
     };
@@ -333,6 +528,8 @@ fn test_nested_elements() {
                                 info: Some("bash".to_string()),
                             },
                             literal: "echo 'nested code'".to_string(),
+
+                            attributes: Attributes::default(),
                         }),
                     ],
                 }],
@@ -347,3 +544,183 @@ fn test_nested_elements() {
     assert!(result.contains("#raw"));
     assert!(result.contains("echo 'nested code'"));
 }
+
+#[test]
+fn test_attribute_ids_become_labels() {
+    let id = |id: &str| Attributes {
+        id: Some(id.to_string()),
+        ..Default::default()
+    };
+    let doc = Document {
+        blocks: vec![
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                attributes: id("intro"),
+                content: vec![Inline::Text("Intro".to_string())],
+            }),
+            Block::CodeBlock(CodeBlock {
+                kind: CodeBlockKind::Fenced { info: None },
+                literal: "x".to_string(),
+                attributes: id("code"),
+            }),
+            Block::Paragraph(vec![Inline::Link(Link {
+                destination: "/docs".to_string(),
+                title: None,
+                children: vec![Inline::Text("docs".to_string())],
+                attributes: id("docs"),
+            })]),
+        ],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert!(result.contains("<intro>"), "{result}");
+    assert!(
+        result.contains(r#"#raw(block: true, "x") <code>"#),
+        "{result}"
+    );
+    assert!(
+        result.contains(r#"#link("/docs")[#"docs"] <docs>"#),
+        "{result}"
+    );
+}
+
+#[test]
+fn test_span_functions() {
+    let span = |classes: &[&str], text: &str| {
+        Inline::Span(Span {
+            attributes: Attributes {
+                classes: classes.iter().map(|c| c.to_string()).collect(),
+                ..Default::default()
+            },
+            children: vec![Inline::Text(text.to_string())],
+        })
+    };
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            span(&["highlight", "caps"], "key"),
+            Inline::Text(" ".to_string()),
+            span(&["unknown"], "plain"),
+        ])],
+    };
+
+    let config = Config::default()
+        .with_span_function("highlight", "highlight")
+        .with_span_function("caps", "smallcaps");
+    let result = render_typst(&doc, config);
+    assert_eq!(
+        result,
+        "#par[#highlight[#smallcaps[#\"key\"]]#\" \"#\"plain\"]"
+    );
+}
+
+#[test]
+fn test_emoji() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Emoji {
+            shortcode: "rocket".to_string(),
+            glyph: "🚀".to_string(),
+        }])],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(result, "#par[#\"🚀\"]");
+}
+
+#[test]
+fn test_figure_with_caption_and_label() {
+    let doc = Document {
+        blocks: vec![Block::Figure {
+            content: vec![Block::Paragraph(vec![Inline::Image(Image {
+                destination: "cat.png".to_string(),
+                title: None,
+                alt: "A cat".to_string(),
+                attributes: Attributes::default(),
+            })])],
+            caption: vec![
+                Inline::Text("A ".to_string()),
+                Inline::Emphasis(vec![Inline::Text("cat".to_string())]),
+            ],
+            label: Some("fig-cat".to_string()),
+            caption_syntax: CaptionSyntax::Image,
+        }],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(
+        result,
+        r##"#figure([#box(image("cat.png", alt: "A cat"))], caption: [#"A "#emph[#"cat"]]) <fig-cat>"##
+    );
+}
+
+#[test]
+fn test_figure_with_table() {
+    let cell = |text: &str| TableCell {
+        content: vec![Inline::Text(text.to_string())],
+        colspan: None,
+        rowspan: None,
+        removed_by_extended_table: false,
+    };
+    let doc = Document {
+        blocks: vec![Block::Figure {
+            content: vec![Block::Table(Table {
+                rows: vec![
+                    vec![cell("Fruit"), cell("Price")],
+                    vec![cell("Apple"), cell("1")],
+                ],
+                alignments: vec![Alignment::Left, Alignment::Right],
+                attributes: Attributes::default(),
+            })],
+            caption: vec![Inline::Text("Prices".to_string())],
+            label: Some("tbl-prices".to_string()),
+            caption_syntax: CaptionSyntax::Table,
+        }],
+    };
+
+    let result = render_typst(&doc, Config::default());
+    assert_eq!(
+        result,
+        "#figure(table(\n  columns: (2),\n  align: (left + horizon, right + horizon),\n  [#\"Fruit\"],  [#\"Price\"],\n  [#\"Apple\"],  [#\"1\"],\n), caption: [#\"Prices\"]) <tbl-prices>"
+    );
+}
+
+#[test]
+fn test_citations() {
+    let item = |key: &str| CitationItem {
+        key: key.to_string(),
+        prefix: None,
+        locator: None,
+        suppress_author: false,
+    };
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Citation(Citation {
+                items: vec![
+                    CitationItem {
+                        prefix: Some("see".to_string()),
+                        locator: Some("p. 33".to_string()),
+                        ..item("doe99")
+                    },
+                    CitationItem {
+                        suppress_author: true,
+                        ..item("smith04")
+                    },
+                    item("roe12"),
+                    item("a/b"),
+                ],
+                in_text: false,
+            }),
+            Inline::Citation(Citation {
+                items: vec![item("doe99")],
+                in_text: true,
+            }),
+        ])],
+    };
+
+    let result = render_typst(&doc, Config::default().with_bibliography("refs.bib"));
+    assert_eq!(
+        result,
+        r##"#par[#"see "#cite(<doe99>, supplement: [#"p. 33"]) #cite(<smith04>, form: "year") @roe12 #cite(label("a/b"))#cite(<doe99>, form: "prose")]
+
+#bibliography("refs.bib")"##
+    );
+}
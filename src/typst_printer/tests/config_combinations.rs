@@ -46,12 +46,16 @@ fn test_default_config() {
                     removed_by_extended_table: false,
                 }]],
                 alignments: vec![Alignment::Left],
+
+                attributes: Attributes::default(),
             }),
             Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Fenced {
                     info: Some("rust".to_string()),
                 },
                 literal: "test".to_string(),
+
+                attributes: Attributes::default(),
             }),
         ],
     };
@@ -24,6 +24,7 @@ fn test_empty_heading() {
     let doc = Document {
         blocks: vec![Block::Heading(Heading {
             kind: HeadingKind::Atx(1),
+            attributes: Attributes::default(),
             content: vec![],
         })],
     };
@@ -84,6 +85,8 @@ fn test_empty_table() {
         blocks: vec![Block::Table(Table {
             rows: vec![],
             alignments: vec![],
+
+            attributes: Attributes::default(),
         })],
     };
 
@@ -97,6 +100,8 @@ fn test_empty_code_block() {
         blocks: vec![Block::CodeBlock(CodeBlock {
             kind: CodeBlockKind::Fenced { info: None },
             literal: "".to_string(),
+
+            attributes: Attributes::default(),
         })],
     };
 
@@ -131,6 +136,8 @@ fn test_special_chars_in_urls() {
             destination: "https://example.com/path?q=a&b=c#fragment".to_string(),
             title: None,
             children: vec![Inline::Text("link".to_string())],
+
+            attributes: Attributes::default(),
         })])],
     };
 
@@ -239,6 +246,8 @@ fn test_table_with_merged_cells() {
                 ],
             ],
             alignments: vec![Alignment::Left, Alignment::Center, Alignment::Right],
+
+            attributes: Attributes::default(),
         })],
     };
 
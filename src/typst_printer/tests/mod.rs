@@ -35,14 +35,17 @@ fn test_headings() {
         blocks: vec![
             Block::Heading(Heading {
                 kind: HeadingKind::Atx(1),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("Level 1".to_string())],
             }),
             Block::Heading(Heading {
                 kind: HeadingKind::Atx(2),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("Level 2".to_string())],
             }),
             Block::Heading(Heading {
                 kind: HeadingKind::Setext(SetextHeading::Level1),
+                attributes: Attributes::default(),
                 content: vec![Inline::Text("Setext 1".to_string())],
             }),
         ],
@@ -84,6 +87,8 @@ fn test_code_block() {
                 info: Some("rust".to_string()),
             },
             literal: literal.to_string(),
+
+            attributes: Attributes::default(),
         })],
     };
 
@@ -168,6 +173,8 @@ fn test_table() {
                 ],
             ],
             alignments: vec![Alignment::Left, Alignment::Right],
+
+            attributes: Attributes::default(),
         })],
     };
 
@@ -208,6 +215,8 @@ fn test_links() {
                 destination: "https://example.com".to_string(),
                 title: Some("Example Site".to_string()),
                 children: vec![Inline::Text("this link".to_string())],
+
+                attributes: Attributes::default(),
             }),
             Inline::Text(".".to_string()),
         ])],
@@ -224,7 +233,10 @@ fn test_autolink() {
     let doc = Document {
         blocks: vec![Block::Paragraph(vec![
             Inline::Text("Visit ".to_string()),
-            Inline::Autolink("https://example.com".to_string()),
+            Inline::Autolink {
+                url: "https://example.com".to_string(),
+                bare: false,
+            },
             Inline::Text(".".to_string()),
         ])],
     };
@@ -3,6 +3,7 @@
 //! This module provides helper functions for Typst generation including
 //! character escaping and Typst function generation.
 
+use crate::ast::Attributes;
 use pretty::{Arena, DocAllocator, DocBuilder};
 
 /// Escape Typst special characters in text
@@ -65,6 +66,17 @@ pub fn body<'a>(
     cmd
 }
 
+/// Render the id of an attribute list as a label ` <id>`, or nothing.
+pub(crate) fn label<'a>(
+    arena: &'a Arena<'a>,
+    attributes: &Attributes,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    match &attributes.id {
+        Some(id) => arena.text(format!(" <{id}>")),
+        None => arena.nil(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
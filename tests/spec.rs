@@ -0,0 +1,213 @@
+//! CommonMark and GFM spec conformance harness
+//!
+//! Every `tests/spec/*.txt` file in the `spec.txt` format of the CommonMark
+//! and GFM specifications is read, and each example is parsed with
+//! `parse_markdown`, rendered with the HTML backend and compared with the
+//! expected HTML. `make spec-fetch` downloads the upstream spec files,
+//! pinned to CommonMark 0.31.2 and GFM 0.29.0.gfm.13.
+//!
+//! A per-section report is printed (run with `--nocapture` to see it) and
+//! written to `spec-report-<name>.md` in the test temporary directory.
+//!
+//! The numbers of the examples that passed are recorded in
+//! `tests/spec/<name>.passing`. The test fails when one of those examples no
+//! longer passes, or when a spec file has no list. Run with `SPEC_BLESS=1`
+//! (or `make spec-bless`) to update the lists after fixing the parser.
+
+#![cfg(all(feature = "parser", feature = "html-printer"))]
+
+use markdown_ppp::html_printer::{config::Config, render_html};
+use markdown_ppp::parser::{parse_markdown, MarkdownParserState};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+/// Fence around the examples of a spec file
+const EXAMPLE_FENCE: &str = "````````````````````````````````";
+
+struct Example {
+    number: usize,
+    section: String,
+    markdown: String,
+    html: String,
+}
+
+/// Read the examples of a spec file
+fn parse_spec(text: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut section = String::new();
+    let mut lines = text.lines();
+
+    while let Some(line) = lines.next() {
+        if let Some(heading) = line.strip_prefix('#') {
+            section = heading.trim_start_matches('#').trim().to_string();
+            continue;
+        }
+        let Some(rest) = line.strip_prefix(EXAMPLE_FENCE) else {
+            continue;
+        };
+        if !rest.trim_start().starts_with("example") {
+            continue;
+        }
+
+        let mut markdown = String::new();
+        for line in lines.by_ref().take_while(|line| *line != ".") {
+            markdown.push_str(line);
+            markdown.push('\n');
+        }
+        let mut html = String::new();
+        for line in lines.by_ref().take_while(|line| *line != EXAMPLE_FENCE) {
+            html.push_str(line);
+            html.push('\n');
+        }
+        examples.push(Example {
+            number: examples.len() + 1,
+            section: section.clone(),
+            markdown: markdown.replace('→', "\t"),
+            html: html.replace('→', "\t"),
+        });
+    }
+    examples
+}
+
+/// Normalize HTML so that only differences in content are reported
+fn normalize_html(html: &str) -> String {
+    html.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace(">\n", ">")
+        .replace("\n<", "<")
+        .trim()
+        .to_string()
+}
+
+/// Render `markdown` the way the spec examples expect
+///
+/// Returns `None` if parsing fails or panics.
+fn render(markdown: &str) -> Option<String> {
+    panic::catch_unwind(|| {
+        let document = parse_markdown(MarkdownParserState::default(), markdown).ok()?;
+        Some(render_html(&document, Config::default()))
+    })
+    .ok()
+    .flatten()
+}
+
+struct SpecResult {
+    passed: BTreeSet<usize>,
+    report: String,
+}
+
+fn run_spec(name: &str, examples: &[Example]) -> SpecResult {
+    let mut passed = BTreeSet::new();
+    // (section, passed, total) in order of appearance
+    let mut sections: Vec<(&str, usize, usize)> = Vec::new();
+
+    for example in examples {
+        let ok = render(&example.markdown)
+            .is_some_and(|html| normalize_html(&html) == normalize_html(&example.html));
+        if ok {
+            passed.insert(example.number);
+        }
+        match sections.last_mut() {
+            Some((section, pass, total)) if *section == example.section => {
+                *pass += usize::from(ok);
+                *total += 1;
+            }
+            _ => sections.push((&example.section, usize::from(ok), 1)),
+        }
+    }
+
+    let mut report = format!(
+        "# {name}: {}/{} examples pass\n\n| Section | Passed | Total |\n|---|---:|---:|\n",
+        passed.len(),
+        examples.len()
+    );
+    for (section, pass, total) in sections {
+        let _ = writeln!(report, "| {section} | {pass} | {total} |");
+    }
+    SpecResult { passed, report }
+}
+
+fn read_passing(path: &Path) -> Option<BTreeSet<usize>> {
+    let text = fs::read_to_string(path).ok()?;
+    Some(
+        text.split_whitespace()
+            .map(|n| n.parse().expect("example number"))
+            .collect(),
+    )
+}
+
+fn write_passing(path: &Path, passed: &BTreeSet<usize>) {
+    let mut text = String::new();
+    for number in passed {
+        let _ = writeln!(text, "{number}");
+    }
+    fs::write(path, text).expect("write list of passing examples");
+}
+
+fn spec_files() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/spec");
+    let mut files: Vec<_> = fs::read_dir(dir)
+        .expect("tests/spec directory")
+        .map(|entry| entry.expect("directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn spec_examples() {
+    let bless = std::env::var_os("SPEC_BLESS").is_some();
+    let mut regressions = Vec::new();
+
+    for path in spec_files() {
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let examples = parse_spec(&fs::read_to_string(&path).expect("spec file"));
+        let result = run_spec(&name, &examples);
+
+        println!("{}", result.report);
+        let report_path =
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("spec-report-{name}.md"));
+        fs::write(&report_path, &result.report).expect("write spec report");
+
+        let passing_path = path.with_extension("passing");
+        if bless {
+            write_passing(&passing_path, &result.passed);
+        } else if let Some(expected) = read_passing(&passing_path) {
+            regressions.extend(
+                expected
+                    .difference(&result.passed)
+                    .map(|number| format!("{name} example {number}")),
+            );
+        } else {
+            regressions.push(format!("{name} (no list, run `make spec-bless`)"));
+        }
+    }
+
+    assert!(
+        regressions.is_empty(),
+        "spec regressions: {}",
+        regressions.join(", ")
+    );
+}
+
+#[test]
+fn spec_format() {
+    let text = "# Tabs\n\n```````````````````````````````` example\n→foo\n.\n<pre><code>foo\n</code></pre>\n````````````````````````````````\n\n## Lists\n\n```````````````````````````````` example\n- a\n.\n<ul>\n<li>a</li>\n</ul>\n````````````````````````````````\n";
+    let examples = parse_spec(text);
+    assert_eq!(examples.len(), 2);
+    assert_eq!(examples[0].section, "Tabs");
+    assert_eq!(examples[0].markdown, "\tfoo\n");
+    assert_eq!(examples[1].number, 2);
+    assert_eq!(examples[1].section, "Lists");
+    assert_eq!(normalize_html(&examples[1].html), "<ul><li>a</li></ul>");
+
+    let result = run_spec("format", &examples);
+    assert!(result.report.contains("| Tabs | 1 | 1 |"));
+    assert!(result.report.contains("| Lists | 1 | 1 |"));
+}